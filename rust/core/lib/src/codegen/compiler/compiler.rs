@@ -70,6 +70,8 @@ impl Compiler {
             );
         }
 
+        compiler_result.validate_partial_references()?;
+
         // Generate the view map.
         let view_mapping = compiler_result.view_name_mapping();
         let view_map_file_path = PathBuf::from(target_dir).join(format!(
@@ -81,7 +83,11 @@ impl Compiler {
         registry::generate_registry(&view_map_file_path, view_mapping, &self.options)?;
 
         let root_mod_file_path = PathBuf::from(target_dir).join(consts::TEMPLATES_MOD_FILE_NAME);
-        let root_mod_ts = Module::generate_root_mod_ts(compiler_result.mods(), &self.options);
+        let root_mod_ts = Module::generate_root_mod_ts(
+            compiler_result.mods(),
+            compiler_result.model_views(),
+            &self.options,
+        );
         fsutil::write_code_to_file(&root_mod_file_path, &root_mod_ts)?;
 
         // Tell cargo to rerun the build script if any of the source directories change.
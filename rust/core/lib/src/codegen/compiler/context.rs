@@ -2,14 +2,31 @@ use crate::{
     codegen::{
         CompilerOptions,
         compiler::optimizer::{self, Optimizer},
+        parser::{html, json},
         types::Block,
     },
-    types::template,
+    types::{EscapeContext, template},
 };
+use std::cell::Cell;
 
 pub(in crate::codegen::compiler) struct CodeGenContext<'a> {
     template_kind: template::Kind,
     options: &'a CompilerOptions,
+    // generating `render_async`, views and partials are awaited.
+    is_async: bool,
+    // the layout is set by a `_viewstart`, which isn't applied to views rendered as layouts.
+    view_start_layout: bool,
+    // where the static content generated so far ends.
+    scan_state: Cell<ScanState>,
+    // bytes of static content and expressions generated so far, to reserve the output.
+    static_size: Cell<usize>,
+    expressions: Cell<usize>,
+}
+
+#[derive(Clone, Copy, Default)]
+pub(in crate::codegen::compiler) struct ScanState {
+    html: html::ScanState,
+    json: json::ScanState,
 }
 
 impl<'a> CodeGenContext<'a> {
@@ -20,9 +37,35 @@ impl<'a> CodeGenContext<'a> {
         Self {
             template_kind: template_kind,
             options: options,
+            is_async: false,
+            view_start_layout: false,
+            scan_state: Cell::new(ScanState::default()),
+            static_size: Cell::new(0),
+            expressions: Cell::new(0),
         }
     }
 
+    pub(in crate::codegen::compiler) fn with_async(mut self, is_async: bool) -> Self {
+        self.is_async = is_async;
+        self
+    }
+
+    pub(in crate::codegen::compiler) fn is_async(&self) -> bool {
+        self.is_async
+    }
+
+    pub(in crate::codegen::compiler) fn with_view_start_layout(
+        mut self,
+        view_start_layout: bool,
+    ) -> Self {
+        self.view_start_layout = view_start_layout;
+        self
+    }
+
+    pub(in crate::codegen::compiler) fn is_view_start_layout(&self) -> bool {
+        self.view_start_layout
+    }
+
     pub(in crate::codegen::compiler) fn options(&self) -> &CompilerOptions {
         self.options
     }
@@ -33,4 +76,46 @@ impl<'a> CodeGenContext<'a> {
     ) -> Box<dyn Optimizer + '_> {
         optimizer::create_optimizer(self.template_kind, self.options)
     }
+
+    // track static content in the generation order to know where the next expression sits.
+    pub(in crate::codegen::compiler) fn scan_content(&self, raw_content: &str) {
+        let mut state = self.scan_state.get();
+        match self.template_kind {
+            template::Kind::KHTML => state.html = html::scan_html(state.html, raw_content),
+            template::Kind::KJSON => state.json = json::scan_json(state.json, raw_content),
+            _ => {}
+        }
+        self.scan_state.set(state);
+    }
+
+    pub(in crate::codegen::compiler) fn scan_state(&self) -> ScanState {
+        self.scan_state.get()
+    }
+
+    // content written into another writer(section) starts from a new state.
+    pub(in crate::codegen::compiler) fn reset_scan_state(&self, state: ScanState) -> ScanState {
+        self.scan_state.replace(state)
+    }
+
+    pub(in crate::codegen::compiler) fn add_static_size(&self, size: usize) {
+        self.static_size.set(self.static_size.get() + size);
+    }
+
+    pub(in crate::codegen::compiler) fn add_expression(&self) {
+        self.expressions.set(self.expressions.get() + 1);
+    }
+
+    // content in loops or sections is counted once, expressions are estimated by the option.
+    pub(in crate::codegen::compiler) fn size_hint(&self) -> usize {
+        self.static_size.get() + self.expressions.get() * self.options.dynamic_size_hint()
+    }
+
+    // KTEXT is written as is.
+    pub(in crate::codegen::compiler) fn escape_context(&self) -> Option<EscapeContext> {
+        match self.template_kind {
+            template::Kind::KHTML => Some(self.scan_state.get().html.escape_context()),
+            template::Kind::KJSON => Some(self.scan_state.get().json.escape_context()),
+            _ => None,
+        }
+    }
 }
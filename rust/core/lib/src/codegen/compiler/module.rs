@@ -4,16 +4,26 @@ use crate::{
         compiler::{fsutil, name},
         consts,
     },
-    types::result,
+    types::{result, template::Kind},
 };
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use std::{fs, path::PathBuf};
 
+// `_imports` or `_viewstart` of a folder, applied to every view of its subtree.
+#[derive(Clone)]
+struct SharedFile {
+    path: PathBuf,
+    content: String,
+    kind: Kind,
+}
+
 pub(crate) struct Module {
     source: PathBuf,
     target: PathBuf,
     namespace: Option<String>,
+    imports: Vec<SharedFile>,
+    view_starts: Vec<SharedFile>,
 }
 
 impl Module {
@@ -22,9 +32,17 @@ impl Module {
             source: source,
             target: target,
             namespace,
+            imports: vec![],
+            view_starts: vec![],
         }
     }
 
+    fn with_shared(mut self, imports: Vec<SharedFile>, view_starts: Vec<SharedFile>) -> Self {
+        self.imports = imports;
+        self.view_starts = view_starts;
+        self
+    }
+
     pub(crate) fn process(
         &self,
         compiler_options: &crate::codegen::compiler::CompilerOptions,
@@ -46,11 +64,39 @@ impl Module {
         let mut result = CompileResult::default();
         if let Ok(read_dir) = fs::read_dir(&self.source) {
             let name_space = name::create_name_space(&self.namespace, &dir_name);
-            for entry in read_dir.flatten() {
-                let entry = &entry;
+            let entries = read_dir.flatten().collect::<Vec<_>>();
+            // the shared files of this folder go after the ones of the parents.
+            let mut imports = self.imports.clone();
+            let mut view_starts = self.view_starts.clone();
+            for entry in &entries {
+                let path = entry.path();
+                let Some(kind) =
+                    fsutil::get_template_kind_from_ext(&path, compiler_options.extensions())
+                else {
+                    continue;
+                };
+                let shared_files = match fsutil::get_file_name(&path).as_deref() {
+                    Some(consts::VIEW_IMPORTS_FILE_NAME) => &mut imports,
+                    Some(consts::VIEW_START_FILE_NAME) => &mut view_starts,
+                    _ => continue,
+                };
+                if path.is_file() {
+                    let content = fs::read_to_string(&path).unwrap_or_default();
+                    shared_files.push(SharedFile {
+                        path,
+                        content,
+                        kind,
+                    });
+                }
+            }
+            let shared_imports = Self::parse_shared_files(&imports, compiler_options)?;
+            let shared_view_starts = Self::parse_shared_files(&view_starts, compiler_options)?;
+
+            for entry in &entries {
                 if let Ok(meta) = entry.metadata() {
                     if meta.is_dir() {
                         Module::new(entry.path(), target_dir.clone(), Some(name_space.clone()))
+                            .with_shared(imports.clone(), view_starts.clone())
                             .process(compiler_options)?
                             .merge_into(&mut result);
                         result.add_mod(entry.file_name().to_str().unwrap_or_default());
@@ -64,25 +110,34 @@ impl Module {
                             continue;
                         }
 
+                        let file_name = fsutil::get_file_name(&entry.path()).unwrap_or_default();
+                        if file_name == consts::VIEW_IMPORTS_FILE_NAME
+                            || file_name == consts::VIEW_START_FILE_NAME
+                        {
+                            // merged into the views, not views themselves.
+                            continue;
+                        }
+
                         let content = fs::read_to_string(entry.path()).unwrap_or_default();
                         let template = match crate::codegen::types::Template::from(
                             &content,
                             Some(name_space.clone()),
                             template_kind.unwrap(),
                             compiler_options,
-                        ) {
+                        )
+                        .and_then(|t| t.with_shared(&shared_imports, &shared_view_starts))
+                        {
                             Ok(t) => t,
                             Err(e) => {
                                 return Err(e.with_file(&entry.path()));
                             }
                         };
 
-                        let file_name = fsutil::get_file_name(&entry.path()).unwrap_or_default();
                         let file_name = format!("{}{}", file_name, consts::RS_FILE_EXTENSION);
                         let target_file = target_dir.join(&file_name);
                         match template.compile(target_file, compiler_options) {
                             Ok(c_result) => {
-                                c_result.merge_into(&mut result);
+                                c_result.with_file(&entry.path()).merge_into(&mut result);
                             }
                             Err(e) => {
                                 return Err(e.with_file(&entry.path()));
@@ -104,6 +159,43 @@ impl Module {
 }
 
 impl Module {
+    fn parse_shared_files<'a>(
+        shared_files: &'a [SharedFile],
+        compiler_options: &CompilerOptions,
+    ) -> result::Result<Vec<crate::codegen::types::Template<'a>>> {
+        shared_files
+            .iter()
+            .map(|f| {
+                crate::codegen::types::Template::from(&f.content, None, f.kind, compiler_options)
+                    .map_err(|e| e.with_file(&f.path))
+            })
+            .collect()
+    }
+
+    // `views::shop::product::ProductView` => `render_views_shop_product(model, context)`.
+    fn generate_render_model_ts(view: &str, compiler_option: &CompilerOptions) -> TokenStream {
+        let mut parts = view.split("::").collect::<Vec<_>>();
+        let view_name = parts.pop().unwrap_or_default();
+        let fn_name = format_ident!("render_{}", parts.join("_"));
+        let view_type = syn::parse_str::<TokenStream>(&name::create_type_full_name(
+            view,
+            compiler_option.mod_name(),
+        ))
+        .unwrap_or_default();
+        let model_type = syn::parse_str::<TokenStream>(&name::create_type_full_name(
+            &format!("{}::{}Model", parts.join("::"), view_name),
+            compiler_option.mod_name(),
+        ))
+        .unwrap_or_default();
+        quote! {
+            // typed entry point of the view with `@model`, the model is checked by the compiler.
+            #[allow(dead_code)]
+            pub(crate) fn #fn_name(model: #model_type, context: &mut impl sbolt::types::Context) -> sbolt::types::result::RenderResult<String> {
+                #view_type::new().render_model(model, context)
+            }
+        }
+    }
+
     fn generate_sub_mod_ts(mods: &[String]) -> result::Result<TokenStream> {
         let imported_content: String = mods
             .iter()
@@ -117,6 +209,7 @@ impl Module {
 
     pub(crate) fn generate_root_mod_ts(
         mods: &[String],
+        model_views: &[String],
         compiler_option: &CompilerOptions,
     ) -> TokenStream {
         let viewtypes_ident = format!(
@@ -131,6 +224,28 @@ impl Module {
             .collect::<String>();
         let import_content_ts: TokenStream = import_content.parse().unwrap();
         let viewtypes_ident_ts: TokenStream = viewtypes_ident.parse().unwrap();
+        let render_stream_ts = match compiler_option.need_stream_render() {
+            true => quote! {
+                // renders by the spawner on a blocking pool, the output is received as chunks split by `@flush`.
+                #[allow(dead_code)]
+                pub(crate) fn render_stream(name: &str, context: impl sbolt::types::Context + Send + 'static, spawner: impl FnOnce(sbolt::types::RenderTask)) -> sbolt::types::result::RenderResult<sbolt::types::RenderStream> {
+                    match TEMPLATE_RESOLVER.resolve(name) {
+                        Some(creator) => {
+                            let mut context = context;
+                            let kind = creator().kind();
+                            Ok(sbolt::types::RenderStream::spawn(kind, spawner, move |writer| {
+                                creator().render_to(&mut context, writer)
+                            }))
+                        }
+                        None => Err(sbolt::types::error::RuntimeError::view_not_found(name)),
+                    }
+                }
+            },
+            false => quote! {},
+        };
+        let render_model_ts = model_views
+            .iter()
+            .map(|view| Self::generate_render_model_ts(view, compiler_option));
         quote! {
             #import_content_ts
             pub(crate) mod #mod_name {
@@ -169,6 +284,38 @@ impl Module {
                     }
                 }
 
+                // writes the output into the sink as it's rendered, escaped by the kind of the view.
+                #[allow(dead_code)]
+                pub(crate) fn render_to(name: &str, context:&mut impl sbolt::types::Context, output: &mut impl std::io::Write) -> sbolt::types::result::RenderResult<()> {
+                    if let Some(creator) = TEMPLATE_RESOLVER.resolve(name) {
+                        let view = creator();
+                        let mut writer = sbolt::types::StreamWriter::from_io(output, view.kind());
+                        view.render_to(context, &mut writer)?;
+                        writer.finish()?;
+                        Ok(())
+                    } else {
+                        Err(sbolt::types::error::RuntimeError::view_not_found(name))
+                    }
+                }
+
+                // awaits the views with `@await`, layouts and partials.
+                #[allow(dead_code)]
+                pub(crate) async fn render_async(name: &str, context:&mut impl sbolt::types::Context) -> sbolt::types::result::RenderResult<String> {
+                    if let Some(creator) = TEMPLATE_RESOLVER.resolve(name) {
+                        let view = creator();
+                        let mut writer = sbolt::types::KWriter::new(view.kind());
+                        sbolt::types::Writer::reserve(&mut writer, view.size_hint());
+                        view.render_async(context, &mut writer).await?;
+                        Ok(writer.into_string())
+                    } else {
+                        Err(sbolt::types::error::RuntimeError::view_not_found(name))
+                    }
+                }
+
+                #render_stream_ts
+
+                #(#render_model_ts)*
+
                 #[allow(dead_code)]
                 pub(crate) fn resolve_view_creator(name: &str) -> Option<fn() -> #viewtypes_ident_ts> {
                     TEMPLATE_RESOLVER.resolve(name)
@@ -1,4 +1,6 @@
+use crate::codegen::parser::html;
 use crate::codegen::{CompilerOptions, compiler::optimizer::Optimizer};
+use crate::types::EscapeContext;
 
 pub(in crate::codegen::compiler::optimizer) struct HtmlOptimizer<'a> {
     compiler_options: &'a CompilerOptions,
@@ -19,7 +21,13 @@ impl<'a> Optimizer for HtmlOptimizer<'a> {
         match self.compiler_options.need_optimization() {
             false => source.into(),
             true => {
-                let result = crate::codegen::parser::html::parse_html(source);
+                // a fragment ending inside a tag is followed by an attribute expression, keep it as it is.
+                let state = html::scan_html(html::ScanState::default(), source);
+                if state.escape_context() != EscapeContext::KText {
+                    return source.into();
+                }
+
+                let result = html::parse_html(source);
                 match result {
                     Ok(dom) => dom.to_string(),
                     Err(_) => source.into(),
@@ -42,3 +42,12 @@ fn optimize_ignore_close_tag_3() {
     let output = optimizer.optimize(source);
     assert_eq!(output, "<div class=\"c1 c2\"/>")
 }
+
+#[test]
+fn optimize_ignore_fragment_ending_in_attribute() {
+    let source = "\n<div title=\"";
+    let options = CompilerOptions::default().with_optimization(true);
+    let optimizer = optimizer::create_optimizer(crate::types::template::Kind::KHTML, &options);
+    let output = optimizer.optimize(source);
+    assert_eq!(output, source)
+}
@@ -3,20 +3,30 @@ use std::collections::HashMap;
 
 pub struct CompilerOptions {
     extensions: HashMap<String, template::Kind>,
+    dynamic_size_hint: usize,
+    filters: HashMap<String, String>,
     mod_name: String,
+    need_json_validation: bool,
     need_optimization: bool,
+    need_stream_render: bool,
     out_dir: Option<String>,
     source_dirs: Vec<String>,
+    trim_blocks: Vec<template::Kind>,
 }
 
 impl Default for CompilerOptions {
     fn default() -> Self {
         let options = CompilerOptions {
             extensions: HashMap::<String, template::Kind>::new(),
+            dynamic_size_hint: consts::DEFAULT_DYNAMIC_SIZE_HINT,
+            filters: HashMap::<String, String>::new(),
             mod_name: String::from(consts::TEMP_GENERATED_DIR),
+            need_json_validation: false,
             need_optimization: false,
+            need_stream_render: false,
             out_dir: None,
             source_dirs: Vec::new(),
+            trim_blocks: Vec::new(),
         };
 
         // add default extensions and their kinds
@@ -33,6 +43,13 @@ impl Default for CompilerOptions {
                 consts::DEFAULT_TEXT_TEMPLATE_FILE_EXTENSION,
                 template::Kind::KTEXT,
             );
+
+        // add built-in filters
+        let options = consts::BUILTIN_FILTERS
+            .iter()
+            .fold(options, |options, name| {
+                options.with_filter(name, &format!("{}::{}", consts::BUILTIN_FILTERS_MOD, name))
+            });
         options
         // todo: uncomment to turn on in release build.
         /*if cfg!(not(debug_assertions)) {
@@ -54,6 +71,26 @@ impl CompilerOptions {
         self
     }
 
+    pub fn dynamic_size_hint(&self) -> usize {
+        self.dynamic_size_hint
+    }
+
+    // bytes reserved for each expression on top of the static content when a view is rendered.
+    pub fn with_dynamic_size_hint(mut self, dynamic_size_hint: usize) -> Self {
+        self.dynamic_size_hint = dynamic_size_hint;
+        self
+    }
+
+    pub fn filters(&self) -> &HashMap<String, String> {
+        &self.filters
+    }
+
+    // `@(exp | name(args))` is generated as `path(&(exp), args)`, built-in filters could be replaced.
+    pub fn with_filter(mut self, name: &str, path: &str) -> Self {
+        self.filters.insert(name.into(), path.into());
+        self
+    }
+
     pub fn mod_name(&self) -> &String {
         &self.mod_name
     }
@@ -63,6 +100,16 @@ impl CompilerOptions {
         self
     }
 
+    pub fn need_json_validation(&self) -> bool {
+        self.need_json_validation
+    }
+
+    // check static content of json templates is well-formed at build time.
+    pub fn with_json_validation(mut self, need_json_validation: bool) -> Self {
+        self.need_json_validation = need_json_validation;
+        self
+    }
+
     pub fn need_optimization(&self) -> bool {
         self.need_optimization
     }
@@ -72,6 +119,29 @@ impl CompilerOptions {
         self
     }
 
+    pub fn need_stream_render(&self) -> bool {
+        self.need_stream_render
+    }
+
+    // generates `render_stream` in the root module, which needs the `stream` feature of sbolt.
+    pub fn with_stream_render(mut self, need_stream_render: bool) -> Self {
+        self.need_stream_render = need_stream_render;
+        self
+    }
+
+    pub fn need_trim_blocks(&self, kind: template::Kind) -> bool {
+        self.trim_blocks.contains(&kind)
+    }
+
+    // lines with only control syntax like `@if a {`, `}` and `@{ ... }` are removed from templates of the kind.
+    pub fn with_trim_blocks(mut self, kind: template::Kind, need_trim_blocks: bool) -> Self {
+        self.trim_blocks.retain(|k| *k != kind);
+        if need_trim_blocks {
+            self.trim_blocks.push(kind);
+        }
+        self
+    }
+
     pub fn source_dirs(&self) -> &[String] {
         &self.source_dirs
     }
@@ -38,12 +38,128 @@ pub(crate) fn generate_registry(
         .collect::<Vec<String>>()
         .join("\n        ");
 
+    let view_unpack_render_to_content = view_name_mapping
+        .iter()
+        .map(|(name, view_name)| {
+            format!(
+                "{}::K{}({}) => {}.render_to(context, writer),",
+                consts::TEMPLATES_TYPE_NAME,
+                name::create_view_type_name(&name),
+                view_name.to_lowercase(),
+                view_name.to_lowercase()
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n        ");
+
+    let view_unpack_render_async_content = view_name_mapping
+        .iter()
+        .map(|(name, view_name)| {
+            format!(
+                "{}::K{}({}) => {}.render_async(context, writer).await,",
+                consts::TEMPLATES_TYPE_NAME,
+                name::create_view_type_name(&name),
+                view_name.to_lowercase(),
+                view_name.to_lowercase()
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n        ");
+
+    let view_unpack_render_body_content = view_name_mapping
+        .iter()
+        .map(|(name, view_name)| {
+            format!(
+                "{}::K{}({}) => {}.render_body(context),",
+                consts::TEMPLATES_TYPE_NAME,
+                name::create_view_type_name(name),
+                view_name.to_lowercase(),
+                view_name.to_lowercase()
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n        ");
+
+    let view_unpack_render_body_async_content = view_name_mapping
+        .iter()
+        .map(|(name, view_name)| {
+            format!(
+                "{}::K{}({}) => {}.render_body_async(context).await,",
+                consts::TEMPLATES_TYPE_NAME,
+                name::create_view_type_name(name),
+                view_name.to_lowercase(),
+                view_name.to_lowercase()
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n        ");
+
+    let view_name_content = view_name_mapping
+        .keys()
+        .map(|name| {
+            format!(
+                "{}::K{}(_) => {}::name(),",
+                consts::TEMPLATES_TYPE_NAME,
+                name::create_view_type_name(name),
+                name::create_type_full_name(name, mod_name)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n        ");
+
+    let view_kind_content = view_name_mapping
+        .keys()
+        .map(|name| {
+            format!(
+                "{}::K{}(_) => {}::kind(),",
+                consts::TEMPLATES_TYPE_NAME,
+                name::create_view_type_name(&name),
+                name::create_type_full_name(name, mod_name)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n        ");
+
+    let view_size_hint_content = view_name_mapping
+        .keys()
+        .map(|name| {
+            format!(
+                "{}::K{}(_) => {}::size_hint(),",
+                consts::TEMPLATES_TYPE_NAME,
+                name::create_view_type_name(&name),
+                name::create_type_full_name(name, mod_name)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n        ");
+
     let view_types_ts = view_types_content
         .parse::<proc_macro2::TokenStream>()
         .unwrap();
     let view_unpack_content_ts = view_unpack_content
         .parse::<proc_macro2::TokenStream>()
         .unwrap();
+    let view_unpack_render_to_content_ts = view_unpack_render_to_content
+        .parse::<proc_macro2::TokenStream>()
+        .unwrap();
+    let view_unpack_render_async_content_ts = view_unpack_render_async_content
+        .parse::<proc_macro2::TokenStream>()
+        .unwrap();
+    let view_unpack_render_body_content_ts = view_unpack_render_body_content
+        .parse::<proc_macro2::TokenStream>()
+        .unwrap();
+    let view_unpack_render_body_async_content_ts = view_unpack_render_body_async_content
+        .parse::<proc_macro2::TokenStream>()
+        .unwrap();
+    let view_name_content_ts = view_name_content
+        .parse::<proc_macro2::TokenStream>()
+        .unwrap();
+    let view_kind_content_ts = view_kind_content
+        .parse::<proc_macro2::TokenStream>()
+        .unwrap();
+    let view_size_hint_content_ts = view_size_hint_content
+        .parse::<proc_macro2::TokenStream>()
+        .unwrap();
 
     let reg_ts = generate_registry_method(mod_name, view_name_mapping)?;
     let type_ident = format_ident!("{}", consts::TEMPLATES_TYPE_NAME);
@@ -60,6 +176,57 @@ pub(crate) fn generate_registry(
                 }
             }
 
+            pub(crate) fn render_to(&self, context:&mut impl sbolt::types::Context, writer: &mut impl sbolt::types::Writer) -> sbolt::types::result::RenderResult<()> {
+                match self {
+                   #view_unpack_render_to_content_ts
+                }
+            }
+
+            // boxed as views await each other through layouts and partials.
+            pub(crate) fn render_async<'a>(&'a self, context:&'a mut impl sbolt::types::Context, writer: &'a mut (impl sbolt::types::Writer + Send)) -> std::pin::Pin<Box<dyn std::future::Future<Output = sbolt::types::result::RenderResult<()>> + Send + 'a>> {
+                Box::pin(async move {
+                    match self {
+                       #view_unpack_render_async_content_ts
+                    }
+                })
+            }
+
+            // these render the body of a view for its layout, crates without layouts don't use them.
+            #[allow(dead_code)]
+            pub(crate) fn render_body(&self, context:&mut impl sbolt::types::Context) -> sbolt::types::result::RenderResult<String> {
+                match self {
+                   #view_unpack_render_body_content_ts
+                }
+            }
+
+            #[allow(dead_code)]
+            pub(crate) fn render_body_async<'a>(&'a self, context:&'a mut impl sbolt::types::Context) -> std::pin::Pin<Box<dyn std::future::Future<Output = sbolt::types::result::RenderResult<String>> + Send + 'a>> {
+                Box::pin(async move {
+                    match self {
+                       #view_unpack_render_body_async_content_ts
+                    }
+                })
+            }
+
+            #[allow(dead_code)]
+            pub(crate) fn name(&self) -> String {
+                match self {
+                   #view_name_content_ts
+                }
+            }
+
+            pub(crate) fn kind(&self) -> sbolt::types::template::Kind {
+                match self {
+                   #view_kind_content_ts
+                }
+            }
+
+            pub(crate) fn size_hint(&self) -> usize {
+                match self {
+                   #view_size_hint_content_ts
+                }
+            }
+
             #reg_ts
         }
     };
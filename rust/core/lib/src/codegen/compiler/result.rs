@@ -1,11 +1,16 @@
-use crate::types::error;
+use crate::types::{self, error};
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 pub struct CompileResult {
     // errors which won't stop build.
     warnings: Vec<error::CompileError>,
     view_name_mapping: HashMap<String, String>,
     mods: Vec<String>,
+    // full names of the views with `@model`, they get typed entry points.
+    model_views: Vec<String>,
+    // literal partial paths: (view name, path, error if not found).
+    partial_references: Vec<(String, String, error::CompileError)>,
 }
 
 impl Default for CompileResult {
@@ -20,6 +25,8 @@ impl CompileResult {
             warnings: Vec::new(),
             view_name_mapping: HashMap::new(),
             mods: Vec::new(),
+            model_views: Vec::new(),
+            partial_references: Vec::new(),
         }
     }
 
@@ -52,8 +59,49 @@ impl CompileResult {
         &self.mods
     }
 
+    pub(crate) fn add_model_view(&mut self, view_name: &str) {
+        self.model_views.push(view_name.to_string());
+    }
+
+    pub(crate) fn model_views(&self) -> &[String] {
+        &self.model_views
+    }
+
+    pub(crate) fn add_partial_reference(
+        &mut self,
+        view_name: &str,
+        path: &str,
+        error: error::CompileError,
+    ) {
+        self.partial_references
+            .push((view_name.to_string(), path.to_string(), error));
+    }
+
+    pub(crate) fn with_file(mut self, file: &PathBuf) -> Self {
+        for (_, _, error) in self.partial_references.iter_mut() {
+            *error = error.with_file(file);
+        }
+        self
+    }
+
+    // partials are resolved like layouts, any of the candidate keys must be a compiled view.
+    pub(crate) fn validate_partial_references(&self) -> Result<(), error::CompileError> {
+        for (view_name, path, error) in &self.partial_references {
+            let found = types::resolve_layout_to_view_keys(path, view_name)
+                .iter()
+                .filter_map(|key| types::normalize_path_to_view_key(key))
+                .any(|key| self.view_name_mapping.contains_key(&key));
+            if !found {
+                return Err(error.clone());
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn merge_without_mods(&mut self, other: CompileResult) {
         self.warnings.extend(other.warnings);
+        self.partial_references.extend(other.partial_references);
+        self.model_views.extend(other.model_views);
         self.view_name_mapping
             .extend(other.view_name_mapping.into_iter());
     }
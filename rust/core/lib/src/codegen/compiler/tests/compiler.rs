@@ -29,6 +29,11 @@ fn compiler_options_default() {
         default_extensions.get(consts::DEFAULT_TEXT_TEMPLATE_FILE_EXTENSION),
         Some(&template::Kind::KTEXT)
     );
+    assert_eq!(option.filters().len(), consts::BUILTIN_FILTERS.len());
+    assert_eq!(
+        option.filters().get("upper"),
+        Some(&String::from("sbolt::types::filters::upper"))
+    );
     assert!(!option.mod_name().is_empty());
     #[cfg(debug_assertions)]
     assert!(!option.need_optimization());
@@ -36,21 +41,33 @@ fn compiler_options_default() {
     assert!(option.need_optimization());
     assert!(option.out_dir().is_none());
     assert!(option.source_dirs().is_empty());
+    assert!(!option.need_trim_blocks(template::Kind::KTEXT));
 }
 
 #[test]
 fn compiler_options_set() {
     let option = codegen::CompilerOptions::default()
         .with_extension("rshtm", template::Kind::KHTML)
+        .with_filter("upper", "crate::upper")
         .with_mod_name("test_views")
         .with_optimization(true)
         .with_out_dir("temp")
-        .with_source_dir("views");
+        .with_source_dir("views")
+        .with_trim_blocks(template::Kind::KTEXT, true)
+        .with_trim_blocks(template::Kind::KJSON, true)
+        .with_trim_blocks(template::Kind::KJSON, false);
 
     let extensions = option.extensions();
     assert_eq!(extensions.get("rshtm"), Some(&template::Kind::KHTML));
+    assert_eq!(
+        option.filters().get("upper"),
+        Some(&String::from("crate::upper"))
+    );
     assert_eq!(option.mod_name(), "test_views");
     assert!(option.need_optimization());
     assert_eq!(option.out_dir(), &Some(String::from("temp")));
     assert_eq!(option.source_dirs(), &["views"]);
+    assert!(option.need_trim_blocks(template::Kind::KTEXT));
+    assert!(!option.need_trim_blocks(template::Kind::KJSON));
+    assert!(!option.need_trim_blocks(template::Kind::KHTML));
 }
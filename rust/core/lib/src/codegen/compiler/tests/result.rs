@@ -8,3 +8,22 @@ fn compile_result() {
     let warnings = result.warnings();
     assert!(!warnings.is_empty())
 }
+
+#[test]
+fn validate_partial_references() {
+    let mut result = CompileResult::new();
+    result.add_view_mapping(
+        "views::shared::card::CardView".to_string(),
+        "CardView".to_string(),
+    );
+    result.add_partial_reference(
+        "views::sub::index::IndexView",
+        "shared/card",
+        "found".into(),
+    );
+    assert!(result.validate_partial_references().is_ok());
+
+    result.add_partial_reference("views::sub::index::IndexView", "~/card", "missing".into());
+    let err = result.validate_partial_references().unwrap_err();
+    assert!(err == "missing".into());
+}
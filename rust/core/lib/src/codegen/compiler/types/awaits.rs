@@ -0,0 +1,44 @@
+use crate::codegen::types::Block;
+
+impl<'a> Block<'a> {
+    // `@await exp` or `.await` in code, the view is rendered by `render_async` only.
+    pub(in crate::codegen::compiler::types) fn is_async(&self) -> bool {
+        match self {
+            Block::KAWAIT(_) => true,
+            // generated at module level, async helpers there are awaited by the view.
+            Block::KCOMMENT(_) | Block::KFUNCTIONS(_) => false,
+            Block::KBRANCH(header, span) => {
+                contains_await(header) || span.blocks().iter().any(Block::is_async)
+            }
+            Block::KCODE(span) | Block::KINLINEDCODE(span) | Block::KRAW(span)
+                if span.is_simple() =>
+            {
+                contains_await(&span.content())
+            }
+            _ => self.span().blocks().iter().any(Block::is_async),
+        }
+    }
+
+    // views rendering other views need their own `render_async`, so async partials and layouts are awaited.
+    pub(in crate::codegen::compiler::types) fn has_partial(&self) -> bool {
+        match self {
+            Block::KPARTIAL(_) => true,
+            _ => self.span().blocks().iter().any(Block::has_partial),
+        }
+    }
+
+    // layouts render the body of their view at `@render` and `@section`, so async bodies are awaited.
+    pub(in crate::codegen::compiler::types) fn has_render(&self) -> bool {
+        match self {
+            Block::KRENDER(_) | Block::KSECTION(_, _, _) => true,
+            _ => self.span().blocks().iter().any(Block::has_render),
+        }
+    }
+}
+
+// `.await` not followed by other ident chars, like `.awaited`.
+fn contains_await(code: &str) -> bool {
+    code.match_indices(".await").any(|(index, m)| {
+        !code[index + m.len()..].starts_with(|c: char| c.is_alphanumeric() || c == '_')
+    })
+}
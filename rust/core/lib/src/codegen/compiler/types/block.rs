@@ -1,4 +1,5 @@
 use crate::codegen::compiler::context::CodeGenContext;
+use crate::codegen::consts;
 use crate::codegen::types::Block;
 use crate::types::error;
 use crate::types::result;
@@ -14,6 +15,17 @@ impl<'a> Block<'a> {
     ) -> result::Result<Vec<TokenStream>> {
         let mut result = vec![];
         match self {
+            Block::KAWAIT(_) => {
+                let ts = self.to_inline_code_token_stream(context)?;
+                result.push(ts);
+            }
+            Block::KBRANCH(_, _) => {
+                // generated within its parent block.
+                return Err(error::CompileError::from_codegen(
+                    &self,
+                    "Wrong method call: couldn't generate code",
+                ));
+            }
             Block::KCODE(_) => {
                 let ts = self.to_code_token_stream(from, context)?;
                 result.push(ts);
@@ -26,15 +38,37 @@ impl<'a> Block<'a> {
                 let ts = self.to_content_token_stream(context)?;
                 result.push(ts);
             }
-            Block::KFUNCTIONS(_) => todo!(),
+            Block::KFLUSH(_) => {
+                // buffered writers of sections and bodies of views with layout ignore it.
+                result.push(quote! { writer.flush(); });
+            }
+            Block::KFOR(_) => {
+                let ts = self.to_for_token_stream(context)?;
+                result.push(ts);
+            }
+            Block::KFUNCTIONS(_) => {
+                // generated at module level.
+            }
+            Block::KIF(_) => {
+                let ts = self.to_if_token_stream(context)?;
+                result.push(ts);
+            }
             Block::KINLINEDCODE(_) => {
-                let ts = self.to_inline_code_token_stream()?;
+                let ts = self.to_inline_code_token_stream(context)?;
                 result.push(ts);
             }
             Block::KINLINEDCONTENT(_) => {
                 let ts = self.to_inline_content_token_stream(context)?;
                 result.push(ts);
             }
+            Block::KPARTIAL(_) => {
+                let ts = self.to_partial_token_stream(context)?;
+                result.push(ts);
+            }
+            Block::KRAW(_) => {
+                let ts = self.to_raw_token_stream()?;
+                result.push(ts);
+            }
             Block::KROOT(span) => {
                 // filter out layout and use was called before this.
                 if span.blocks().is_empty() {
@@ -44,20 +78,23 @@ impl<'a> Block<'a> {
                         "Wrong method call: couldn't generate code",
                     ));
                 } else {
-                    for block in span.blocks() {
-                        if !matches!(block, Block::KLAYOUT(_) | Block::KUSE(_)) {
-                            for rs in block.to_token_stream(from, context)? {
-                                result.push(rs);
-                            }
-                        }
-                    }
+                    let blocks = span.blocks().iter().filter(|block| {
+                        !matches!(
+                            block,
+                            Block::KINJECT(_)
+                                | Block::KLAYOUT(_)
+                                | Block::KMODEL(_)
+                                | Block::KUSE(_)
+                        )
+                    });
+                    result.extend(Self::blocks_to_token_stream(blocks, from, context)?);
                 }
             }
             Block::KRENDER(_) => {
-                let ts = self.to_render_token_stream()?;
+                let ts = self.to_render_token_stream(context)?;
                 result.push(ts);
             }
-            Block::KSECTION(_, _) => {
+            Block::KSECTION(_, _, _) => {
                 let ts = self.to_section_token_stream(context)?;
                 result.push(ts);
             }
@@ -65,7 +102,15 @@ impl<'a> Block<'a> {
                 let use_ts = self.to_use_token_stream()?;
                 result.push(use_ts);
             }
-            Block::KLAYOUT(_) => {
+            Block::KMATCH(_) => {
+                let ts = self.to_match_token_stream(context)?;
+                result.push(ts);
+            }
+            Block::KWHILE(_) => {
+                let ts = self.to_while_token_stream(context)?;
+                result.push(ts);
+            }
+            Block::KINJECT(_) | Block::KLAYOUT(_) | Block::KMODEL(_) => {
                 return Err(error::CompileError::from_codegen(
                     &self,
                     "Wrong method call: couldn't generate code",
@@ -76,6 +121,67 @@ impl<'a> Block<'a> {
         Ok(result)
     }
 
+    // adjacent static content, like the one around comments or of nested content blocks, is written at once.
+    pub(in crate::codegen::compiler::types) fn blocks_to_token_stream<'b>(
+        blocks: impl IntoIterator<Item = &'b Block<'a>>,
+        from: Option<&Block<'a>>,
+        context: &CodeGenContext,
+    ) -> result::Result<Vec<TokenStream>>
+    where
+        'a: 'b,
+    {
+        let mut result = vec![];
+        let mut static_content = String::new();
+        Self::collect_token_streams(blocks, from, context, &mut static_content, &mut result)?;
+        if !static_content.is_empty() {
+            result.push(Self::to_static_content_token_stream(
+                &static_content,
+                context,
+            ));
+        }
+        Ok(result)
+    }
+
+    fn collect_token_streams<'b>(
+        blocks: impl IntoIterator<Item = &'b Block<'a>>,
+        from: Option<&Block<'a>>,
+        context: &CodeGenContext,
+        static_content: &mut String,
+        result: &mut Vec<TokenStream>,
+    ) -> result::Result<()>
+    where
+        'a: 'b,
+    {
+        for block in blocks {
+            match block {
+                Block::KCONTENT(span) | Block::KINLINEDCONTENT(span) if span.is_simple() => {
+                    static_content.push_str(&block.to_static_content(context));
+                }
+                Block::KCONTENT(span) => {
+                    Self::collect_token_streams(
+                        span.blocks(),
+                        Some(block),
+                        context,
+                        static_content,
+                        result,
+                    )?;
+                }
+                _ => {
+                    let tsv = block.to_token_stream(from, context)?;
+                    if !tsv.is_empty() && !static_content.is_empty() {
+                        result.push(Self::to_static_content_token_stream(
+                            static_content,
+                            context,
+                        ));
+                        static_content.clear();
+                    }
+                    result.extend(tsv);
+                }
+            }
+        }
+        Ok(())
+    }
+
     pub(in crate::codegen::compiler::types) fn generate_render_token_stream(
         &self,
         context: &CodeGenContext,
@@ -87,43 +193,208 @@ impl<'a> Block<'a> {
             ));
         }
 
+        // awaits while rendering, `render_to` could only report it.
+        if !context.is_async() && self.is_async() {
+            return Ok(quote! {
+                fn render_to(&self, _context:&mut impl sbolt::types::Context, _writer: &mut impl sbolt::types::Writer) -> sbolt::types::result::RenderResult<()> {
+                    Err(sbolt::types::error::RuntimeError::async_view(&Self::name()))
+                }
+            });
+        }
+
         let ts = self.to_token_stream(Some(self), context)?;
-        let root_span = self.span();
-        let has_layout = root_span
-            .blocks()
-            .iter()
-            .any(|b| matches!(b, Block::KLAYOUT(_)));
-        match has_layout {
-            true => {
-                let view_root_mod_name = format_ident!("{}", context.options().mod_name());
+        // the body renders with a context lending the model, so the views it renders in the same context see it.
+        // a model moved out of the context is put back even on errors.
+        let ts = match self.generate_model_type_token_stream()? {
+            Some(model_type) => {
+                let body = quote! {
+                    #[allow(unused_variables)]
+                    let context = &mut sbolt_model_context;
+                    #[allow(unused_variables)]
+                    let model: &#model_type = &sbolt_model;
+                    #(#ts)*
+                    Ok(())
+                };
+                let result = match context.is_async() {
+                    true => quote! {
+                        let sbolt_result: sbolt::types::result::RenderResult<()> = async {
+                            #body
+                        }.await;
+                    },
+                    false => quote! {
+                        let sbolt_result = (|| -> sbolt::types::result::RenderResult<()> {
+                            #body
+                        })();
+                    },
+                };
+                quote! {
+                    let (sbolt_model, sbolt_model_taken) = sbolt::types::lend_model::<#model_type>(context, &Self::name())?;
+                    let sbolt_result = {
+                        let mut sbolt_model_context = sbolt::types::ModelViewContext::new(context, sbolt_model.clone());
+                        #result
+                        sbolt_result
+                    };
+                    sbolt::types::restore_model(context, sbolt_model, sbolt_model_taken);
+                    sbolt_result?;
+                }
+            }
+            None => quote! { #(#ts)* },
+        };
+        let injects = self.generate_inject_token_stream()?;
+        let ts = quote! {
+            #(#injects)*
+            #ts
+        };
+        let (signature, writer_type, render_layout) = match context.is_async() {
+            true => (
+                quote! { async fn render_async },
+                quote! { &mut (impl sbolt::types::Writer + Send) },
+                quote! { view.render_async(context, sbolt_writer).await },
+            ),
+            false => (
+                quote! { fn render_to },
+                quote! { &mut impl sbolt::types::Writer },
+                quote! { view.render_to(context, sbolt_writer) },
+            ),
+        };
+        let view_root_mod_name = format_ident!("{}", context.options().mod_name());
+        let default_layout = self.generate_default_layout_token_stream()?;
+        // like the views rendered as layouts, which have the body of another view.
+        // partials are rendered without it as well, the mark is taken before the body renders other views.
+        let (take_partial, default_layout) = match context.is_view_start_layout() {
+            true => (
+                quote! {
+                    let sbolt_partial = sbolt::types::take_partial_render(context);
+                },
+                quote! {
+                    match sbolt_partial || context.get_default_section().is_some() || sbolt::types::has_pending_body(context, &Self::name()) {
+                        true => None,
+                        false => #default_layout,
+                    }
+                },
+            ),
+            false => (quote! {}, default_layout),
+        };
+        match self.has_layout() {
+            // the expression could use the variables of the view, so the body is rendered before it.
+            true if self.has_layout_expression() => {
                 let code = quote! {
-                    fn render(&self, context:&mut impl sbolt::types::Context) -> sbolt::types::result::RenderResult<String> {
+                    #signature(&self, context:&mut impl sbolt::types::Context, sbolt_writer: #writer_type) -> sbolt::types::result::RenderResult<()> {
+                        #take_partial
+                        // the body is buffered as it's the default section of the layout.
                         let mut writer = self.create_writer(None);
-                        #(#ts)*
-                        match Self::layout() {
+                        writer.reserve(Self::size_hint());
+                        #ts
+                        // the layout set in the context for the request wins.
+                        let layout = match sbolt::types::take_layout(context) {
+                            Some(layout) => layout,
+                            None => #default_layout,
+                        };
+                        match layout {
                             Some(layout) => {
                                 for key in sbolt::types::resolve_layout_to_view_keys(&layout, &Self::name()) {
                                     if let Some(creator) = crate::#view_root_mod_name::resolve_view_creator(&key) {
+                                        // the body of the outer view is put back once the layout is rendered.
+                                        let sbolt_body = context.take_default_section();
+                                        let view = creator();
+                                        // the layout writes the body and its own content.
+                                        sbolt_writer.reserve(writer.len() + view.size_hint());
                                         context.set_default_section(writer.into_string());
+                                        let sbolt_result = #render_layout;
+                                        match sbolt_body {
+                                            Some(body) => {
+                                                context.set_default_section(body);
+                                            }
+                                            None => {
+                                                context.take_default_section();
+                                            }
+                                        }
+                                        return sbolt_result;
+                                    }
+                                }
+                                Err(sbolt::types::error::RuntimeError::layout_not_found(&layout, &Self::name()))
+                            }
+                            None => {
+                                sbolt_writer.write(&writer.into_string());
+                                Ok(())
+                            }
+                        }
+                    }
+                };
+                Ok(code)
+            }
+            true => {
+                let template_type = format_ident!("{}", consts::TEMPLATES_TYPE_NAME);
+                let (body_signature, render_body) = match context.is_async() {
+                    true => (
+                        quote! { async fn render_body_async },
+                        quote! { self.render_body_async(context).await? },
+                    ),
+                    false => (
+                        quote! { fn render_body },
+                        quote! { self.render_body(context)? },
+                    ),
+                };
+                let code = quote! {
+                    #signature(&self, context:&mut impl sbolt::types::Context, sbolt_writer: #writer_type) -> sbolt::types::result::RenderResult<()> {
+                        #take_partial
+                        // the layout set in the context for the request wins.
+                        let layout = match sbolt::types::take_layout(context) {
+                            Some(layout) => layout,
+                            None => #default_layout,
+                        };
+                        match layout {
+                            Some(layout) => {
+                                for key in sbolt::types::resolve_layout_to_view_keys(&layout, &Self::name()) {
+                                    if let Some(creator) = crate::#view_root_mod_name::resolve_view_creator(&key) {
+                                        // the body of the outer view is put back once the layout is rendered.
+                                        let sbolt_body = context.take_default_section();
                                         let view = creator();
-                                        return view.render(context);
+                                        let sbolt_layout = view.name();
+                                        // the body reserves its own share once the layout renders it.
+                                        sbolt_writer.reserve(view.size_hint());
+                                        // the body is rendered by the layout once it's needed, so the content before is written first.
+                                        let sbolt_pending = sbolt::types::set_pending_body(context, &sbolt_layout, Self::create as fn() -> #template_type);
+                                        let sbolt_result = #render_layout;
+                                        sbolt::types::take_pending_body::<fn() -> #template_type>(context, &sbolt_layout);
+                                        if let Some(pending) = sbolt_pending {
+                                            sbolt::types::set_pending_body(context, &sbolt_layout, pending);
+                                        }
+                                        match sbolt_body {
+                                            Some(body) => {
+                                                context.set_default_section(body);
+                                            }
+                                            None => {
+                                                context.take_default_section();
+                                            }
+                                        }
+                                        return sbolt_result;
                                     }
                                 }
                                 Err(sbolt::types::error::RuntimeError::layout_not_found(&layout, &Self::name()))
                             }
-                            None => Ok(writer.into_string()),
+                            None => {
+                                sbolt_writer.write(&#render_body);
+                                Ok(())
+                            }
                         }
                     }
+
+                    #body_signature(&self, #[allow(unused_variables)]context:&mut impl sbolt::types::Context) -> sbolt::types::result::RenderResult<String> {
+                        let mut writer = self.create_writer(None);
+                        writer.reserve(Self::size_hint());
+                        #ts
+                        Ok(writer.into_string())
+                    }
                 };
                 Ok(code)
             }
             false => {
                 let code = quote! {
-                    fn render(&self, #[allow(unused_variables)]context:&mut impl sbolt::types::Context) -> sbolt::types::result::RenderResult<String> {
-                        let mut writer = self.create_writer(None);
+                    #signature(&self, #[allow(unused_variables)]context:&mut impl sbolt::types::Context, #[allow(unused_mut)]mut writer: #writer_type) -> sbolt::types::result::RenderResult<()> {
                         // TODO: add other logic here
-                        #(#ts)*
-                        Ok(writer.into_string())
+                        #ts
+                        Ok(())
                     }
                 };
                 Ok(code)
@@ -1,6 +1,7 @@
 use crate::codegen::compiler::context::CodeGenContext;
+use crate::codegen::compiler::types::filter;
 use crate::codegen::types::Block;
-use crate::types::{error, result};
+use crate::types::{EscapeContext, error, result};
 use proc_macro2::TokenStream;
 use quote::quote;
 
@@ -48,8 +49,9 @@ impl<'a> Block<'a> {
 
     pub(in crate::codegen::compiler::types) fn to_inline_code_token_stream(
         &self,
+        context: &CodeGenContext,
     ) -> result::Result<TokenStream> {
-        if !matches!(self, Block::KINLINEDCODE(_)) {
+        if !matches!(self, Block::KINLINEDCODE(_) | Block::KAWAIT(_)) {
             return Err(error::CompileError::from_codegen(
                 &self,
                 "Wrong method call: couldn't generate code",
@@ -57,11 +59,43 @@ impl<'a> Block<'a> {
         }
         let code_span = self.span();
         if code_span.is_simple() {
+            context.add_expression();
             let raw_content = code_span.content();
-            match raw_content.parse::<TokenStream>() {
-                Ok(ts) => Ok(quote! {
-                    writer.write(&#ts.to_string());
-                }),
+            let (exp, spec) = split_format_spec(&raw_content);
+            if let Some(name) = filter::find_unknown_filter(exp, context.options().filters()) {
+                return Err(error::CompileError::from_codegen(
+                    self,
+                    &format!(
+                        "Unknown filter '{name}', register it by `CompilerOptions::with_filter` or use `@(({}))` for rust",
+                        exp.trim()
+                    ),
+                ));
+            }
+            // @await exp: the value is awaited before filters and format spec are applied.
+            let awaited;
+            let exp = match self {
+                Block::KAWAIT(_) => {
+                    let (value, filters) = filter::split_filters(exp, context.options().filters());
+                    awaited = format!("({}).await {filters}", value.trim());
+                    awaited.as_str()
+                }
+                _ => exp,
+            };
+            let exp = filter::apply_filters(exp, context.options().filters())
+                .unwrap_or_else(|| exp.to_string());
+            if let Some(spec) = spec {
+                return self.to_format_token_stream(&exp, spec, context);
+            }
+            match exp.parse::<TokenStream>() {
+                // written into the writer by `Render`, then as markup or `Display`, text isn't escaped by its writer.
+                Ok(ts) => {
+                    let escape_context = to_escape_context_token_stream(
+                        context.escape_context().unwrap_or(EscapeContext::KText),
+                    );
+                    Ok(quote! {
+                        (&&&sbolt::types::Output(&(#ts))).write_to(&mut writer, #escape_context)?;
+                    })
+                }
                 Err(err) => Err(error::CompileError::from_lex(&self, err)),
             }
         } else {
@@ -71,4 +105,135 @@ impl<'a> Block<'a> {
             ))
         }
     }
+
+    // @(exp:spec): written by `write!` with the format spec.
+    fn to_format_token_stream(
+        &self,
+        exp: &str,
+        spec: &str,
+        context: &CodeGenContext,
+    ) -> result::Result<TokenStream> {
+        let ts = exp
+            .parse::<TokenStream>()
+            .map_err(|err| error::CompileError::from_lex(self, err))?;
+        let format = format!("{{:{spec}}}");
+        let escape_context = match context.escape_context() {
+            // json output isn't markup.
+            Some(escape_context @ (EscapeContext::KJsonString | EscapeContext::KJsonValue)) => {
+                let escape_context = to_escape_context_token_stream(escape_context);
+                quote! { Some(#escape_context) }
+            }
+            Some(escape_context) => {
+                let escape_context = to_escape_context_token_stream(escape_context);
+                quote! { (&&sbolt::types::Output(sbolt_fmt_value)).escape_context(#escape_context) }
+            }
+            None => quote! { None },
+        };
+        Ok(quote! {
+            {
+                #[allow(unused_imports)]
+                use sbolt::types::{FormatMarkup as _, FormatValue as _};
+                let sbolt_fmt_value = &(#ts);
+                let sbolt_fmt_context = #escape_context;
+                write!(sbolt::types::FmtWriter::new(&mut writer, sbolt_fmt_context), #format, sbolt_fmt_value)?;
+            }
+        })
+    }
+
+    // @raw(exp): written as it is without escaping.
+    pub(in crate::codegen::compiler::types) fn to_raw_token_stream(
+        &self,
+    ) -> result::Result<TokenStream> {
+        if !matches!(self, Block::KRAW(_)) {
+            return Err(error::CompileError::from_codegen(
+                self,
+                "Wrong method call: couldn't generate code",
+            ));
+        }
+
+        let raw_content = self.span().content();
+        match raw_content.parse::<TokenStream>() {
+            Ok(ts) => Ok(quote! {
+                writer.write(&(#ts).to_string());
+            }),
+            Err(err) => Err(error::CompileError::from_lex(self, err)),
+        }
+    }
+}
+
+fn to_escape_context_token_stream(escape_context: EscapeContext) -> TokenStream {
+    match escape_context {
+        EscapeContext::KText => quote! { sbolt::types::EscapeContext::KText },
+        EscapeContext::KAttribute => quote! { sbolt::types::EscapeContext::KAttribute },
+        EscapeContext::KJsonString => quote! { sbolt::types::EscapeContext::KJsonString },
+        EscapeContext::KJsonValue => quote! { sbolt::types::EscapeContext::KJsonValue },
+        EscapeContext::KUnquotedAttribute => {
+            quote! { sbolt::types::EscapeContext::KUnquotedAttribute }
+        }
+    }
+}
+
+// `price:.2` => (`price`, Some(`.2`)), the spec is after the last ':' out of brackets and literals.
+pub(in crate::codegen::compiler::types) fn split_format_spec(exp: &str) -> (&str, Option<&str>) {
+    let bytes = exp.as_bytes();
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut split = None;
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' if in_string => index += 1,
+            b'"' => in_string = !in_string,
+            _ if in_string => {}
+            // char literal, like ':'
+            b'\'' if bytes.get(index + 2) == Some(&b'\'') => index += 2,
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => depth -= 1,
+            b':' if depth == 0 => {
+                let is_path =
+                    bytes.get(index + 1) == Some(&b':') || (index > 0 && bytes[index - 1] == b':');
+                if !is_path {
+                    split = Some(index);
+                }
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+
+    match split {
+        Some(index) if !exp[..index].trim().is_empty() && is_format_spec(&exp[index + 1..]) => {
+            (&exp[..index], Some(&exp[index + 1..]))
+        }
+        _ => (exp, None),
+    }
+}
+
+// [[fill]align][sign]['#']['0'][width]['.' precision][type], without `$` arguments.
+fn is_format_spec(spec: &str) -> bool {
+    let mut chars = spec.chars().peekable();
+    let mut rest = spec.chars().skip(1);
+    match (spec.chars().next(), rest.next()) {
+        (Some(_), Some('<' | '^' | '>')) => {
+            chars.next();
+            chars.next();
+        }
+        (Some('<' | '^' | '>'), _) => {
+            chars.next();
+        }
+        (None, _) => return false,
+        _ => {}
+    }
+    chars.next_if(|c| *c == '+' || *c == '-');
+    chars.next_if_eq(&'#');
+    while chars.next_if(|c| c.is_ascii_digit()).is_some() {}
+    if chars.next_if_eq(&'.').is_some() && chars.next_if(|c| c.is_ascii_digit()).is_none() {
+        return false;
+    }
+    while chars.next_if(|c| c.is_ascii_digit()).is_some() {}
+    let format_type = chars.collect::<String>();
+    matches!(
+        format_type.as_str(),
+        "" | "?" | "x?" | "X?" | "x" | "X" | "o" | "b" | "e" | "E"
+    )
 }
@@ -0,0 +1,55 @@
+use crate::codegen::compiler::context::CodeGenContext;
+use crate::codegen::types::Block;
+use crate::types::{error, result};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+impl<'a> Block<'a> {
+    pub(in crate::codegen::compiler::types) fn to_if_token_stream(
+        &self,
+        context: &CodeGenContext,
+    ) -> result::Result<TokenStream> {
+        if !matches!(self, Block::KIF(_)) {
+            return Err(error::CompileError::from_codegen(
+                self,
+                "Wrong method call: couldn't generate code",
+            ));
+        }
+
+        // every branch starts from where the content before @if ends, the first branch decides where it ends.
+        let start_state = context.scan_state();
+        let mut end_state = None;
+        let mut result = vec![];
+        for (index, branch) in self.span().blocks().iter().enumerate() {
+            let Block::KBRANCH(condition, span) = branch else {
+                return Err(error::CompileError::from_codegen(
+                    branch,
+                    "Expected branch in '@if' block",
+                ));
+            };
+
+            context.reset_scan_state(start_state);
+            let body = Self::blocks_to_token_stream(span.blocks(), Some(self), context)?;
+            end_state.get_or_insert(context.scan_state());
+
+            let ts = match (index, condition.is_empty()) {
+                (0, _) | (_, false) => {
+                    let condition = condition
+                        .parse::<TokenStream>()
+                        .map_err(|err| error::CompileError::from_lex(branch, err))?;
+                    match index {
+                        0 => quote! { if #condition { #(#body)* } },
+                        _ => quote! { else if #condition { #(#body)* } },
+                    }
+                }
+                _ => quote! { else { #(#body)* } },
+            };
+            result.push(ts);
+        }
+        context.reset_scan_state(end_state.unwrap_or(start_state));
+
+        Ok(quote! {
+            #(#result)*
+        })
+    }
+}
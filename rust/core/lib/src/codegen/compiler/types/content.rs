@@ -18,20 +18,10 @@ impl<'a> Block<'a> {
 
         let content_span = self.span();
         if content_span.is_simple() {
-            let raw_content = content_span.content();
-            let optimizer = context.create_optimizer(self);
-            let raw_content = optimizer.optimize(&raw_content);
-            let ts = quote! {
-                writer.write(#raw_content);
-            };
-            Ok(ts)
+            let raw_content = self.to_static_content(context);
+            Ok(Self::to_static_content_token_stream(&raw_content, context))
         } else {
-            let mut result = vec![];
-            for block in content_span.blocks() {
-                for ts in block.to_token_stream(Some(self), context)? {
-                    result.push(ts);
-                }
-            }
+            let result = Self::blocks_to_token_stream(content_span.blocks(), Some(self), context)?;
             Ok(quote! {
                 #(#result)*
             })
@@ -52,13 +42,8 @@ impl<'a> Block<'a> {
 
         let content_span = self.span();
         if content_span.is_simple() {
-            let raw_content = content_span.content();
-            let optimizer = context.create_optimizer(self);
-            let raw_content = optimizer.optimize(&raw_content);
-            let ts = quote! {
-                writer.write(#raw_content);
-            };
-            Ok(ts)
+            let raw_content = self.to_static_content(context);
+            Ok(Self::to_static_content_token_stream(&raw_content, context))
         } else {
             Err(error::CompileError::from_codegen(
                 &self,
@@ -66,4 +51,25 @@ impl<'a> Block<'a> {
             ))
         }
     }
+
+    // optimized content of simple content blocks, scanned to know where the next expression sits.
+    pub(in crate::codegen::compiler::types) fn to_static_content(
+        &self,
+        context: &CodeGenContext,
+    ) -> String {
+        let raw_content = self.span().content();
+        context.scan_content(&raw_content);
+        let optimizer = context.create_optimizer(self);
+        optimizer.optimize(&raw_content)
+    }
+
+    pub(in crate::codegen::compiler::types) fn to_static_content_token_stream(
+        content: &str,
+        context: &CodeGenContext,
+    ) -> TokenStream {
+        context.add_static_size(content.len());
+        quote! {
+            writer.write(#content);
+        }
+    }
 }
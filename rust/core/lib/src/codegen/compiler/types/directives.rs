@@ -70,6 +70,9 @@ impl<'a> Block<'a> {
             .collect::<Vec<_>>();
         match layout_blocks.len() {
             usize::MIN..=0 => Ok(None),
+            // @layout(exp) is selected in render.
+            1 if is_layout_expression(layout_blocks[0]) => Ok(None),
+            1 if is_layout_none(layout_blocks[0]) => Ok(None),
             1 => {
                 let content = layout_blocks[0].content();
                 Ok(Some(quote! {
@@ -84,4 +87,137 @@ impl<'a> Block<'a> {
             )),
         }
     }
+
+    // `@layout none;` opts out of the layout set by a `_viewstart`.
+    pub(in crate::codegen::compiler::types) fn has_layout(&self) -> bool {
+        self.span()
+            .blocks()
+            .iter()
+            .any(|b| matches!(b, Block::KLAYOUT(_)) && !is_layout_none(b))
+    }
+
+    // `@layout(exp)` could use the variables of the view, it's evaluated after the content.
+    pub(in crate::codegen::compiler::types) fn has_layout_expression(&self) -> bool {
+        self.span()
+            .blocks()
+            .iter()
+            .any(|b| matches!(b, Block::KLAYOUT(_)) && is_layout_expression(b))
+    }
+
+    // layout of the view without the override of the context, `Self::layout()` or `@layout(exp)`.
+    pub(in crate::codegen::compiler::types) fn generate_default_layout_token_stream(
+        &self,
+    ) -> result::Result<TokenStream> {
+        let root_span = self.span();
+        let layout_block = root_span
+            .blocks()
+            .iter()
+            .find(|b| matches!(b, Block::KLAYOUT(_)));
+        match layout_block {
+            Some(block) if is_layout_expression(block) => {
+                let expr = syn::parse_str::<syn::Expr>(block.content().trim()).map_err(|_| {
+                    error::CompileError::from_codegen(
+                        block,
+                        &format!(
+                            "Expected an expression in '@{}(...)'",
+                            consts::DIRECTIVE_KEYWORD_LAYOUT
+                        ),
+                    )
+                })?;
+                Ok(quote! {
+                    #expr.map(|layout| layout.to_string())
+                })
+            }
+            _ => Ok(quote! { Self::layout() }),
+        }
+    }
+
+    // @model type, the view takes it from the context as `model`.
+    pub(in crate::codegen::compiler::types) fn generate_model_type_token_stream(
+        &self,
+    ) -> result::Result<Option<TokenStream>> {
+        if !matches!(self, Block::KROOT(_)) {
+            return Err(error::CompileError::from_codegen(
+                &self,
+                "Wrong method call: couldn't generate code",
+            ));
+        }
+
+        let root_span = self.span();
+        let model_blocks = root_span
+            .blocks()
+            .iter()
+            .filter(|b| matches!(b, Block::KMODEL(_)))
+            .collect::<Vec<_>>();
+        match model_blocks.len() {
+            0 => Ok(None),
+            1 => {
+                let block = model_blocks[0];
+                let model_type =
+                    syn::parse_str::<syn::Type>(block.content().trim()).map_err(|_| {
+                        error::CompileError::from_codegen(block, "Expected a type after '@model'")
+                    })?;
+                Ok(Some(quote! { #model_type }))
+            }
+            _ => Err(error::CompileError::from_codegen(
+                self,
+                "Multiple model directives found",
+            )),
+        }
+    }
+
+    // @inject name: Type, cloned out of the context once at the start of render.
+    pub(in crate::codegen::compiler::types) fn generate_inject_token_stream(
+        &self,
+    ) -> result::Result<Vec<TokenStream>> {
+        if !matches!(self, Block::KROOT(_)) {
+            return Err(error::CompileError::from_codegen(
+                &self,
+                "Wrong method call: couldn't generate code",
+            ));
+        }
+
+        let mut names = vec![];
+        let mut result = vec![];
+        for block in self
+            .span()
+            .blocks()
+            .iter()
+            .filter(|b| matches!(b, Block::KINJECT(_)))
+        {
+            let content = block.content();
+            let (name, service_type) = content
+                .split_once(':')
+                .and_then(|(name, service_type)| {
+                    let name = syn::parse_str::<syn::Ident>(name.trim()).ok()?;
+                    let service_type = syn::parse_str::<syn::Type>(service_type.trim()).ok()?;
+                    Some((name, service_type))
+                })
+                .ok_or_else(|| {
+                    error::CompileError::from_codegen(block, "Expected '@inject name: Type'")
+                })?;
+            let key = name.to_string();
+            if names.contains(&key) {
+                return Err(error::CompileError::from_codegen(
+                    block,
+                    &format!("Service '{key}' is injected more than once"),
+                ));
+            }
+            result.push(quote! {
+                #[allow(unused_variables)]
+                let #name = sbolt::types::inject::<#service_type>(context, #key, &Self::name())?;
+            });
+            names.push(key);
+        }
+
+        Ok(result)
+    }
+}
+
+fn is_layout_expression(block: &Block) -> bool {
+    block.content().trim_start().starts_with('(')
+}
+
+fn is_layout_none(block: &Block) -> bool {
+    block.content().trim() == consts::LAYOUT_NONE
 }
@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+
+// `name | upper | truncate(20)` => `truncate_path(&(upper_path(&(name))), 20)`.
+// trailing segments are filters only if they are registered, unknown ones are rejected before.
+pub(in crate::codegen::compiler::types) fn apply_filters(
+    exp: &str,
+    filters: &HashMap<String, String>,
+) -> Option<String> {
+    let pipes = find_pipes(exp);
+    let mut start = exp.len();
+    let mut applied = vec![];
+    for pipe in pipes.iter().rev() {
+        match parse_filter(&exp[pipe + 1..start]) {
+            Some((name, args)) if filters.contains_key(name) => {
+                applied.push((&filters[name], args));
+                start = *pipe;
+            }
+            _ => break,
+        }
+    }
+
+    let value = exp[..start].trim();
+    if applied.is_empty() || value.is_empty() {
+        return None;
+    }
+
+    let result = applied
+        .iter()
+        .rev()
+        .fold(format!("({value})"), |acc, (path, args)| match args {
+            Some(args) if !args.trim().is_empty() => format!("{path}(&{acc}, {args})"),
+            _ => format!("{path}(&{acc})"),
+        });
+    Some(result)
+}
+
+// `name | upper` => (`name `, `| upper`), the value is awaited before the filters by `@await`.
+pub(in crate::codegen::compiler::types) fn split_filters<'e>(
+    exp: &'e str,
+    filters: &HashMap<String, String>,
+) -> (&'e str, &'e str) {
+    let mut start = exp.len();
+    for pipe in find_pipes(exp).iter().rev() {
+        match parse_filter(&exp[pipe + 1..start]) {
+            Some((name, _)) if filters.contains_key(name) => start = *pipe,
+            _ => break,
+        }
+    }
+    exp.split_at(start)
+}
+
+// `name` or `name(args)` after '|' is a filter, `flags | MASK` and `flags | mask(1)` are rejected
+// unless the name is registered, `(flags | MASK)` is the bitwise or.
+pub(in crate::codegen::compiler::types) fn find_unknown_filter<'e>(
+    exp: &'e str,
+    filters: &HashMap<String, String>,
+) -> Option<&'e str> {
+    let pipes = find_pipes(exp);
+    pipes.iter().enumerate().find_map(|(index, pipe)| {
+        let end = pipes.get(index + 1).copied().unwrap_or(exp.len());
+        match parse_filter(&exp[pipe + 1..end]) {
+            Some((name, _)) if !filters.contains_key(name) => Some(name),
+            _ => None,
+        }
+    })
+}
+
+// positions of single '|' out of brackets and literals.
+fn find_pipes(exp: &str) -> Vec<usize> {
+    let bytes = exp.as_bytes();
+    let mut pipes = vec![];
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' if in_string => index += 1,
+            b'"' => in_string = !in_string,
+            _ if in_string => {}
+            b'\'' if bytes.get(index + 2) == Some(&b'\'') => index += 2,
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => depth -= 1,
+            b'|' if bytes.get(index + 1) == Some(&b'|') => index += 1,
+            b'|' if depth == 0 => pipes.push(index),
+            _ => {}
+        }
+        index += 1;
+    }
+    pipes
+}
+
+// `name` or `name(args)`.
+fn parse_filter(segment: &str) -> Option<(&str, Option<&str>)> {
+    let segment = segment.trim();
+    let name_end = segment
+        .char_indices()
+        .find(|(index, c)| {
+            !(c.is_ascii_alphabetic() || *c == '_' || (*index > 0 && c.is_ascii_digit()))
+        })
+        .map(|(index, _)| index)
+        .unwrap_or(segment.len());
+    if name_end == 0 {
+        return None;
+    }
+
+    let (name, rest) = segment.split_at(name_end);
+    let rest = rest.trim_start();
+    if rest.is_empty() {
+        return Some((name, None));
+    }
+
+    match rest
+        .strip_prefix('(')
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        // `f(a) * g(b)` isn't a call of `f`.
+        Some(args) if is_balanced(args) => Some((name, Some(args))),
+        _ => None,
+    }
+}
+
+// brackets out of literals never close more than they open.
+fn is_balanced(args: &str) -> bool {
+    let bytes = args.as_bytes();
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' if in_string => index += 1,
+            b'"' => in_string = !in_string,
+            _ if in_string => {}
+            b'\'' if bytes.get(index + 2) == Some(&b'\'') => index += 2,
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' if depth == 0 => return false,
+            b')' | b']' | b'}' => depth -= 1,
+            _ => {}
+        }
+        index += 1;
+    }
+    depth == 0
+}
@@ -0,0 +1,29 @@
+use crate::codegen::types::Block;
+use crate::types::{error, result};
+use proc_macro2::TokenStream;
+
+impl<'a> Block<'a> {
+    // helpers in @functions are emitted at module level of the view.
+    pub(in crate::codegen::compiler::types) fn generate_functions_token_stream(
+        &self,
+    ) -> result::Result<Vec<TokenStream>> {
+        if !matches!(self, Block::KROOT(_)) {
+            return Err(error::CompileError::from_codegen(
+                self,
+                "Wrong method call: couldn't generate code",
+            ));
+        }
+
+        let mut functions_ts = vec![];
+        for block in self.span().blocks() {
+            if matches!(block, Block::KFUNCTIONS(_)) {
+                match block.content().parse::<TokenStream>() {
+                    Ok(ts) => functions_ts.push(ts),
+                    Err(err) => return Err(error::CompileError::from_lex(block, err)),
+                }
+            }
+        }
+
+        Ok(functions_ts)
+    }
+}
@@ -0,0 +1,67 @@
+use crate::codegen::parser::json;
+use crate::codegen::types::Block;
+use crate::types::{error, result};
+
+impl<'a> Block<'a> {
+    // check static json content with expressions replaced by placeholders, "@name" => "" and @age => 0.
+    // templates generating json from code(loops, sections...) could not be checked at build time.
+    pub(in crate::codegen::compiler::types) fn validate_json_skeleton(&self) -> result::Result<()> {
+        if !matches!(self, Block::KROOT(_)) {
+            return Err(error::CompileError::from_codegen(
+                self,
+                "Wrong method call: couldn't validate json",
+            ));
+        }
+
+        let mut skeleton = String::new();
+        let mut state = json::ScanState::default();
+        if self.push_json_skeleton(&mut skeleton, &mut state).is_none() {
+            return Ok(());
+        }
+
+        match json::validate_json(&skeleton) {
+            Ok(_) => Ok(()),
+            Err((offset, message)) => {
+                let start = skeleton.floor_char_boundary(offset.saturating_sub(20));
+                let end = skeleton.ceil_char_boundary((offset + 20).min(skeleton.len()));
+                Err(error::CompileError::CodeGen(
+                    self.location(),
+                    format!("Invalid json: {}", message),
+                    skeleton[start..end].to_string(),
+                ))
+            }
+        }
+    }
+
+    fn push_json_skeleton(&self, skeleton: &mut String, state: &mut json::ScanState) -> Option<()> {
+        match self {
+            Block::KCONTENT(span) | Block::KROOT(span) if span.has_blocks() => {
+                for block in span.blocks() {
+                    block.push_json_skeleton(skeleton, state)?;
+                }
+            }
+            Block::KCONTENT(span) | Block::KINLINEDCONTENT(span) => {
+                let content = span.content();
+                *state = json::scan_json(*state, &content);
+                skeleton.push_str(&content);
+            }
+            Block::KINLINEDCODE(_) | Block::KPARTIAL(_) | Block::KRAW(_)
+                if *state == json::ScanState::VALUE =>
+            {
+                skeleton.push('0');
+            }
+            // code without content only declares values.
+            Block::KCODE(span) if span.blocks().iter().any(|b| !matches!(b, Block::KCODE(_))) => {
+                return None;
+            }
+            Block::KFOR(_)
+            | Block::KIF(_)
+            | Block::KMATCH(_)
+            | Block::KRENDER(_)
+            | Block::KSECTION(_, _, _)
+            | Block::KWHILE(_) => return None,
+            _ => {}
+        }
+        Some(())
+    }
+}
@@ -0,0 +1,113 @@
+use crate::codegen::compiler::context::CodeGenContext;
+use crate::codegen::types::Block;
+use crate::types::{error, result};
+use proc_macro2::{TokenStream, TokenTree};
+use quote::quote;
+
+impl<'a> Block<'a> {
+    pub(in crate::codegen::compiler::types) fn to_for_token_stream(
+        &self,
+        context: &CodeGenContext,
+    ) -> result::Result<TokenStream> {
+        if !matches!(self, Block::KFOR(_)) {
+            return Err(error::CompileError::from_codegen(
+                self,
+                "Wrong method call: couldn't generate code",
+            ));
+        }
+
+        let branches = self.span().blocks();
+        let Some(Block::KBRANCH(header, _)) = branches.first() else {
+            return Err(error::CompileError::from_codegen(
+                self,
+                "Expected body in '@for' block",
+            ));
+        };
+        // split on the first `in` out of groups, the header could span lines.
+        let mut tokens = header
+            .parse::<TokenStream>()
+            .map_err(|err| error::CompileError::from_lex(self, err))?
+            .into_iter();
+        let pattern: TokenStream = tokens
+            .by_ref()
+            .take_while(|token| !matches!(token, TokenTree::Ident(ident) if ident == "in"))
+            .collect();
+        let expression: TokenStream = tokens.collect();
+        if pattern.is_empty() || expression.is_empty() {
+            return Err(error::CompileError::from_codegen(
+                self,
+                "Expected 'pattern in expression' in '@for'",
+            ));
+        }
+
+        // body and @empty both start from where the content before @for ends.
+        let start_state = context.scan_state();
+        let body = Self::to_branch_token_stream(&branches[0], self, context)?;
+        let end_state = context.scan_state();
+        let empty = match branches.get(1) {
+            Some(branch) => {
+                context.reset_scan_state(start_state);
+                let empty = Self::to_branch_token_stream(branch, self, context)?;
+                quote! {
+                    if sbolt_loop_index == 0 {
+                        #empty
+                    }
+                }
+            }
+            None => quote! {},
+        };
+        context.reset_scan_state(end_state);
+
+        // peek to know whether it's the last item.
+        Ok(quote! {
+            {
+                let mut sbolt_loop_iter = ::core::iter::IntoIterator::into_iter(#expression).peekable();
+                let mut sbolt_loop_index: usize = 0;
+                while let Some(#pattern) = sbolt_loop_iter.next() {
+                    #[allow(unused_variables)]
+                    let forloop = sbolt::types::ForLoop::new(sbolt_loop_index, sbolt_loop_iter.peek().is_none());
+                    sbolt_loop_index += 1;
+                    #body
+                }
+                #empty
+            }
+        })
+    }
+
+    pub(in crate::codegen::compiler::types) fn to_while_token_stream(
+        &self,
+        context: &CodeGenContext,
+    ) -> result::Result<TokenStream> {
+        if !matches!(self, Block::KWHILE(_)) {
+            return Err(error::CompileError::from_codegen(
+                self,
+                "Wrong method call: couldn't generate code",
+            ));
+        }
+        let Some(branch @ Block::KBRANCH(condition, _)) = self.span().blocks().first() else {
+            return Err(error::CompileError::from_codegen(
+                self,
+                "Expected body in '@while' block",
+            ));
+        };
+
+        let condition = condition
+            .parse::<TokenStream>()
+            .map_err(|err| error::CompileError::from_lex(branch, err))?;
+        let body = Self::to_branch_token_stream(branch, self, context)?;
+        Ok(quote! {
+            while #condition {
+                #body
+            }
+        })
+    }
+
+    fn to_branch_token_stream(
+        branch: &Block<'a>,
+        from: &Block<'a>,
+        context: &CodeGenContext,
+    ) -> result::Result<TokenStream> {
+        let body = Self::blocks_to_token_stream(branch.span().blocks(), Some(from), context)?;
+        Ok(quote! { #(#body)* })
+    }
+}
@@ -0,0 +1,56 @@
+use crate::codegen::compiler::context::CodeGenContext;
+use crate::codegen::types::Block;
+use crate::types::{error, result};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+impl<'a> Block<'a> {
+    // arms are generated as they are, so rust still checks the match is exhaustive.
+    pub(in crate::codegen::compiler::types) fn to_match_token_stream(
+        &self,
+        context: &CodeGenContext,
+    ) -> result::Result<TokenStream> {
+        if !matches!(self, Block::KMATCH(_)) {
+            return Err(error::CompileError::from_codegen(
+                self,
+                "Wrong method call: couldn't generate code",
+            ));
+        }
+
+        let expression = self
+            .content()
+            .parse::<TokenStream>()
+            .map_err(|err| error::CompileError::from_lex(self, err))?;
+        // every arm starts from where the content before @match ends, the first arm decides where it ends.
+        let start_state = context.scan_state();
+        let mut end_state = None;
+        let mut arms = vec![];
+        for arm in self.span().blocks() {
+            let Block::KBRANCH(pattern, span) = arm else {
+                return Err(error::CompileError::from_codegen(
+                    arm,
+                    "Expected arm in '@match' block",
+                ));
+            };
+            let pattern = pattern
+                .parse::<TokenStream>()
+                .map_err(|err| error::CompileError::from_lex(arm, err))?;
+
+            context.reset_scan_state(start_state);
+            let body = Self::blocks_to_token_stream(span.blocks(), Some(self), context)?;
+            end_state.get_or_insert(context.scan_state());
+            arms.push(quote! {
+                #pattern => {
+                    #(#body)*
+                }
+            });
+        }
+        context.reset_scan_state(end_state.unwrap_or(start_state));
+
+        Ok(quote! {
+            match #expression {
+                #(#arms)*
+            }
+        })
+    }
+}
@@ -1,8 +1,16 @@
+mod awaits;
 mod block;
 mod code;
+mod condition;
 mod content;
 mod directives;
 mod error;
+mod filter;
+mod functions;
+mod json;
+mod loops;
+mod matching;
+mod partial;
 mod render;
 mod section;
 mod template;
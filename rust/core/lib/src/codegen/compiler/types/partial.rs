@@ -0,0 +1,141 @@
+use crate::codegen::compiler::context::CodeGenContext;
+use crate::codegen::types::Block;
+use crate::types::{Location, error, result};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+impl<'a> Block<'a> {
+    // @partial(path, data): the view is resolved like layouts and its output is written as it is.
+    pub(in crate::codegen::compiler::types) fn to_partial_token_stream(
+        &self,
+        context: &CodeGenContext,
+    ) -> result::Result<TokenStream> {
+        if !matches!(self, Block::KPARTIAL(_)) {
+            return Err(error::CompileError::from_codegen(
+                self,
+                "Wrong method call: couldn't generate code",
+            ));
+        }
+
+        let args = self.span().blocks();
+        let path = match args.first() {
+            Some(block) => block
+                .content()
+                .parse::<TokenStream>()
+                .map_err(|err| error::CompileError::from_lex(block, err))?,
+            None => {
+                return Err(error::CompileError::from_codegen(
+                    self,
+                    "Expected path in '@partial()'",
+                ));
+            }
+        };
+
+        context.add_expression();
+        let is_async = context.is_async();
+        // a partial of the same kind writes into the writer of the view as it's rendered, so `@flush` reaches the sink,
+        // others are buffered and escaped by their own kind. the writer is erased, so nested partials don't create new types.
+        // async partials are awaited in `render_async`, the mark is removed even if the view didn't take it.
+        let render_with = |partial_context: TokenStream| {
+            let result = match is_async {
+                true => quote! {
+                    match sbolt_partial_view.kind() == Self::kind() {
+                        true => {
+                            let mut sbolt_partial_writer: &mut (dyn sbolt::types::Writer + Send) = &mut writer;
+                            sbolt_partial_writer.reserve(sbolt_partial_view.size_hint());
+                            sbolt_partial_view.render_async(#partial_context, &mut sbolt_partial_writer).await
+                        }
+                        false => {
+                            let mut sbolt_partial_writer = sbolt::types::KWriter::new(sbolt_partial_view.kind());
+                            sbolt_partial_writer.reserve(sbolt_partial_view.size_hint());
+                            sbolt_partial_view
+                                .render_async(#partial_context, &mut sbolt_partial_writer)
+                                .await
+                                .map(|_| writer.write(&sbolt_partial_writer.into_string()))
+                        }
+                    }
+                },
+                false => quote! {
+                    match sbolt_partial_view.kind() == Self::kind() {
+                        true => {
+                            let mut sbolt_partial_writer: &mut dyn sbolt::types::Writer = &mut writer;
+                            sbolt_partial_writer.reserve(sbolt_partial_view.size_hint());
+                            sbolt_partial_view.render_to(#partial_context, &mut sbolt_partial_writer)
+                        }
+                        false => sbolt_partial_view
+                            .render(#partial_context)
+                            .map(|output| writer.write(&output)),
+                    }
+                },
+            };
+            quote! {
+                {
+                    let sbolt_partial_view = sbolt_partial_creator();
+                    sbolt::types::set_partial_render(#partial_context);
+                    let sbolt_partial_result = #result;
+                    sbolt::types::take_partial_render(#partial_context);
+                    sbolt_partial_result?;
+                }
+            }
+        };
+        let render = match args.get(1) {
+            // render with the same context.
+            None => render_with(quote! { context }),
+            // evaluated before the child context borrows the current one.
+            Some(block) => {
+                let data = block
+                    .content()
+                    .parse::<TokenStream>()
+                    .map_err(|err| error::CompileError::from_lex(block, err))?;
+                let render_partial = render_with(quote! { &mut sbolt_partial_context });
+                quote! {
+                    {
+                        let sbolt_partial_data = #data;
+                        let mut sbolt_partial_context = sbolt::types::ChildViewContext::new(context);
+                        sbolt::types::Context::set_data(
+                            &mut sbolt_partial_context,
+                            sbolt::types::PARTIAL_DATA_KEY,
+                            move || sbolt_partial_data,
+                        );
+                        #render_partial
+                    }
+                }
+            }
+        };
+
+        let view_root_mod_name = format_ident!("{}", context.options().mod_name());
+        Ok(quote! {
+            {
+                let sbolt_partial_path = #path;
+                let sbolt_partial_creator = sbolt::types::resolve_layout_to_view_keys(&sbolt_partial_path, &Self::name())
+                    .iter()
+                    .find_map(|key| crate::#view_root_mod_name::resolve_view_creator(key))
+                    .ok_or_else(|| sbolt::types::error::RuntimeError::partial_not_found(&sbolt_partial_path, &Self::name()))?;
+                #render
+            }
+        })
+    }
+
+    // literal partial paths, validated once all views are compiled.
+    pub(in crate::codegen::compiler::types) fn collect_partial_paths(
+        &self,
+        paths: &mut Vec<(String, Location, String)>,
+    ) {
+        match self {
+            Block::KPARTIAL(span) => {
+                let literal = span
+                    .blocks()
+                    .first()
+                    .and_then(|block| syn::parse_str::<syn::LitStr>(block.content().trim()).ok());
+                if let Some(path) = literal {
+                    paths.push((path.value(), self.location(), self.content()));
+                }
+            }
+            _ => {
+                for block in self.span().blocks() {
+                    block.collect_partial_paths(paths);
+                }
+            }
+        }
+    }
+}
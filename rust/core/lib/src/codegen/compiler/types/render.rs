@@ -1,11 +1,14 @@
+use crate::codegen::compiler::context::CodeGenContext;
+use crate::codegen::consts;
 use crate::codegen::types::Block;
 use crate::types::{error, result};
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 
 impl<'a> Block<'a> {
     pub(in crate::codegen::compiler::types) fn to_render_token_stream(
         &self,
+        context: &CodeGenContext,
     ) -> result::Result<TokenStream> {
         if !matches!(self, Block::KRENDER(_)) {
             return Err(error::CompileError::from_codegen(
@@ -15,9 +18,19 @@ impl<'a> Block<'a> {
         }
 
         let content_span = self.span();
-        match content_span.blocks().len() {
+        // the fallback block is the last one, `@render(name) { ... }`.
+        let (params, fallback) = match content_span.blocks().split_last() {
+            Some((last, params)) if matches!(last, Block::KSECTION(_, _, _)) => (
+                params,
+                Some(self.to_render_fallback_token_stream(last, context)?),
+            ),
+            _ => (content_span.blocks().as_slice(), None),
+        };
+        let pending_body = Self::to_pending_body_token_stream(context);
+        match params.len() {
             0 => {
                 let ts = quote! {
+                    #pending_body
                     // No parameters in @render()
                    let default_section = context.get_default_section();
                    match default_section {
@@ -29,34 +42,33 @@ impl<'a> Block<'a> {
                 };
                 return Ok(ts);
             }
-            1 => {
-                // todo: envaluated dynamically.
-                let section_name = content_span.blocks()[0].content();
-                let ts = quote! {
-                    // 1 parameters in @render()
-                   let section_name = #section_name;
-                   let sections = context.get_section(section_name);
-                   match sections {
-                       Some(contents) => {
-                        for content in contents {
-                            writer.write(&content)
-                        }
-                       },
-                       None => {
-                           return Err(sbolt::types::error::RuntimeError::NotFound(format!("Section `{}` not found", section_name), "".to_string()))
-                       }
-                   }
+            1 | 2 => {
+                let section_name = self.to_render_section_name_token_stream(&params[0])?;
+                let not_found = quote! {
+                    return Err(sbolt::types::error::RuntimeError::NotFound(format!("Section `{}` not found", section_name), "".to_string()))
+                };
+                // required by default, the fallback is rendered whether the section is required or not.
+                let missing = match (fallback, params.get(1)) {
+                    (Some(fallback), _) => fallback,
+                    (None, None) => not_found,
+                    (None, Some(required)) => match self.to_render_required_expr(required)? {
+                        syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Bool(required),
+                            ..
+                        }) => match required.value {
+                            true => not_found,
+                            false => quote! {},
+                        },
+                        required => quote! {
+                            if #required {
+                                #not_found
+                            }
+                        },
+                    },
                 };
-                return Ok(ts);
-            }
-            2 => {
-                // todo: envaluated dynamically.
-                let section_name = content_span.blocks()[0].content();
-                let is_required = content_span.blocks()[1].content(); // let is_bool = text.parse::<bool>().is_ok();
                 let ts = quote! {
-                    // 2 parameters in @render()
-                   let section_name = #section_name;
-                   let is_required = #is_required.parse::<bool>().is_ok();
+                   #pending_body
+                   let section_name: &str = #section_name;
                    let sections = context.get_section(section_name);
                    match sections {
                        Some(contents) => {
@@ -64,15 +76,12 @@ impl<'a> Block<'a> {
                             writer.write(&content)
                         }
                        },
-                       None if is_required => {
-                           return Err(sbolt::types::error::RuntimeError::NotFound(format!("Section `{}` not found", section_name), "".to_string()))
-                       },
-                       _ => {
-                          /*ignore */
+                       None => {
+                           #missing
                        }
                    }
                 };
-                return Ok(ts);
+                Ok(ts)
             }
             _ => Err(error::CompileError::from_codegen(
                 &self,
@@ -80,4 +89,78 @@ impl<'a> Block<'a> {
             )),
         }
     }
+
+    // the body of the view using this one as its layout, it's rendered at the first `@render` or `@section`.
+    pub(in crate::codegen::compiler::types) fn to_pending_body_token_stream(
+        context: &CodeGenContext,
+    ) -> TokenStream {
+        let template_type = format_ident!("{}", consts::TEMPLATES_TYPE_NAME);
+        let render_body = match context.is_async() {
+            true => quote! { sbolt_pending().render_body_async(context).await? },
+            false => quote! { sbolt_pending().render_body(context)? },
+        };
+        quote! {
+            if let Some(sbolt_pending) = sbolt::types::take_pending_body::<fn() -> #template_type>(context, &Self::name()) {
+                let sbolt_pending_body = #render_body;
+                context.set_default_section(sbolt_pending_body);
+            }
+        }
+    }
+
+    // a bare name like `@render(footer)` is the literal name, other expressions are evaluated to `&str`.
+    fn to_render_section_name_token_stream(&self, name: &Block<'a>) -> result::Result<TokenStream> {
+        let name = name.content();
+        let name = name.trim();
+        if let Ok(ident) = syn::parse_str::<syn::Ident>(name) {
+            let name = ident.to_string();
+            return Ok(quote! { #name });
+        }
+        match syn::parse_str::<syn::Expr>(name) {
+            Ok(syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(name),
+                ..
+            })) => Ok(quote! { #name }),
+            Ok(syn::Expr::Lit(_)) | Err(_) => Err(error::CompileError::from_codegen(
+                &self,
+                &format!(
+                    "Expected section name or expression as the first parameter of '@render', found '{name}'"
+                ),
+            )),
+            Ok(expr) => Ok(quote! { &(#expr) }),
+        }
+    }
+
+    fn to_render_required_expr(&self, required: &Block<'a>) -> result::Result<syn::Expr> {
+        let required = required.content();
+        let required = required.trim();
+        // literals other than `true` and `false` are rejected.
+        syn::parse_str::<syn::Expr>(required)
+            .ok()
+            .filter(|expr| match expr {
+                syn::Expr::Lit(syn::ExprLit { lit, .. }) => matches!(lit, syn::Lit::Bool(_)),
+                _ => true,
+            })
+            .ok_or_else(|| {
+                error::CompileError::from_codegen(
+                    &self,
+                    &format!(
+                        "Expected boolean expression as the second parameter of '@render', found '{required}'"
+                    ),
+                )
+            })
+    }
+
+    fn to_render_fallback_token_stream(
+        &self,
+        fallback: &Block<'a>,
+        context: &CodeGenContext,
+    ) -> result::Result<TokenStream> {
+        let span = fallback.span();
+        let blocks = match span.is_simple() {
+            true => vec![fallback.to_content()],
+            false => span.blocks().clone(),
+        };
+        let tsv = Self::blocks_to_token_stream(&blocks, Some(self), context)?;
+        Ok(quote! { #(#tsv)* })
+    }
 }
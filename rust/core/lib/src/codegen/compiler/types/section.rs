@@ -1,16 +1,16 @@
-use crate::codegen::compiler::context::CodeGenContext;
-use crate::codegen::types::Block;
+use crate::codegen::compiler::context::{CodeGenContext, ScanState};
+use crate::codegen::types::{Block, SectionMode};
 use crate::types::{error, result};
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 
 impl<'a> Block<'a> {
     pub(in crate::codegen::compiler::types) fn to_section_token_stream(
         &self,
         context: &CodeGenContext,
     ) -> result::Result<TokenStream> {
-        let (name, span) = match self {
-            Block::KSECTION(name, span) => (name, span),
+        let (name, mode, span) = match self {
+            Block::KSECTION(name, mode, span) => (name, mode, span),
             _ => {
                 return Err(error::CompileError::from_codegen(
                     &self,
@@ -19,40 +19,48 @@ impl<'a> Block<'a> {
             }
         };
 
+        let add_section = match mode {
+            SectionMode::KDEFAULT => format_ident!("add_section"),
+            SectionMode::KAPPEND => format_ident!("append_section"),
+            SectionMode::KPREPEND => format_ident!("prepend_section"),
+            SectionMode::KREPLACE => format_ident!("replace_section"),
+        };
+
+        // the sections of the body win over the ones of its layout.
+        let pending_body = Self::to_pending_body_token_stream(context);
+        // section is written by its own writer.
+        let state = context.reset_scan_state(ScanState::default());
         let ts = match span.is_simple() {
             true => {
                 // simple is content section.
-                let raw_content = span.content();
-                let optimizer = context.create_optimizer(self);
-                let raw_content = optimizer.optimize(&raw_content);
+                let raw_content = self.to_static_content(context);
+                context.add_static_size(raw_content.len());
                 quote! {
+                    #pending_body
                     let section_name = #name;
                     let inner_writer = {
                         let mut writer = self.create_writer(None);
                         writer.write(#raw_content);
                         writer
                     };
-                    context.add_section(section_name, inner_writer.into_string());
+                    context.#add_section(section_name, inner_writer.into_string());
                 }
             }
             false => {
-                let mut tsv = vec![];
-                for block in span.blocks() {
-                    for rs in block.to_token_stream(Some(self), context)? {
-                        tsv.push(rs);
-                    }
-                }
+                let tsv = Self::blocks_to_token_stream(span.blocks(), Some(self), context)?;
                 quote! {
+                    #pending_body
                     let section_name = #name;
                     let section_writer = {
                         let mut writer = self.create_writer(None);
                         #(#tsv)*
                         writer
                     };
-                    context.add_section(section_name, section_writer.into_string());
+                    context.#add_section(section_name, section_writer.into_string());
                 }
             }
         };
+        context.reset_scan_state(state);
         Ok(ts)
     }
 }
@@ -4,8 +4,9 @@ use crate::codegen::compiler::context::CodeGenContext;
 use crate::codegen::compiler::fsutil;
 use crate::codegen::compiler::name;
 use crate::codegen::consts;
+use crate::codegen::types::Block;
 use crate::codegen::types::Template;
-use crate::types::result;
+use crate::types::{error, result};
 use proc_macro2::TokenStream;
 use quote::format_ident;
 use quote::quote;
@@ -34,6 +35,24 @@ impl<'a> Template<'a> {
         let template_type_ts = syn::parse_str::<TokenStream>(&template_type_full_name)?;
         let imports_content = self.block().generate_imports_token_stream()?;
         let layout_content = self.block().generate_layout_token_stream()?;
+        let functions_content = self.block().generate_functions_token_stream()?;
+        // typed entry point, the model is checked by the compiler.
+        let model_type = self.block().generate_model_type_token_stream()?;
+        let model_content = model_type.as_ref().map(|model_type| {
+            quote! {
+                pub(crate) fn render_model(&self, model: #model_type, context: &mut impl sbolt::types::Context) -> sbolt::types::result::RenderResult<String> {
+                    sbolt::types::set_model(context, model);
+                    sbolt::types::Template::render(self, context)
+                }
+            }
+        });
+        // named by the view, so the root module could refer to the type resolved by the imports of the view.
+        let model_alias = model_type.as_ref().map(|model_type| {
+            let alias = format_ident!("{}Model", view_name);
+            quote! {
+                pub(crate) type #alias = #model_type;
+            }
+        });
         let kind = match self.kind() {
             crate::types::template::Kind::KHTML => {
                 quote! { sbolt::types::template::Kind::KHTML }
@@ -45,15 +64,39 @@ impl<'a> Template<'a> {
                 quote! { sbolt::types::template::Kind::KTEXT }
             }
         };
+        if self.kind() == crate::types::template::Kind::KJSON
+            && compiler_options.need_json_validation()
+        {
+            self.block().validate_json_skeleton()?;
+        }
         // a view must have render method.
-        let render_content = self
-            .block()
-            .generate_render_token_stream(&CodeGenContext::new(self.kind(), compiler_options))?;
+        let context = CodeGenContext::new(self.kind(), compiler_options)
+            .with_view_start_layout(self.has_view_start_layout());
+        let render_content = self.block().generate_render_token_stream(&context)?;
+        // sync views without layouts, partials and `@render` keep the default one calling `render_to`.
+        let async_context = CodeGenContext::new(self.kind(), compiler_options)
+            .with_view_start_layout(self.has_view_start_layout())
+            .with_async(true);
+        let render_async_content = match self.block().is_async()
+            || self.block().has_layout()
+            || self.block().has_partial()
+            || self.block().has_render()
+        {
+            true => Some(self.block().generate_render_token_stream(&async_context)?),
+            false => None,
+        };
+        // `render_to` of async views only reports the error, the content is counted by `render_async`.
+        let size_hint = context.size_hint().max(async_context.size_hint());
         let code = quote! {
             use #template_type_ts;
             use sbolt::types::Template as _;
+            #[allow(unused_imports)]
             use sbolt::types::Writer;
+            #[allow(unused_imports)]
+            use sbolt::types::{WriteDisplay as _, WriteMarkup as _, WriteRender as _};
             #(#imports_content)*
+            #(#functions_content)*
+            #model_alias
 
             pub struct #view_name;
             impl #view_name {
@@ -65,6 +108,9 @@ impl<'a> Template<'a> {
                    #template_type::#view_type(#view_name::new())
                 }
 
+                #model_content
+
+                #[allow(dead_code)]
                 fn create_writer(&self, kind: Option<sbolt::types::template::Kind>) -> sbolt::types::KWriter {
                     let kind = match kind {
                         Some(k) => k,
@@ -74,6 +120,9 @@ impl<'a> Template<'a> {
                         sbolt::types::template::Kind::KHTML => {
                             sbolt::types::KWriter::KHtml(sbolt::types::HtmlWriter::new())
                         },
+                        sbolt::types::template::Kind::KJSON => {
+                            sbolt::types::KWriter::KJson(sbolt::types::JsonWriter::new())
+                        },
                         _ => sbolt::types::KWriter::KText(String::new()),
                     }
                 }
@@ -91,7 +140,13 @@ impl<'a> Template<'a> {
 
                 #layout_content
 
+                fn size_hint() -> usize {
+                    #size_hint
+                }
+
                 #render_content
+
+                #render_async_content
             }
         };
         Ok(code)
@@ -113,6 +168,9 @@ impl<'a> Template<'a> {
         let full_view_name = name::create_normalized_name(&Some(namespace), &view_name);
         let view_type = name::create_view_type_name(&full_view_name);
         result.add_view_mapping(full_view_name.to_string(), view_name.clone());
+        if self.block().generate_model_type_token_stream()?.is_some() {
+            result.add_model_view(&full_view_name);
+        }
         syn::parse_str::<Ident>(&view_name).map_err(|_| {
             format!(
                 "'{}' is not a valid ident name, please change the file name",
@@ -127,7 +185,135 @@ impl<'a> Template<'a> {
         })?;
         let code =
             self.to_token_stream(&view_name, &view_type, &full_view_name, compiler_options)?;
+        let mut partial_paths = vec![];
+        self.block().collect_partial_paths(&mut partial_paths);
+        for (path, location, detail) in partial_paths {
+            let error = error::CompileError::CodeGen(
+                location,
+                format!("Partial '{path}' not found for View `{full_view_name}`"),
+                detail,
+            );
+            result.add_partial_reference(&full_view_name, &path, error);
+        }
         fsutil::write_code_to_file(&target, &code)?;
         Ok(result)
     }
 }
+
+impl<'a> Template<'a> {
+    // merges `_imports` and `_viewstart` of the folders from the root down to the view,
+    // the ones declared later win: nearer folders over parents and the view over all of them.
+    pub(in crate::codegen::compiler) fn with_shared(
+        self,
+        imports: &[Template<'a>],
+        view_starts: &[Template<'a>],
+    ) -> result::Result<Self> {
+        // shared files only apply to the views of their kind, like `_viewstart.rshtml` to html views.
+        let imports = imports
+            .iter()
+            .filter(|t| t.kind() == self.kind())
+            .collect::<Vec<_>>();
+        let view_starts = view_starts
+            .iter()
+            .filter(|t| t.kind() == self.kind())
+            .collect::<Vec<_>>();
+        if imports.is_empty() && view_starts.is_empty() {
+            return Ok(self);
+        }
+
+        let mut directives = vec![];
+        let mut codes = vec![];
+        let mut layout = None;
+        for (shared, file_name) in imports
+            .iter()
+            .map(|t| (t, consts::VIEW_IMPORTS_FILE_NAME))
+            .chain(
+                view_starts
+                    .iter()
+                    .map(|t| (t, consts::VIEW_START_FILE_NAME)),
+            )
+        {
+            let is_view_start = file_name == consts::VIEW_START_FILE_NAME;
+            for block in shared.block().span().blocks() {
+                match block {
+                    Block::KUSE(_) | Block::KINJECT(_) => directives.push(block.clone()),
+                    Block::KLAYOUT(_) if is_view_start => layout = Some(block.clone()),
+                    Block::KCODE(_) if is_view_start => codes.push(block.clone()),
+                    Block::KCOMMENT(_) => {}
+                    Block::KCONTENT(_) if block.content().trim().is_empty() => {}
+                    _ => {
+                        return Err(error::CompileError::from_codegen(
+                            block,
+                            &match is_view_start {
+                                true => format!(
+                                    "Only '@{}', '@{}', '@{}' and code blocks are allowed in {}",
+                                    consts::DIRECTIVE_KEYWORD_LAYOUT,
+                                    consts::DIRECTIVE_KEYWORD_USE,
+                                    consts::DIRECTIVE_KEYWORD_INJECT,
+                                    file_name
+                                ),
+                                false => format!(
+                                    "Only '@{}' and '@{}' are allowed in {}",
+                                    consts::DIRECTIVE_KEYWORD_USE,
+                                    consts::DIRECTIVE_KEYWORD_INJECT,
+                                    file_name
+                                ),
+                            },
+                        ));
+                    }
+                }
+            }
+        }
+
+        let Block::KROOT(span) = self.block() else {
+            return Ok(self);
+        };
+        let mut span = span.clone();
+        // the same `@use` twice doesn't compile and an `@inject` name is taken once,
+        // so the nearest one wins and the view's own always does.
+        let own_keys = span
+            .blocks()
+            .iter()
+            .filter(|b| matches!(b, Block::KUSE(_) | Block::KINJECT(_)))
+            .map(shared_directive_key)
+            .collect::<Vec<_>>();
+        let mut blocks: Vec<Block<'a>> = vec![];
+        for block in directives {
+            let key = shared_directive_key(&block);
+            if !own_keys.contains(&key) {
+                blocks.retain(|b| shared_directive_key(b) != key);
+                blocks.push(block);
+            }
+        }
+        let mut view_start_layout = false;
+        if let Some(layout) = layout
+            && !span.blocks().iter().any(|b| matches!(b, Block::KLAYOUT(_)))
+        {
+            blocks.push(layout);
+            view_start_layout = true;
+        }
+        blocks.extend(codes);
+        span.prepend_blocks(blocks);
+        Ok(Template::new(
+            self.namespace().cloned(),
+            Block::new_root(span),
+            self.kind(),
+        )
+        .with_view_start_layout(view_start_layout))
+    }
+}
+
+// `use a::b` by the path, `inject name: Type` by the name.
+fn shared_directive_key(block: &Block) -> String {
+    let content = block.content();
+    match block {
+        Block::KINJECT(_) => format!(
+            "inject {}",
+            content
+                .split_once(':')
+                .map_or(content.as_str(), |(name, _)| name)
+                .trim()
+        ),
+        _ => format!("use {}", content.trim()),
+    }
+}
@@ -0,0 +1,112 @@
+#![cfg(test)]
+use crate::{
+    codegen::{
+        CompilerOptions,
+        compiler::context::CodeGenContext,
+        types::{Block, Template},
+    },
+    types::{result, template::Kind},
+};
+
+#[test]
+fn to_token_stream_from_await() -> result::Result<()> {
+    let raw_content = r#"<h1>@await repo.title()</h1>"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    let block = &template.block().span().blocks()[1];
+    assert!(matches!(block, Block::KAWAIT(_)));
+
+    let context = CodeGenContext::new(Kind::KHTML, &options).with_async(true);
+    let ts = block.to_token_stream(None, &context)?;
+    assert_eq!(ts.len(), 1);
+    assert_eq!(
+        ts[0].to_string(),
+        quote::quote! {
+            (&&&sbolt::types::Output(&((repo.title()).await))).write_to(&mut writer, sbolt::types::EscapeContext::KText)?;
+        }
+        .to_string()
+    );
+    Ok(())
+}
+
+#[test]
+fn to_token_stream_from_await_with_filters() -> result::Result<()> {
+    let raw_content = r#"<h1>@await (repo.title() | upper)</h1>"#;
+    let options = CompilerOptions::default().with_filter("upper", "crate::upper");
+    let template = Template::from(&raw_content, None, Kind::KTEXT, &options)?;
+    let block = &template.block().span().blocks()[1];
+
+    let context = CodeGenContext::new(Kind::KTEXT, &options).with_async(true);
+    let ts = block.to_token_stream(None, &context)?;
+    assert_eq!(
+        ts[0].to_string(),
+        quote::quote! {
+            (&&&sbolt::types::Output(&(crate::upper(&((repo.title()).await))))).write_to(&mut writer, sbolt::types::EscapeContext::KText)?;
+        }
+        .to_string()
+    );
+    Ok(())
+}
+
+#[test]
+fn is_async() -> result::Result<()> {
+    let options = CompilerOptions::default();
+    for (raw_content, expected) in [
+        ("<p>@await load()</p>", true),
+        ("@{ let items = load().await; }<p>@items.len()</p>", true),
+        ("@if ready().await {<p>ready</p>}", true),
+        ("@for item in load().await {<p>@item</p>}", true),
+        ("<p>@(load().await)</p>", true),
+        (
+            "@functions { async fn load() -> u8 { ready().await } }<p>x.await</p>",
+            false,
+        ),
+        ("@{ let awaited = state.awaited; }<p>@awaited</p>", false),
+    ] {
+        let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+        assert_eq!(template.block().is_async(), expected, "{raw_content}");
+    }
+    Ok(())
+}
+
+#[test]
+fn generate_render_token_stream_with_await() -> result::Result<()> {
+    let raw_content = r#"<p>@await load()</p>"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+
+    // rendering synchronously is reported.
+    let context = CodeGenContext::new(Kind::KHTML, &options);
+    let ts = template.block().generate_render_token_stream(&context)?;
+    let code = ts.to_string();
+    assert!(code.starts_with("fn render_to"));
+    assert!(code.contains("RuntimeError :: async_view"));
+
+    let context = CodeGenContext::new(Kind::KHTML, &options).with_async(true);
+    let ts = template.block().generate_render_token_stream(&context)?;
+    let code = ts.to_string();
+    assert!(code.starts_with("async fn render_async"));
+    assert!(code.contains("(load ()) . await"));
+    Ok(())
+}
+
+#[test]
+fn generate_render_token_stream_with_partial() -> result::Result<()> {
+    let raw_content = r#"@layout frame;<p>@partial("card")</p>"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    assert!(template.block().has_partial());
+    assert!(!template.block().is_async());
+
+    // layouts and partials are awaited as they could be async.
+    let context = CodeGenContext::new(Kind::KHTML, &options).with_async(true);
+    let code = template
+        .block()
+        .generate_render_token_stream(&context)?
+        .to_string();
+    assert!(code.contains(
+        "sbolt_partial_view . render_async (context , & mut sbolt_partial_writer) . await"
+    ));
+    assert!(code.contains("view . render_async (context , sbolt_writer) . await"));
+    Ok(())
+}
@@ -40,16 +40,15 @@ fn generate_render_token_stream_from_non_root() {
 }
 
 #[test]
-#[should_panic]
-fn to_token_stream_from_function() {
-    // this won't happen as parser will catch this error.
+fn to_token_stream_from_function() -> result::Result<()> {
+    // functions are generated at module level, nothing in render.
     let span = Span::new("");
     let root_block = Block::new_functions(span);
     let options = CompilerOptions::default();
     let context = CodeGenContext::new(Kind::KHTML, &options);
-    root_block
-        .to_token_stream(Some(&root_block), &context)
-        .expect("Not implemented yet, should panic");
+    let ts = root_block.to_token_stream(Some(&root_block), &context)?;
+    assert!(ts.is_empty());
+    Ok(())
 }
 
 #[test]
@@ -66,3 +65,61 @@ fn to_token_stream_from_comments() -> result::Result<()> {
     block.to_token_stream(Some(&block), &context)?;
     Ok(())
 }
+
+#[test]
+fn to_token_stream_from_flush() -> result::Result<()> {
+    let raw_content = r#"<head></head>@flush;<body></body>"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    let block = &template.block().span().blocks()[1];
+    assert!(matches!(block, Block::KFLUSH(_)));
+
+    let context = CodeGenContext::new(Kind::KHTML, &options);
+    let ts = block.to_token_stream(None, &context)?;
+    assert_eq!(ts.len(), 1);
+    assert_eq!(
+        ts[0].to_string(),
+        quote::quote! { writer.flush(); }.to_string()
+    );
+    Ok(())
+}
+
+#[test]
+fn to_token_stream_with_merged_content() -> result::Result<()> {
+    let raw_content = r#"<div>@* note *@<p>@name</p>@* note *@</div>"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    let block = template.block();
+
+    let context = CodeGenContext::new(Kind::KHTML, &options);
+    let ts = block.to_token_stream(Some(&block), &context)?;
+    let expected = quote::quote! {
+        writer.write("<div><p>");
+        (&&&sbolt::types::Output(&(name))).write_to(&mut writer, sbolt::types::EscapeContext::KText)?;
+        writer.write("</p></div>");
+    };
+    assert_eq!(
+        ts.iter()
+            .map(|t| t.to_string())
+            .collect::<Vec<_>>()
+            .join(" "),
+        expected.to_string()
+    );
+    // static content and the estimate of the expression.
+    assert_eq!(context.size_hint(), 18 + options.dynamic_size_hint());
+    Ok(())
+}
+
+#[test]
+fn size_hint_with_dynamic_size_hint() -> result::Result<()> {
+    let raw_content = r#"<p>@name</p>@for i in 0..3 {<i>@i</i>}"#;
+    let options = CompilerOptions::default().with_dynamic_size_hint(100);
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    let block = template.block();
+
+    let context = CodeGenContext::new(Kind::KHTML, &options);
+    block.to_token_stream(Some(&block), &context)?;
+    // the body of a loop is counted once.
+    assert_eq!(context.size_hint(), 14 + 2 * 100);
+    Ok(())
+}
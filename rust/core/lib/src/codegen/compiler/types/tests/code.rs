@@ -152,7 +152,7 @@ fn to_code_token_stream_with_complex_nested_block() -> result::Result<()> {
             writer.write("<tr>");
             for j in 0 .. 5 {
                 writer.write("<td>");
-                writer.write(&j.to_string());
+                (&&&sbolt::types::Output(&(j))).write_to(&mut writer, sbolt::types::EscapeContext::KText)?;
                 writer.write("</td>");
             }
             writer.write("</tr>");
@@ -175,8 +175,9 @@ fn to_inline_code_token_stream_from_wrong_block() {
     let root_span = block.span();
     assert_eq!(root_span.blocks().len(), 1);
     let block = &root_span.blocks()[0];
+    let context = CodeGenContext::new(Kind::KHTML, &options);
     block
-        .to_inline_code_token_stream()
+        .to_inline_code_token_stream(&context)
         .expect("wrong block type");
 }
 
@@ -196,7 +197,7 @@ fn to_inline_code_token_stream_from_content_block() -> result::Result<()> {
     let ts = code_block.to_token_stream(Some(&block), &context)?;
     let expected = quote! {
        testcode;
-       writer.write(&name.to_string());
+       (&&&sbolt::types::Output(&(name))).write_to(&mut writer, sbolt::types::EscapeContext::KText)?;
     };
     assert_eq!(ts[0].to_string(), expected.to_string());
     Ok(())
@@ -211,7 +212,166 @@ fn to_inline_code_token_stream_with_with_wrong_content() {
     span.push_block(Block::new_content(types::Span::new(raw_content)));
     span.push_block(Block::new_content(types::Span::new(raw_content)));
     let render_block = Block::new_inline_code(span);
+    let options = CompilerOptions::default();
+    let context = CodeGenContext::new(Kind::KHTML, &options);
     render_block
-        .to_inline_code_token_stream()
+        .to_inline_code_token_stream(&context)
         .expect("Expected valid inline code block here");
 }
+
+#[test]
+fn to_inline_code_token_stream_in_attribute() -> result::Result<()> {
+    let raw_content = r#"<a href="@url" data-id=@id>@name</a>"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    let block = template.block();
+    let context = CodeGenContext::new(Kind::KHTML, &options);
+    let ts = block.to_token_stream(Some(block), &context)?;
+    let ts = quote! { #(#ts)* };
+    let expected = quote! {
+        writer.write("<a href=\"");
+        (&&&sbolt::types::Output(&(url))).write_to(&mut writer, sbolt::types::EscapeContext::KAttribute)?;
+        writer.write("\" data-id=");
+        (&&&sbolt::types::Output(&(id))).write_to(&mut writer, sbolt::types::EscapeContext::KUnquotedAttribute)?;
+        writer.write(">");
+        (&&&sbolt::types::Output(&(name))).write_to(&mut writer, sbolt::types::EscapeContext::KText)?;
+        writer.write("</a>");
+    };
+    assert_eq!(ts.to_string(), expected.to_string());
+    Ok(())
+}
+
+#[test]
+fn to_inline_code_token_stream_in_text_template() -> result::Result<()> {
+    let raw_content = r#"<a href="@url">"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KTEXT, &options)?;
+    let block = template.block();
+    let context = CodeGenContext::new(Kind::KTEXT, &options);
+    let ts = block.to_token_stream(Some(block), &context)?;
+    let ts = quote! { #(#ts)* };
+    let expected = quote! {
+        writer.write("<a href=\"");
+        (&&&sbolt::types::Output(&(url))).write_to(&mut writer, sbolt::types::EscapeContext::KText)?;
+        writer.write("\">");
+    };
+    assert_eq!(ts.to_string(), expected.to_string());
+    Ok(())
+}
+
+#[test]
+fn to_inline_code_token_stream_in_json_template() -> result::Result<()> {
+    let raw_content = r#"{"name": "@name", "age": @age}"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KJSON, &options)?;
+    let block = template.block();
+    let context = CodeGenContext::new(Kind::KJSON, &options);
+    let ts = block.to_token_stream(Some(block), &context)?;
+    let ts = quote! { #(#ts)* };
+    let expected = quote! {
+        writer.write("{\"name\": \"");
+        (&&&sbolt::types::Output(&(name))).write_to(&mut writer, sbolt::types::EscapeContext::KJsonString)?;
+        writer.write("\", \"age\": ");
+        (&&&sbolt::types::Output(&(age))).write_to(&mut writer, sbolt::types::EscapeContext::KJsonValue)?;
+        writer.write("}");
+    };
+    assert_eq!(ts.to_string(), expected.to_string());
+    Ok(())
+}
+
+#[test]
+fn to_raw_token_stream() -> result::Result<()> {
+    let raw_content = r#"<div>@raw(widget.html())</div>"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    let block = template.block();
+    let raw_block = &block.span().blocks()[1];
+    assert!(matches!(raw_block, Block::KRAW(_)));
+    let ts = raw_block.to_raw_token_stream()?;
+    let expected = quote! {
+        writer.write(&(widget.html()).to_string());
+    };
+    assert_eq!(ts.to_string(), expected.to_string());
+    Ok(())
+}
+
+#[test]
+fn split_format_spec() {
+    use crate::codegen::compiler::types::code::split_format_spec;
+    let cases = [
+        ("price:.2", "price", Some(".2")),
+        ("id:>8", "id", Some(">8")),
+        ("id:*^+#010.3e", "id", Some("*^+#010.3e")),
+        ("items:?", "items", Some("?")),
+        ("items:#x?", "items", Some("#x?")),
+        (
+            "std::f64::consts::PI:.3",
+            "std::f64::consts::PI",
+            Some(".3"),
+        ),
+        ("t(\"a:b\"):<4", "t(\"a:b\")", Some("<4")),
+        ("':'", "':'", None),
+        ("std::f64::consts::PI", "std::f64::consts::PI", None),
+        ("Foo { a: 1 }", "Foo { a: 1 }", None),
+        ("|x: i32| x", "|x: i32| x", None),
+        (":.2", ":.2", None),
+        ("price:.", "price:.", None),
+        ("price:$", "price:$", None),
+    ];
+    for (exp, value, spec) in cases {
+        assert_eq!(split_format_spec(exp), (value, spec), "{exp}");
+    }
+}
+
+#[test]
+fn to_inline_code_token_stream_with_format_spec() -> result::Result<()> {
+    let raw_content = r#"<b>@(price:.2)</b>"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    let block = template.block();
+    let context = CodeGenContext::new(Kind::KHTML, &options);
+    let ts = block.to_token_stream(Some(block), &context)?;
+    let ts = quote! { #(#ts)* };
+    let expected = quote! {
+        writer.write("<b>");
+        {
+            #[allow(unused_imports)]
+            use sbolt::types::{FormatMarkup as _, FormatValue as _};
+            let sbolt_fmt_value = &(price);
+            let sbolt_fmt_context = (&&sbolt::types::Output(sbolt_fmt_value)).escape_context(sbolt::types::EscapeContext::KText);
+            write!(sbolt::types::FmtWriter::new(&mut writer, sbolt_fmt_context), "{:.2}", sbolt_fmt_value)?;
+        }
+        writer.write("</b>");
+    };
+    assert_eq!(ts.to_string(), expected.to_string());
+    Ok(())
+}
+
+#[test]
+fn to_inline_code_token_stream_with_format_spec_in_json_and_text() -> result::Result<()> {
+    let options = CompilerOptions::default();
+    for (kind, escape_context) in [
+        (
+            Kind::KJSON,
+            quote! { Some(sbolt::types::EscapeContext::KJsonValue) },
+        ),
+        (Kind::KTEXT, quote! { None }),
+    ] {
+        let raw_content = r#"@(id:>8)"#;
+        let template = Template::from(&raw_content, None, kind, &options)?;
+        let block = &template.block().span().blocks()[0];
+        let context = CodeGenContext::new(kind, &options);
+        let ts = block.to_inline_code_token_stream(&context)?;
+        let expected = quote! {
+            {
+                #[allow(unused_imports)]
+                use sbolt::types::{FormatMarkup as _, FormatValue as _};
+                let sbolt_fmt_value = &(id);
+                let sbolt_fmt_context = #escape_context;
+                write!(sbolt::types::FmtWriter::new(&mut writer, sbolt_fmt_context), "{:>8}", sbolt_fmt_value)?;
+            }
+        };
+        assert_eq!(ts.to_string(), expected.to_string());
+    }
+    Ok(())
+}
@@ -0,0 +1,69 @@
+#![cfg(test)]
+use crate::codegen::CompilerOptions;
+use crate::codegen::compiler::context::CodeGenContext;
+use crate::codegen::types::Block;
+use crate::codegen::types::Template;
+use crate::types::result;
+use crate::types::template::Kind;
+use quote::quote;
+
+#[test]
+#[should_panic]
+fn to_if_token_stream_from_wrong_block() {
+    let raw_content = r#"test"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options).unwrap();
+    let block = template.block();
+    let context = CodeGenContext::new(Kind::KHTML, &options);
+    block
+        .to_if_token_stream(&context)
+        .expect("Expect if block here");
+}
+
+#[test]
+fn to_if_token_stream() -> result::Result<()> {
+    let raw_content = r#"@if age > 18 {<b>@name</b>} else if age > 0 {child} else {unknown}"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    let block = &template.block().span().blocks()[0];
+    assert!(matches!(block, Block::KIF(_)));
+    let context = CodeGenContext::new(Kind::KHTML, &options);
+    let ts = block.to_if_token_stream(&context)?;
+    let expected = quote! {
+        if age > 18 {
+            writer.write("<b>");
+            (&&&sbolt::types::Output(&(name))).write_to(&mut writer, sbolt::types::EscapeContext::KText)?;
+            writer.write("</b>");
+        } else if age > 0 {
+            writer.write("child");
+        } else {
+            writer.write("unknown");
+        }
+    };
+    assert_eq!(ts.to_string(), expected.to_string());
+    Ok(())
+}
+
+#[test]
+fn to_if_token_stream_in_attribute() -> result::Result<()> {
+    let raw_content = r#"<li class="@if active {on} else {off}">@name</li>"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    let block = template.block();
+    let context = CodeGenContext::new(Kind::KHTML, &options);
+    let ts = block.to_token_stream(Some(block), &context)?;
+    let ts = quote! { #(#ts)* };
+    let expected = quote! {
+        writer.write("<li class=\"");
+        if active {
+            writer.write("on");
+        } else {
+            writer.write("off");
+        }
+        writer.write("\">");
+        (&&&sbolt::types::Output(&(name))).write_to(&mut writer, sbolt::types::EscapeContext::KText)?;
+        writer.write("</li>");
+    };
+    assert_eq!(ts.to_string(), expected.to_string());
+    Ok(())
+}
@@ -72,6 +72,53 @@ fn generate_layout_token_stream_with_one_layout_block() -> result::Result<()> {
     Ok(())
 }
 
+#[test]
+fn generate_layout_token_stream_with_layout_none() -> result::Result<()> {
+    let options = CompilerOptions::default();
+    let template = Template::from(&"@layout none;\n<p>a</p>", None, Kind::KHTML, &options)?;
+    assert!(template.block().generate_layout_token_stream()?.is_none());
+    assert!(!template.block().has_layout());
+
+    let template = Template::from(&"@layout test;\n<p>a</p>", None, Kind::KHTML, &options)?;
+    assert!(template.block().has_layout());
+
+    Ok(())
+}
+
+#[test]
+fn generate_layout_token_stream_with_layout_expression() -> result::Result<()> {
+    let raw_content = "@layout(if print { Some(\"print\") } else { None });\n<p>a</p>";
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    let block = template.block();
+    let root_span = block.span();
+    assert!(matches!(root_span.blocks()[0], Block::KLAYOUT(_)));
+    assert_eq!(
+        root_span.blocks()[0].content(),
+        "(if print { Some(\"print\") } else { None })"
+    );
+    assert!(block.generate_layout_token_stream()?.is_none());
+
+    let ts = block.generate_default_layout_token_stream()?;
+    let expected = quote! {
+        (if print { Some("print") } else { None }).map(|layout| layout.to_string())
+    };
+    assert_eq!(ts.to_string(), expected.to_string());
+
+    let template = Template::from(&"@layout test;", None, Kind::KHTML, &options)?;
+    let ts = template.block().generate_default_layout_token_stream()?;
+    assert_eq!(ts.to_string(), quote! { Self::layout() }.to_string());
+
+    let template = Template::from(&"@layout(a b)", None, Kind::KHTML, &options)?;
+    assert!(
+        template
+            .block()
+            .generate_default_layout_token_stream()
+            .is_err()
+    );
+    Ok(())
+}
+
 #[test]
 #[should_panic]
 fn generate_layout_token_stream_with_multiple_layout_blocks() {
@@ -179,3 +226,115 @@ fn generate_imports_token_stream_with_wrong_content() {
         .generate_imports_token_stream()
         .expect("expected valid use blocks here");
 }
+
+#[test]
+fn generate_model_type_token_stream() -> result::Result<()> {
+    let options = CompilerOptions::default();
+    let template = Template::from(&"<p>@model</p>", None, Kind::KHTML, &options)?;
+    assert!(
+        template
+            .block()
+            .generate_model_type_token_stream()?
+            .is_none()
+    );
+
+    let raw_content = "@model Vec<crate::Item>\n<p>@model.len()</p>";
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    let block = template.block();
+    let root_span = block.span();
+    assert!(matches!(root_span.blocks()[0], Block::KMODEL(_)));
+    assert!(matches!(root_span.blocks()[1], Block::KCONTENT(_)));
+    let result = block.generate_model_type_token_stream()?;
+    let expected = quote! { Vec<crate::Item> };
+    assert_eq!(result.unwrap().to_string(), expected.to_string());
+    Ok(())
+}
+
+#[test]
+fn generate_model_type_token_stream_with_invalid_blocks() -> result::Result<()> {
+    let options = CompilerOptions::default();
+    for raw_content in ["@model String\n@model i32\n", "@model 1 + 2\n"] {
+        let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+        assert!(template.block().generate_model_type_token_stream().is_err());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn generate_model_type_token_stream_with_model_value() -> result::Result<()> {
+    let options = CompilerOptions::default();
+    // the value after content or out of the root, not the directive.
+    for raw_content in [
+        "<p>@model is ready</p>",
+        "text\n@model String",
+        "@if true {\n@model String\n}",
+    ] {
+        let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+        assert!(
+            template
+                .block()
+                .generate_model_type_token_stream()?
+                .is_none()
+        );
+    }
+
+    // directives and comments could be before the directive.
+    let raw_content = "@use crate::Item\n@** item **@\n@model Item\n<p>@model is ready</p>";
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    let result = template.block().generate_model_type_token_stream()?;
+    assert_eq!(result.unwrap().to_string(), quote! { Item }.to_string());
+    Ok(())
+}
+
+#[test]
+fn generate_render_token_stream_with_model() -> result::Result<()> {
+    let raw_content = "@model String\n@model";
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KTEXT, &options)?;
+    let context = CodeGenContext::new(Kind::KTEXT, &options);
+    let ts = template.block().generate_render_token_stream(&context)?;
+    let ts = ts.to_string();
+    let lend = quote! { sbolt::types::lend_model::<String>(context, &Self::name())? };
+    let context = quote! { sbolt::types::ModelViewContext::new(context, sbolt_model.clone()) };
+    let restore = quote! { sbolt::types::restore_model(context, sbolt_model, sbolt_model_taken); };
+    assert!(ts.contains(&lend.to_string()));
+    assert!(ts.contains(&context.to_string()));
+    assert!(ts.contains(&restore.to_string()));
+    Ok(())
+}
+
+#[test]
+fn generate_inject_token_stream() -> result::Result<()> {
+    let raw_content = "@inject urls: crate::Urls\n@inject flags: crate::Flags\n<p>@urls</p>";
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    let result = template.block().generate_inject_token_stream()?;
+    assert_eq!(result.len(), 2);
+    let expected = quote! {
+        #[allow(unused_variables)]
+        let urls = sbolt::types::inject::<crate::Urls>(context, "urls", &Self::name())?;
+    };
+    assert_eq!(result[0].to_string(), expected.to_string());
+    let expected = quote! {
+        #[allow(unused_variables)]
+        let flags = sbolt::types::inject::<crate::Flags>(context, "flags", &Self::name())?;
+    };
+    assert_eq!(result[1].to_string(), expected.to_string());
+    Ok(())
+}
+
+#[test]
+fn generate_inject_token_stream_with_invalid_blocks() -> result::Result<()> {
+    let options = CompilerOptions::default();
+    for raw_content in [
+        "@inject urls\n",
+        "@inject crate::Urls\n",
+        "@inject 1: Urls\n",
+        "@inject urls: Urls\n@inject urls: Urls\n",
+    ] {
+        let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+        assert!(template.block().generate_inject_token_stream().is_err());
+    }
+    Ok(())
+}
@@ -0,0 +1,109 @@
+#![cfg(test)]
+use crate::codegen::CompilerOptions;
+use crate::codegen::compiler::context::CodeGenContext;
+use crate::codegen::compiler::types::filter::{apply_filters, find_unknown_filter};
+use crate::codegen::types::Template;
+use crate::types::result;
+use crate::types::template::Kind;
+use quote::quote;
+
+#[test]
+fn apply_filters_with_registry() {
+    let options = CompilerOptions::default().with_filter("money", "crate::filters::money");
+    let filters = options.filters();
+    let cases = [
+        (
+            "name | upper",
+            Some("sbolt::types::filters::upper(&(name))"),
+        ),
+        (
+            "name | trim | truncate(20)",
+            Some("sbolt::types::filters::truncate(&sbolt::types::filters::trim(&(name)), 20)"),
+        ),
+        (
+            r#"tags | join(", | ")"#,
+            Some(r#"sbolt::types::filters::join(&(tags), ", | ")"#),
+        ),
+        ("price|money", Some("crate::filters::money(&(price))")),
+        (
+            "flags | MASK | upper",
+            Some("sbolt::types::filters::upper(&(flags | MASK))"),
+        ),
+        ("flags | MASK", None),
+        ("a || upper", None),
+        ("f(|x| upper)", None),
+        ("| upper", None),
+        ("name", None),
+    ];
+    for (exp, expected) in cases {
+        assert_eq!(apply_filters(exp, filters).as_deref(), expected, "{exp}");
+    }
+}
+
+#[test]
+fn to_inline_code_token_stream_with_filters() -> result::Result<()> {
+    let raw_content = r#"@(name | upper:>8)"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KTEXT, &options)?;
+    let block = &template.block().span().blocks()[0];
+    let context = CodeGenContext::new(Kind::KTEXT, &options);
+    let ts = block.to_inline_code_token_stream(&context)?;
+    let expected = quote! {
+        {
+            #[allow(unused_imports)]
+            use sbolt::types::{FormatMarkup as _, FormatValue as _};
+            let sbolt_fmt_value = &(sbolt::types::filters::upper(&(name)));
+            let sbolt_fmt_context = None;
+            write!(sbolt::types::FmtWriter::new(&mut writer, sbolt_fmt_context), "{:>8}", sbolt_fmt_value)?;
+        }
+    };
+    assert_eq!(ts.to_string(), expected.to_string());
+    Ok(())
+}
+
+#[test]
+fn find_unknown_filter_with_registry() {
+    let options = CompilerOptions::default().with_filter("money", "crate::filters::money");
+    let filters = options.filters();
+    let cases = [
+        ("flags | MASK", Some("MASK")),
+        ("name | uper", Some("uper")),
+        ("flags | MASK | upper", Some("MASK")),
+        ("price | money | trim", None),
+        ("name | truncate(20)", None),
+        ("flags | mask(1)", Some("mask")),
+        ("flags | MASK(1) | upper", Some("MASK")),
+        ("(flags | mask(1))", None),
+        ("(flags | MASK)", None),
+        ("a | f(1) * g(2)", None),
+        ("a || b", None),
+        ("a | b + 1", None),
+    ];
+    for (exp, expected) in cases {
+        assert_eq!(find_unknown_filter(exp, filters), expected, "{exp}");
+    }
+}
+
+#[test]
+fn to_inline_code_token_stream_with_unknown_filter() -> result::Result<()> {
+    let options = CompilerOptions::default();
+    let context = CodeGenContext::new(Kind::KTEXT, &options);
+    let template = Template::from("@(flags | MASK)", None, Kind::KTEXT, &options)?;
+    let block = &template.block().span().blocks()[0];
+    let err = block.to_inline_code_token_stream(&context).unwrap_err();
+    assert!(err.to_string().contains("Unknown filter 'MASK'"), "{err}");
+
+    let template = Template::from("@(x | unknown(20))", None, Kind::KTEXT, &options)?;
+    let block = &template.block().span().blocks()[0];
+    let err = block.to_inline_code_token_stream(&context).unwrap_err();
+    assert!(
+        err.to_string().contains("Unknown filter 'unknown'"),
+        "{err}"
+    );
+
+    // parenthesized for the bitwise or.
+    let template = Template::from("@((flags | MASK))", None, Kind::KTEXT, &options)?;
+    let block = &template.block().span().blocks()[0];
+    assert!(block.to_inline_code_token_stream(&context).is_ok());
+    Ok(())
+}
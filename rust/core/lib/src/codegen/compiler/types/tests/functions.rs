@@ -0,0 +1,53 @@
+#![cfg(test)]
+use crate::codegen::CompilerOptions;
+use crate::codegen::types::Template;
+use crate::types::result;
+use crate::types::template::Kind;
+use quote::quote;
+
+#[test]
+#[should_panic]
+fn generate_functions_token_stream_from_wrong_block() {
+    let raw_content = r#"@functions { fn a() {} }"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options).unwrap();
+    let block = &template.block().span().blocks()[0];
+    block
+        .generate_functions_token_stream()
+        .expect("Expect root block here");
+}
+
+#[test]
+fn generate_functions_token_stream() -> result::Result<()> {
+    let raw_content = r#"@functions { fn a() -> i32 { 1 } }
+<p>@a()</p>
+@functions { struct Row { id: i32 } }"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    let ts = template.block().generate_functions_token_stream()?;
+    let ts = quote! { #(#ts)* };
+    let expected = quote! {
+        fn a() -> i32 { 1 }
+        struct Row { id: i32 }
+    };
+    assert_eq!(ts.to_string(), expected.to_string());
+    Ok(())
+}
+
+#[test]
+fn to_token_stream_with_functions() -> result::Result<()> {
+    let raw_content = r#"@functions { fn a() -> i32 { 1 } }"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    let ts = template.to_token_stream(
+        "TestView",
+        "TestnsTestViewView",
+        "testns::TestView",
+        &CompilerOptions::default().with_mod_name("test_view_mod"),
+    )?;
+    let code = ts.to_string();
+    let functions = quote! { fn a() -> i32 { 1 } }.to_string();
+    let view = quote! { pub struct TestView; }.to_string();
+    assert!(code.find(&functions).unwrap() < code.find(&view).unwrap());
+    Ok(())
+}
@@ -0,0 +1,126 @@
+#![cfg(test)]
+use crate::codegen::CompilerOptions;
+use crate::codegen::compiler::context::CodeGenContext;
+use crate::codegen::types::Block;
+use crate::codegen::types::Template;
+use crate::types::result;
+use crate::types::template::Kind;
+use quote::quote;
+
+#[test]
+#[should_panic]
+fn to_for_token_stream_from_wrong_block() {
+    let raw_content = r#"test"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options).unwrap();
+    let block = template.block();
+    let context = CodeGenContext::new(Kind::KHTML, &options);
+    block
+        .to_for_token_stream(&context)
+        .expect("Expect for block here");
+}
+
+#[test]
+fn to_for_token_stream() -> result::Result<()> {
+    let raw_content = r#"@for item in items {<li>@item</li>}"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    let block = &template.block().span().blocks()[0];
+    assert!(matches!(block, Block::KFOR(_)));
+    let context = CodeGenContext::new(Kind::KHTML, &options);
+    let ts = block.to_for_token_stream(&context)?;
+    let expected = quote! {
+        {
+            let mut sbolt_loop_iter = ::core::iter::IntoIterator::into_iter(items).peekable();
+            let mut sbolt_loop_index: usize = 0;
+            while let Some(item) = sbolt_loop_iter.next() {
+                #[allow(unused_variables)]
+                let forloop = sbolt::types::ForLoop::new(sbolt_loop_index, sbolt_loop_iter.peek().is_none());
+                sbolt_loop_index += 1;
+                writer.write("<li>");
+                (&&&sbolt::types::Output(&(item))).write_to(&mut writer, sbolt::types::EscapeContext::KText)?;
+                writer.write("</li>");
+            }
+        }
+    };
+    assert_eq!(ts.to_string(), expected.to_string());
+    Ok(())
+}
+
+#[test]
+fn to_for_token_stream_with_empty() -> result::Result<()> {
+    let raw_content = r#"@for item in items {@item} @empty {none}"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KTEXT, &options)?;
+    let block = &template.block().span().blocks()[0];
+    let context = CodeGenContext::new(Kind::KTEXT, &options);
+    let ts = block.to_for_token_stream(&context)?;
+    let expected = quote! {
+        {
+            let mut sbolt_loop_iter = ::core::iter::IntoIterator::into_iter(items).peekable();
+            let mut sbolt_loop_index: usize = 0;
+            while let Some(item) = sbolt_loop_iter.next() {
+                #[allow(unused_variables)]
+                let forloop = sbolt::types::ForLoop::new(sbolt_loop_index, sbolt_loop_iter.peek().is_none());
+                sbolt_loop_index += 1;
+                (&&&sbolt::types::Output(&(item))).write_to(&mut writer, sbolt::types::EscapeContext::KText)?;
+            }
+            if sbolt_loop_index == 0 {
+                writer.write("none");
+            }
+        }
+    };
+    assert_eq!(ts.to_string(), expected.to_string());
+    Ok(())
+}
+
+#[test]
+fn to_for_token_stream_with_multiline_header() -> result::Result<()> {
+    let raw_content =
+        "@for (id, name) in\n    users.iter().filter(|(_, n)| n.contains(\"in\"))\n{@name}";
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KTEXT, &options)?;
+    let block = &template.block().span().blocks()[0];
+    assert!(matches!(block, Block::KFOR(_)));
+    let context = CodeGenContext::new(Kind::KTEXT, &options);
+    let ts = block.to_for_token_stream(&context)?;
+    let expected = quote! {
+        {
+            let mut sbolt_loop_iter = ::core::iter::IntoIterator::into_iter(users.iter().filter(|(_, n)| n.contains("in"))).peekable();
+            let mut sbolt_loop_index: usize = 0;
+            while let Some((id, name)) = sbolt_loop_iter.next() {
+                #[allow(unused_variables)]
+                let forloop = sbolt::types::ForLoop::new(sbolt_loop_index, sbolt_loop_iter.peek().is_none());
+                sbolt_loop_index += 1;
+                (&&&sbolt::types::Output(&(name))).write_to(&mut writer, sbolt::types::EscapeContext::KText)?;
+            }
+        }
+    };
+    assert_eq!(ts.to_string(), expected.to_string());
+
+    let template = Template::from("@for item\tin\titems {@item}", None, Kind::KTEXT, &options)?;
+    let block = &template.block().span().blocks()[0];
+    assert!(block.to_for_token_stream(&context).is_ok());
+
+    let raw_content = "@for items {@item}";
+    assert!(Template::from(&raw_content, None, Kind::KTEXT, &options).is_err());
+    Ok(())
+}
+
+#[test]
+fn to_while_token_stream() -> result::Result<()> {
+    let raw_content = r#"@while let Some(item) = stack.pop() {@item}"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KTEXT, &options)?;
+    let block = &template.block().span().blocks()[0];
+    assert!(matches!(block, Block::KWHILE(_)));
+    let context = CodeGenContext::new(Kind::KTEXT, &options);
+    let ts = block.to_while_token_stream(&context)?;
+    let expected = quote! {
+        while let Some(item) = stack.pop() {
+            (&&&sbolt::types::Output(&(item))).write_to(&mut writer, sbolt::types::EscapeContext::KText)?;
+        }
+    };
+    assert_eq!(ts.to_string(), expected.to_string());
+    Ok(())
+}
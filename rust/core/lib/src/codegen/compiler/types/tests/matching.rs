@@ -0,0 +1,46 @@
+#![cfg(test)]
+use crate::codegen::CompilerOptions;
+use crate::codegen::compiler::context::CodeGenContext;
+use crate::codegen::types::Block;
+use crate::codegen::types::Template;
+use crate::types::result;
+use crate::types::template::Kind;
+use quote::quote;
+
+#[test]
+#[should_panic]
+fn to_match_token_stream_from_wrong_block() {
+    let raw_content = r#"test"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options).unwrap();
+    let block = template.block();
+    let context = CodeGenContext::new(Kind::KHTML, &options);
+    block
+        .to_match_token_stream(&context)
+        .expect("Expect match block here");
+}
+
+#[test]
+fn to_match_token_stream() -> result::Result<()> {
+    let raw_content = r#"@match status { Status::Paid => {<b>paid</b>} Status::Due(days) if days > 1 => {@days days} _ => {} }"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    let block = &template.block().span().blocks()[0];
+    assert!(matches!(block, Block::KMATCH(_)));
+    let context = CodeGenContext::new(Kind::KHTML, &options);
+    let ts = block.to_match_token_stream(&context)?;
+    let expected = quote! {
+        match status {
+            Status::Paid => {
+                writer.write("<b>paid</b>");
+            }
+            Status::Due(days) if days > 1 => {
+                (&&&sbolt::types::Output(&(days))).write_to(&mut writer, sbolt::types::EscapeContext::KText)?;
+                writer.write(" days");
+            }
+            _ => {}
+        }
+    };
+    assert_eq!(ts.to_string(), expected.to_string());
+    Ok(())
+}
@@ -1,8 +1,15 @@
 #![cfg(test)]
+mod awaits;
 mod block;
 mod code;
+mod condition;
 mod content;
 mod directives;
+mod filter;
+mod functions;
+mod loops;
+mod matching;
+mod partial;
 mod render;
 mod section;
 mod template;
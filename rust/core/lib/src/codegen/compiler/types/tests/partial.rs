@@ -0,0 +1,117 @@
+#![cfg(test)]
+use crate::codegen::CompilerOptions;
+use crate::codegen::compiler::context::CodeGenContext;
+use crate::codegen::types::Block;
+use crate::codegen::types::Template;
+use crate::types::result;
+use crate::types::template::Kind;
+use quote::quote;
+
+#[test]
+#[should_panic]
+fn to_partial_token_stream_from_wrong_block() {
+    let raw_content = r#"test"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options).unwrap();
+    let block = template.block();
+    let context = CodeGenContext::new(Kind::KHTML, &options);
+    block
+        .to_partial_token_stream(&context)
+        .expect("Expect partial block here");
+}
+
+#[test]
+fn to_partial_token_stream() -> result::Result<()> {
+    let raw_content = r#"@partial("card")"#;
+    let options = CompilerOptions::default().with_mod_name("views");
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    let block = &template.block().span().blocks()[0];
+    assert!(matches!(block, Block::KPARTIAL(_)));
+    let context = CodeGenContext::new(Kind::KHTML, &options);
+    let ts = block.to_partial_token_stream(&context)?;
+    let expected = quote! {
+        {
+            let sbolt_partial_path = "card";
+            let sbolt_partial_creator = sbolt::types::resolve_layout_to_view_keys(&sbolt_partial_path, &Self::name())
+                .iter()
+                .find_map(|key| crate::views::resolve_view_creator(key))
+                .ok_or_else(|| sbolt::types::error::RuntimeError::partial_not_found(&sbolt_partial_path, &Self::name()))?;
+            {
+                let sbolt_partial_view = sbolt_partial_creator();
+                sbolt::types::set_partial_render(context);
+                let sbolt_partial_result = match sbolt_partial_view.kind() == Self::kind() {
+                    true => {
+                        let mut sbolt_partial_writer: &mut dyn sbolt::types::Writer = &mut writer;
+                        sbolt_partial_writer.reserve(sbolt_partial_view.size_hint());
+                        sbolt_partial_view.render_to(context, &mut sbolt_partial_writer)
+                    }
+                    false => sbolt_partial_view
+                        .render(context)
+                        .map(|output| writer.write(&output)),
+                };
+                sbolt::types::take_partial_render(context);
+                sbolt_partial_result?;
+            }
+        }
+    };
+    assert_eq!(ts.to_string(), expected.to_string());
+    Ok(())
+}
+
+#[test]
+fn to_partial_token_stream_with_data() -> result::Result<()> {
+    let raw_content = r#"@partial(path, row.clone())"#;
+    let options = CompilerOptions::default().with_mod_name("views");
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    let block = &template.block().span().blocks()[0];
+    let context = CodeGenContext::new(Kind::KHTML, &options);
+    let ts = block.to_partial_token_stream(&context)?;
+    let expected = quote! {
+        {
+            let sbolt_partial_path = path;
+            let sbolt_partial_creator = sbolt::types::resolve_layout_to_view_keys(&sbolt_partial_path, &Self::name())
+                .iter()
+                .find_map(|key| crate::views::resolve_view_creator(key))
+                .ok_or_else(|| sbolt::types::error::RuntimeError::partial_not_found(&sbolt_partial_path, &Self::name()))?;
+            {
+                let sbolt_partial_data = row.clone();
+                let mut sbolt_partial_context = sbolt::types::ChildViewContext::new(context);
+                sbolt::types::Context::set_data(
+                    &mut sbolt_partial_context,
+                    sbolt::types::PARTIAL_DATA_KEY,
+                    move || sbolt_partial_data,
+                );
+                {
+                    let sbolt_partial_view = sbolt_partial_creator();
+                    sbolt::types::set_partial_render(&mut sbolt_partial_context);
+                    let sbolt_partial_result = match sbolt_partial_view.kind() == Self::kind() {
+                        true => {
+                            let mut sbolt_partial_writer: &mut dyn sbolt::types::Writer = &mut writer;
+                            sbolt_partial_writer.reserve(sbolt_partial_view.size_hint());
+                            sbolt_partial_view.render_to(&mut sbolt_partial_context, &mut sbolt_partial_writer)
+                        }
+                        false => sbolt_partial_view
+                            .render(&mut sbolt_partial_context)
+                            .map(|output| writer.write(&output)),
+                    };
+                    sbolt::types::take_partial_render(&mut sbolt_partial_context);
+                    sbolt_partial_result?;
+                }
+            }
+        }
+    };
+    assert_eq!(ts.to_string(), expected.to_string());
+    Ok(())
+}
+
+#[test]
+fn collect_partial_paths() -> result::Result<()> {
+    let raw_content = r#"@partial("card") @if ok {@partial("~/row", 1)} @partial(path)"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    let mut paths = vec![];
+    template.block().collect_partial_paths(&mut paths);
+    let paths = paths.into_iter().map(|(p, _, _)| p).collect::<Vec<_>>();
+    assert_eq!(paths, vec!["card".to_string(), "~/row".to_string()]);
+    Ok(())
+}
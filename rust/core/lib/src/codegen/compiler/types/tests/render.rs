@@ -1,5 +1,6 @@
 #![cfg(test)]
 use crate::codegen::CompilerOptions;
+use crate::codegen::compiler::context::CodeGenContext;
 use crate::codegen::types::Block;
 use crate::codegen::types::Span;
 use crate::codegen::types::Template;
@@ -21,7 +22,10 @@ fn to_render_token_stream() {
     assert_eq!(root_span.blocks().len(), 1);
 
     let block = &root_span.blocks()[0];
-    block.to_render_token_stream().expect("wrong block type");
+    let context = CodeGenContext::new(Kind::KHTML, &options);
+    block
+        .to_render_token_stream(&context)
+        .expect("wrong block type");
 }
 
 #[test]
@@ -36,8 +40,13 @@ fn to_render_token_stream_with_no_param() -> result::Result<()> {
     assert_eq!(root_span.blocks().len(), 1);
 
     let block = &root_span.blocks()[0];
-    let ts = block.to_render_token_stream()?;
+    let context = CodeGenContext::new(Kind::KHTML, &options);
+    let ts = block.to_render_token_stream(&context)?;
     let expected = quote! {
+        if let Some(sbolt_pending) = sbolt::types::take_pending_body::<fn() -> KTemplate>(context, &Self::name()) {
+            let sbolt_pending_body = sbolt_pending().render_body(context)?;
+            context.set_default_section(sbolt_pending_body);
+        }
         let default_section = context.get_default_section();
         match default_section {
             Some(content) => writer.write(&content),
@@ -62,9 +71,14 @@ fn to_render_token_stream_with_single_param() -> result::Result<()> {
     assert_eq!(root_span.blocks().len(), 1);
 
     let block = &root_span.blocks()[0];
-    let ts = block.to_render_token_stream()?;
+    let context = CodeGenContext::new(Kind::KHTML, &options);
+    let ts = block.to_render_token_stream(&context)?;
     let expected = quote! {
-        let section_name = "test";
+        if let Some(sbolt_pending) = sbolt::types::take_pending_body::<fn() -> KTemplate>(context, &Self::name()) {
+            let sbolt_pending_body = sbolt_pending().render_body(context)?;
+            context.set_default_section(sbolt_pending_body);
+        }
+        let section_name: &str = "test";
         let sections = context.get_section(section_name);
         match sections {
             Some(contents) => {
@@ -93,10 +107,14 @@ fn to_render_token_stream_with_two_params() -> result::Result<()> {
     assert_eq!(root_span.blocks().len(), 1);
 
     let block = &root_span.blocks()[0];
-    let ts = block.to_render_token_stream()?;
+    let context = CodeGenContext::new(Kind::KHTML, &options);
+    let ts = block.to_render_token_stream(&context)?;
     let expected = quote! {
-        let section_name = "test";
-        let is_required = "false".parse::<bool>().is_ok();
+        if let Some(sbolt_pending) = sbolt::types::take_pending_body::<fn() -> KTemplate>(context, &Self::name()) {
+            let sbolt_pending_body = sbolt_pending().render_body(context)?;
+            context.set_default_section(sbolt_pending_body);
+        }
+        let section_name: &str = "test";
         let sections = context.get_section(section_name);
         match sections {
             Some(contents) => {
@@ -104,10 +122,7 @@ fn to_render_token_stream_with_two_params() -> result::Result<()> {
                     writer.write(&content)
                 }
             },
-            None if is_required => {
-                return Err(sbolt::types::error::RuntimeError::NotFound(format!("Section `{}` not found", section_name), "".to_string()))
-            },
-            _ => { }
+            None => { }
         }
     };
 
@@ -125,7 +140,135 @@ fn to_render_token_stream_with_more_than_two_params() {
     span.push_block(Block::new_content(Span::new(raw_content)));
     span.push_block(Block::new_content(Span::new(raw_content)));
     let render_block = Block::new_render(span);
+    let options = CompilerOptions::default();
+    let context = CodeGenContext::new(Kind::KHTML, &options);
     render_block
-        .to_render_token_stream()
+        .to_render_token_stream(&context)
         .expect("wrong number of params");
 }
+
+#[test]
+fn to_render_token_stream_with_fallback() -> result::Result<()> {
+    let raw_content = r#"@render(title) {<title>@name</title>}"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    let root_span = template.block().span();
+    assert_eq!(root_span.blocks().len(), 1);
+
+    let block = &root_span.blocks()[0];
+    let context = CodeGenContext::new(Kind::KHTML, &options);
+    let ts = block.to_render_token_stream(&context)?;
+    let expected = quote! {
+        if let Some(sbolt_pending) = sbolt::types::take_pending_body::<fn() -> KTemplate>(context, &Self::name()) {
+            let sbolt_pending_body = sbolt_pending().render_body(context)?;
+            context.set_default_section(sbolt_pending_body);
+        }
+        let section_name: &str = "title";
+        let sections = context.get_section(section_name);
+        match sections {
+            Some(contents) => {
+              for content in contents {
+                  writer.write(&content)
+              }
+            },
+            None => {
+                writer.write("<title>");
+                (&&&sbolt::types::Output(&(name))).write_to(&mut writer, sbolt::types::EscapeContext::KText)?;
+                writer.write("</title>");
+            }
+        }
+    };
+
+    assert_eq!(ts.to_string(), expected.to_string());
+    Ok(())
+}
+
+#[test]
+fn to_render_token_stream_with_two_params_and_fallback() -> result::Result<()> {
+    let raw_content = r#"@render(title, true) {Home}"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    let root_span = template.block().span();
+    assert_eq!(root_span.blocks().len(), 1);
+
+    let block = &root_span.blocks()[0];
+    let context = CodeGenContext::new(Kind::KHTML, &options);
+    let ts = block.to_render_token_stream(&context)?;
+    let expected = quote! {
+        if let Some(sbolt_pending) = sbolt::types::take_pending_body::<fn() -> KTemplate>(context, &Self::name()) {
+            let sbolt_pending_body = sbolt_pending().render_body(context)?;
+            context.set_default_section(sbolt_pending_body);
+        }
+        let section_name: &str = "title";
+        let sections = context.get_section(section_name);
+        match sections {
+            Some(contents) => {
+              for content in contents {
+                  writer.write(&content)
+              }
+            },
+            None => {
+                writer.write("Home");
+            }
+        }
+    };
+
+    assert_eq!(ts.to_string(), expected.to_string());
+    Ok(())
+}
+
+#[test]
+fn to_render_token_stream_with_expressions() -> result::Result<()> {
+    let raw_content = r#"@render(&format!("col-{}", i), i > 0)"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    let root_span = template.block().span();
+    assert_eq!(root_span.blocks().len(), 1);
+
+    let block = &root_span.blocks()[0];
+    let context = CodeGenContext::new(Kind::KHTML, &options);
+    let ts = block.to_render_token_stream(&context)?;
+    let expected = quote! {
+        if let Some(sbolt_pending) = sbolt::types::take_pending_body::<fn() -> KTemplate>(context, &Self::name()) {
+            let sbolt_pending_body = sbolt_pending().render_body(context)?;
+            context.set_default_section(sbolt_pending_body);
+        }
+        let section_name: &str = &(&format!("col-{}", i));
+        let sections = context.get_section(section_name);
+        match sections {
+            Some(contents) => {
+              for content in contents {
+                  writer.write(&content)
+              }
+            },
+            None => {
+                if i > 0 {
+                    return Err(sbolt::types::error::RuntimeError::NotFound(format!("Section `{}` not found", section_name), "".to_string()))
+                }
+            }
+        }
+    };
+
+    assert_eq!(ts.to_string(), expected.to_string());
+    Ok(())
+}
+
+#[test]
+fn to_render_token_stream_with_invalid_params() -> result::Result<()> {
+    let options = CompilerOptions::default();
+    let context = CodeGenContext::new(Kind::KHTML, &options);
+    for raw_content in [
+        r#"@render(test, 1)"#,
+        r#"@render(test, "true")"#,
+        r#"@render(1)"#,
+        r#"@render(a b)"#,
+    ] {
+        let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+        let block = &template.block().span().blocks()[0];
+        assert!(
+            block.to_render_token_stream(&context).is_err(),
+            "{raw_content}"
+        );
+    }
+    Ok(())
+}
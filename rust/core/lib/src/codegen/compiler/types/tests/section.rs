@@ -37,6 +37,10 @@ fn to_section_token_stream_simple() -> result::Result<()> {
     let context = CodeGenContext::new(Kind::KHTML, &options);
     let ts = block.to_section_token_stream(&context)?;
     let expected = quote! {
+        if let Some(sbolt_pending) = sbolt::types::take_pending_body::<fn() -> KTemplate>(context, &Self::name()) {
+            let sbolt_pending_body = sbolt_pending().render_body(context)?;
+            context.set_default_section(sbolt_pending_body);
+        }
         let section_name = "test";
         let inner_writer = {
             let mut writer = self.create_writer(None);
@@ -66,10 +70,14 @@ fn to_section_token_stream_composite() -> result::Result<()> {
     let context = CodeGenContext::new(Kind::KHTML, &options);
     let ts = block.to_section_token_stream(&context)?;
     let expected = quote! {
+        if let Some(sbolt_pending) = sbolt::types::take_pending_body::<fn() -> KTemplate>(context, &Self::name()) {
+            let sbolt_pending_body = sbolt_pending().render_body(context)?;
+            context.set_default_section(sbolt_pending_body);
+        }
         let section_name = "test";
         let section_writer = {
             let mut writer = self.create_writer(None);
-            writer.write(&test.to_string());
+            (&&&sbolt::types::Output(&(test))).write_to(&mut writer, sbolt::types::EscapeContext::KText)?;
             writer.write(" helloworld");
             writer
         };
@@ -80,3 +88,38 @@ fn to_section_token_stream_composite() -> result::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn to_section_token_stream_modes() -> result::Result<()> {
+    let raw_content = r#"@section a append {a}@section b prepend {b}@section c replace {c}"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    let root_span = template.block().span();
+    assert_eq!(root_span.blocks().len(), 3);
+
+    let context = CodeGenContext::new(Kind::KHTML, &options);
+    let methods = [
+        ("a", quote! { append_section }),
+        ("b", quote! { prepend_section }),
+        ("c", quote! { replace_section }),
+    ];
+    for (block, (name, method)) in root_span.blocks().iter().zip(methods) {
+        let ts = block.to_section_token_stream(&context)?;
+        let expected = quote! {
+            if let Some(sbolt_pending) = sbolt::types::take_pending_body::<fn() -> KTemplate>(context, &Self::name()) {
+                let sbolt_pending_body = sbolt_pending().render_body(context)?;
+                context.set_default_section(sbolt_pending_body);
+            }
+            let section_name = #name;
+            let inner_writer = {
+                let mut writer = self.create_writer(None);
+                writer.write(#name);
+                writer
+            };
+            context.#method(section_name, inner_writer.into_string());
+        };
+        assert_eq!(ts.to_string(), expected.to_string());
+    }
+
+    Ok(())
+}
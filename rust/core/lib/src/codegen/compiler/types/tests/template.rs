@@ -1,5 +1,5 @@
 #![cfg(test)]
-use crate::codegen::types::Template;
+use crate::codegen::types::{Block, Template};
 use crate::codegen::{self, CompilerOptions};
 use crate::types::result;
 use crate::types::template::Kind;
@@ -26,7 +26,10 @@ this is @name}
     let expected = quote! {
      use crate::test_view_mod::ktemplate::KTemplate;
      use sbolt::types::Template as _;
+     #[allow(unused_imports)]
      use sbolt::types::Writer;
+     #[allow(unused_imports)]
+     use sbolt::types::{WriteDisplay as _, WriteMarkup as _, WriteRender as _};
      use test::test1;
      pub struct TestView;
 
@@ -39,6 +42,7 @@ this is @name}
              KTemplate::KTestnsTestViewView(TestView::new())
          }
 
+         #[allow(dead_code)]
          fn create_writer(&self, kind: Option<sbolt::types::template::Kind>) -> sbolt::types::KWriter {
              let kind = match kind {
                  Some(k) => k,
@@ -48,6 +52,9 @@ this is @name}
                  sbolt::types::template::Kind::KHTML => {
                      sbolt::types::KWriter::KHtml(sbolt::types::HtmlWriter::new())
                  },
+                 sbolt::types::template::Kind::KJSON => {
+                     sbolt::types::KWriter::KJson(sbolt::types::JsonWriter::new())
+                 },
                  _ => sbolt::types::KWriter::KText(String::new()),
              }
          }
@@ -65,31 +72,126 @@ this is @name}
              Some("test::test2".to_string())
          }
 
-         fn render(&self, context: &mut impl sbolt::types::Context) -> sbolt::types::result::RenderResult<String> {
+         fn size_hint() -> usize {
+             52usize
+         }
+
+         fn render_to(&self, context: &mut impl sbolt::types::Context, sbolt_writer: &mut impl sbolt::types::Writer) -> sbolt::types::result::RenderResult<()> {
+             let layout = match sbolt::types::take_layout(context) {
+                 Some(layout) => layout,
+                 None => Self::layout(),
+             };
+             match layout {
+                 Some(layout) => {
+                     for key in sbolt::types::resolve_layout_to_view_keys(&layout, &Self::name()) {
+                         if let Some(creator) = crate::test_view_mod::resolve_view_creator(&key) {
+                             let sbolt_body = context.take_default_section();
+                             let view = creator();
+                             let sbolt_layout = view.name();
+                             sbolt_writer.reserve(view.size_hint());
+                             let sbolt_pending = sbolt::types::set_pending_body(context, &sbolt_layout, Self::create as fn() -> KTemplate);
+                             let sbolt_result = view.render_to(context, sbolt_writer);
+                             sbolt::types::take_pending_body::<fn() -> KTemplate>(context, &sbolt_layout);
+                             if let Some(pending) = sbolt_pending {
+                                 sbolt::types::set_pending_body(context, &sbolt_layout, pending);
+                             }
+                             match sbolt_body {
+                                 Some(body) => {
+                                     context.set_default_section(body);
+                                 }
+                                 None => {
+                                     context.take_default_section();
+                                 }
+                             }
+                             return sbolt_result;
+                         }
+                     }
+                     Err(sbolt::types::error::RuntimeError::layout_not_found(&layout, &Self::name()))
+                 }
+                 None => {
+                     sbolt_writer.write(&self.render_body(context)?);
+                     Ok(())
+                 }
+             }
+         }
+
+         fn render_body(&self, #[allow(unused_variables)] context: &mut impl sbolt::types::Context) -> sbolt::types::result::RenderResult<String> {
              let mut writer = self.create_writer(None);
+             writer.reserve(Self::size_hint());
+             if let Some(sbolt_pending) = sbolt::types::take_pending_body::<fn() -> KTemplate>(context, &Self::name()) {
+                 let sbolt_pending_body = sbolt_pending().render_body(context)?;
+                 context.set_default_section(sbolt_pending_body);
+             }
              let section_name = "test1";
              let section_writer = {
                  let mut writer = self.create_writer(None);
                  let name = "test1";
                  writer.write("this is ");
-                 writer.write(&name.to_string());
+                 (&&&sbolt::types::Output(&(name))).write_to(&mut writer, sbolt::types::EscapeContext::KText)?;
                  writer
              };
              context.add_section(section_name, section_writer.into_string());
              writer.write("<html><div>Test</div></html>");
-             match Self::layout() {
+             Ok(writer.into_string())
+         }
+
+         async fn render_async(&self, context: &mut impl sbolt::types::Context, sbolt_writer: &mut (impl sbolt::types::Writer + Send)) -> sbolt::types::result::RenderResult<()> {
+             let layout = match sbolt::types::take_layout(context) {
+                 Some(layout) => layout,
+                 None => Self::layout(),
+             };
+             match layout {
                  Some(layout) => {
                      for key in sbolt::types::resolve_layout_to_view_keys(&layout, &Self::name()) {
                          if let Some(creator) = crate::test_view_mod::resolve_view_creator(&key) {
-                             context.set_default_section(writer.into_string());
+                             let sbolt_body = context.take_default_section();
                              let view = creator();
-                             return view.render(context);
+                             let sbolt_layout = view.name();
+                             sbolt_writer.reserve(view.size_hint());
+                             let sbolt_pending = sbolt::types::set_pending_body(context, &sbolt_layout, Self::create as fn() -> KTemplate);
+                             let sbolt_result = view.render_async(context, sbolt_writer).await;
+                             sbolt::types::take_pending_body::<fn() -> KTemplate>(context, &sbolt_layout);
+                             if let Some(pending) = sbolt_pending {
+                                 sbolt::types::set_pending_body(context, &sbolt_layout, pending);
+                             }
+                             match sbolt_body {
+                                 Some(body) => {
+                                     context.set_default_section(body);
+                                 }
+                                 None => {
+                                     context.take_default_section();
+                                 }
+                             }
+                             return sbolt_result;
                          }
                      }
                      Err(sbolt::types::error::RuntimeError::layout_not_found(&layout, &Self::name()))
                  }
-                 None => Ok(writer.into_string()),
+                 None => {
+                     sbolt_writer.write(&self.render_body_async(context).await?);
+                     Ok(())
+                 }
+             }
+         }
+
+         async fn render_body_async(&self, #[allow(unused_variables)] context: &mut impl sbolt::types::Context) -> sbolt::types::result::RenderResult<String> {
+             let mut writer = self.create_writer(None);
+             writer.reserve(Self::size_hint());
+             if let Some(sbolt_pending) = sbolt::types::take_pending_body::<fn() -> KTemplate>(context, &Self::name()) {
+                 let sbolt_pending_body = sbolt_pending().render_body_async(context).await?;
+                 context.set_default_section(sbolt_pending_body);
              }
+             let section_name = "test1";
+             let section_writer = {
+                 let mut writer = self.create_writer(None);
+                 let name = "test1";
+                 writer.write("this is ");
+                 (&&&sbolt::types::Output(&(name))).write_to(&mut writer, sbolt::types::EscapeContext::KText)?;
+                 writer
+             };
+             context.add_section(section_name, section_writer.into_string());
+             writer.write("<html><div>Test</div></html>");
+             Ok(writer.into_string())
          }
      }
     };
@@ -113,7 +215,10 @@ fn to_token_stream_html_without_layout() -> result::Result<()> {
     let expected = quote! {
      use crate::test_view_mod::ktemplate::KTemplate;
      use sbolt::types::Template as _;
+     #[allow(unused_imports)]
      use sbolt::types::Writer;
+     #[allow(unused_imports)]
+     use sbolt::types::{WriteDisplay as _, WriteMarkup as _, WriteRender as _};
      use test::test1;
      pub struct TestView;
 
@@ -126,6 +231,7 @@ fn to_token_stream_html_without_layout() -> result::Result<()> {
              KTemplate::KTestnsTestViewView(TestView::new())
          }
 
+         #[allow(dead_code)]
          fn create_writer(&self, kind: Option<sbolt::types::template::Kind>) -> sbolt::types::KWriter {
              let kind = match kind {
                  Some(k) => k,
@@ -135,6 +241,9 @@ fn to_token_stream_html_without_layout() -> result::Result<()> {
                  sbolt::types::template::Kind::KHTML => {
                      sbolt::types::KWriter::KHtml(sbolt::types::HtmlWriter::new())
                  },
+                 sbolt::types::template::Kind::KJSON => {
+                     sbolt::types::KWriter::KJson(sbolt::types::JsonWriter::new())
+                 },
                  _ => sbolt::types::KWriter::KText(String::new()),
              }
          }
@@ -148,10 +257,13 @@ fn to_token_stream_html_without_layout() -> result::Result<()> {
              sbolt::types::template::Kind::KHTML
          }
 
-         fn render(&self, #[allow(unused_variables)]context: &mut impl sbolt::types::Context) -> sbolt::types::result::RenderResult<String> {
-             let mut writer = self.create_writer(None);
+         fn size_hint() -> usize {
+             28usize
+         }
+
+         fn render_to(&self, #[allow(unused_variables)]context: &mut impl sbolt::types::Context, #[allow(unused_mut)]mut writer: &mut impl sbolt::types::Writer) -> sbolt::types::result::RenderResult<()> {
              writer.write("<html><div>Test</div></html>");
-             Ok (writer.into_string())
+             Ok(())
          }
      }
     };
@@ -180,6 +292,65 @@ this is @name}"#;
     Ok(())
 }
 
+#[test]
+fn to_token_stream_json_with_validation() -> result::Result<()> {
+    let raw_content = r#"@{
+   let name = "test1";
+}
+{"name": "@name", "age": @age, "tags": [@raw(tags)]}"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KJSON, &options)?;
+    let ts = template.to_token_stream(
+        "TestView",
+        "TestnsTestViewView",
+        "testns::TestView",
+        &CompilerOptions::default()
+            .with_mod_name("test_view_mod")
+            .with_json_validation(true),
+    );
+    assert!(ts.is_ok());
+    Ok(())
+}
+
+#[test]
+fn to_token_stream_json_with_invalid_skeleton() -> result::Result<()> {
+    let raw_content = r#"{"name": "@name", "age": @age,}"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KJSON, &options)?;
+    let ts = template.to_token_stream(
+        "TestView",
+        "TestnsTestViewView",
+        "testns::TestView",
+        &CompilerOptions::default()
+            .with_mod_name("test_view_mod")
+            .with_json_validation(true),
+    );
+    match ts {
+        Err(crate::types::error::CompileError::CodeGen(_, summary, _)) => {
+            assert_eq!(summary, "Invalid json: Expected a string key in object")
+        }
+        _ => panic!("expected json validation error"),
+    }
+    Ok(())
+}
+
+#[test]
+fn to_token_stream_json_with_loop_skip_validation() -> result::Result<()> {
+    let raw_content = r#"[@{ for i in 0..3 { @{@i,} } }]"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KJSON, &options)?;
+    let ts = template.to_token_stream(
+        "TestView",
+        "TestnsTestViewView",
+        "testns::TestView",
+        &CompilerOptions::default()
+            .with_mod_name("test_view_mod")
+            .with_json_validation(true),
+    );
+    assert!(ts.is_ok());
+    Ok(())
+}
+
 #[test]
 fn to_token_stream_txt() -> result::Result<()> {
     let raw_content = r#"
@@ -230,3 +401,112 @@ fn compile_without_invalid_mod_name() {
         .compile("test.rshtml".into(), &option)
         .expect("Expect valid ident from mod name");
 }
+
+#[test]
+fn with_shared_merges_imports_and_view_start() -> result::Result<()> {
+    let options = CompilerOptions::default();
+    let imports = [
+        Template::from(
+            "@use a::b;\n@inject urls: Urls\n",
+            None,
+            Kind::KHTML,
+            &options,
+        )?,
+        Template::from(
+            "@* nearer *@\n@inject urls: NearUrls\n",
+            None,
+            Kind::KHTML,
+            &options,
+        )?,
+    ];
+    let view_starts = [
+        Template::from(
+            "@layout site;\n@{ let a = 1; }",
+            None,
+            Kind::KHTML,
+            &options,
+        )?,
+        Template::from("@layout docs;", None, Kind::KHTML, &options)?,
+    ];
+    let template = Template::from("@use a::b;\n<p>@a</p>", None, Kind::KHTML, &options)?
+        .with_shared(&imports, &view_starts)?;
+    let blocks = template.block().span().blocks();
+    assert!(matches!(blocks[0], Block::KINJECT(_)));
+    assert_eq!(blocks[0].content().trim(), "urls: NearUrls");
+    assert!(matches!(blocks[1], Block::KLAYOUT(_)));
+    assert_eq!(blocks[1].content(), "docs");
+    assert!(template.has_view_start_layout());
+    assert!(matches!(blocks[2], Block::KCODE(_)));
+    assert!(matches!(blocks[3], Block::KUSE(_)));
+    assert_eq!(
+        blocks
+            .iter()
+            .filter(|b| matches!(b, Block::KUSE(_) | Block::KINJECT(_) | Block::KLAYOUT(_)))
+            .count(),
+        3
+    );
+
+    // the layout of the view wins, `@layout none` included.
+    let template = Template::from("@layout none;\n<p>@a</p>", None, Kind::KHTML, &options)?
+        .with_shared(&[], &view_starts)?;
+    let layouts = template
+        .block()
+        .span()
+        .blocks()
+        .iter()
+        .filter(|b| matches!(b, Block::KLAYOUT(_)))
+        .map(|b| b.content())
+        .collect::<Vec<_>>();
+    assert_eq!(layouts, vec!["none"]);
+    assert!(!template.has_view_start_layout());
+
+    Ok(())
+}
+
+#[test]
+fn with_shared_of_other_kind() -> result::Result<()> {
+    let options = CompilerOptions::default();
+    let imports = [Template::from("@use a::b;", None, Kind::KHTML, &options)?];
+    let view_starts = [Template::from(
+        "@layout site;",
+        None,
+        Kind::KHTML,
+        &options,
+    )?];
+    let template = Template::from(r#"{"a": 1}"#, None, Kind::KJSON, &options)?
+        .with_shared(&imports, &view_starts)?;
+    let blocks = template.block().span().blocks();
+    assert_eq!(blocks.len(), 1);
+    assert!(matches!(blocks[0], Block::KCONTENT(_)));
+    assert!(!template.has_view_start_layout());
+    Ok(())
+}
+
+#[test]
+fn with_shared_rejects_content() -> result::Result<()> {
+    let options = CompilerOptions::default();
+    let imports = [Template::from(
+        "@layout site;",
+        None,
+        Kind::KHTML,
+        &options,
+    )?];
+    let result =
+        Template::from("<p>a</p>", None, Kind::KHTML, &options)?.with_shared(&imports, &[]);
+    assert!(result.is_err_and(|e| {
+        e.to_string()
+            .contains("Only '@use' and '@inject' are allowed in _imports")
+    }));
+
+    let view_starts = [Template::from(
+        "@layout site;\n<p>shared</p>",
+        None,
+        Kind::KHTML,
+        &options,
+    )?];
+    let result =
+        Template::from("<p>a</p>", None, Kind::KHTML, &options)?.with_shared(&[], &view_starts);
+    assert!(result.is_err_and(|e| e.to_string().contains("_viewstart")));
+
+    Ok(())
+}
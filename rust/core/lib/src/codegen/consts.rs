@@ -1,11 +1,42 @@
 pub(crate) const DEFAULT_HTML_TEMPLATE_FILE_EXTENSION: &'static str = "rshtml";
 pub(crate) const DEFAULT_JSON_TEMPLATE_FILE_EXTENSION: &'static str = "rsjson";
 pub(crate) const DEFAULT_TEXT_TEMPLATE_FILE_EXTENSION: &'static str = "rstxt";
+// bytes an expression is expected to write, like a number or a short name.
+pub(crate) const DEFAULT_DYNAMIC_SIZE_HINT: usize = 16;
 
+pub(crate) const BUILTIN_FILTERS: [&'static str; 8] = [
+    "date",
+    "default",
+    "join",
+    "lower",
+    "pluralize",
+    "trim",
+    "truncate",
+    "upper",
+];
+pub(crate) const BUILTIN_FILTERS_MOD: &'static str = "sbolt::types::filters";
+
+pub(crate) const DIRECTIVE_KEYWORD_INJECT: &'static str = "inject";
 pub(crate) const DIRECTIVE_KEYWORD_LAYOUT: &'static str = "layout";
+pub(crate) const DIRECTIVE_KEYWORD_MODEL: &'static str = "model";
 pub(crate) const DIRECTIVE_KEYWORD_USE: &'static str = "use";
+pub(crate) const LAYOUT_NONE: &'static str = "none";
+pub(crate) const KEYWORD_AWAIT: &'static str = "await";
+pub(crate) const KEYWORD_ELSE: &'static str = "else";
+pub(crate) const KEYWORD_EMPTY: &'static str = "empty";
+pub(crate) const KEYWORD_FLUSH: &'static str = "flush";
+pub(crate) const KEYWORD_FOR: &'static str = "for";
+pub(crate) const KEYWORD_FUNCTIONS: &'static str = "functions";
+pub(crate) const KEYWORD_IF: &'static str = "if";
+pub(crate) const KEYWORD_MATCH: &'static str = "match";
+pub(crate) const KEYWORD_PARTIAL: &'static str = "partial";
+pub(crate) const KEYWORD_RAW: &'static str = "raw";
 pub(crate) const KEYWORD_RENDER: &'static str = "render";
 pub(crate) const KEYWORD_SECTION: &'static str = "section";
+pub(crate) const KEYWORD_WHILE: &'static str = "while";
+pub(crate) const SECTION_MODE_APPEND: &'static str = "append";
+pub(crate) const SECTION_MODE_PREPEND: &'static str = "prepend";
+pub(crate) const SECTION_MODE_REPLACE: &'static str = "replace";
 
 pub(crate) const OUT_DIR_ENV_NAME: &'static str = "OUT_DIR";
 pub(crate) const RS_FILE_EXTENSION: &'static str = ".rs";
@@ -13,4 +44,6 @@ pub(crate) const TEMP_GENERATED_DIR: &'static str = "generated_views";
 pub(crate) const TEMPLATES_FILES_ENV: &'static str = "TEMPLATES_FILES";
 pub(crate) const TEMPLATES_MAP_FILE_NAME: &'static str = "ktemplate";
 pub(crate) const TEMPLATES_MOD_FILE_NAME: &'static str = "mod.rs";
+pub(crate) const VIEW_IMPORTS_FILE_NAME: &'static str = "_imports";
+pub(crate) const VIEW_START_FILE_NAME: &'static str = "_viewstart";
 pub(crate) const TEMPLATES_TYPE_NAME: &'static str = "KTemplate";
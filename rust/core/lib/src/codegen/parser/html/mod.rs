@@ -1,10 +1,13 @@
 mod doc;
 mod node;
 mod parser;
+mod scanner;
 
 #[cfg(test)]
 mod tests;
 
+pub(in crate::codegen) use scanner::ScanState;
+
 use crate::types::result;
 // The goal is not to parse and geneted a strict doc but fragments as the whole doc content is devided by diffrent type of blocks, some blocks will be evaluated
 // at runtime. Also wellformed node(s) might be generated by concatenating by all blocks(static/runtime). Goal is to do as much as possible to optimize blocks to
@@ -15,3 +18,8 @@ use crate::types::result;
 pub(in crate::codegen) fn parse_html<'s>(raw_html: &'s str) -> result::Result<doc::HtmlDocument> {
     doc::HtmlDocument::parse(raw_html)
 }
+
+// Resume scanning from the state where previous content ended and return the state where `html` ends.
+pub(in crate::codegen) fn scan_html(state: ScanState, html: &str) -> ScanState {
+    scanner::scan(state, html)
+}
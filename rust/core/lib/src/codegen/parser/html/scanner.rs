@@ -0,0 +1,125 @@
+use crate::types::EscapeContext;
+
+// Light-weight scanner to track where the static html content ends, so that expressions following
+// the content could be escaped with the right strategy. Unlike the parser, it doesn't build nodes but only keeps the
+// state which could be resumed with the next content fragment:
+// "<a href=\"" => DQATTRVAL, "<div>" => TEXT, "<img src=" => BEFOREATTRVAL
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(in crate::codegen) enum ScanState {
+    #[default]
+    TEXT,
+    // saw '<'
+    TAGOPEN,
+    TAGNAME,
+    // inside tag, between attributes.
+    TAG,
+    ATTRNAME,
+    AFTERATTRNAME,
+    // saw '='
+    BEFOREATTRVAL,
+    DQATTRVAL,
+    SQATTRVAL,
+    ATTRVAL,
+    // <!doctype or <!--
+    DECL,
+    DECLDASH,
+    // number of consecutive '-' seen.
+    COMMENT(u8),
+}
+
+impl ScanState {
+    pub(in crate::codegen) fn escape_context(&self) -> EscapeContext {
+        match self {
+            ScanState::TEXT | ScanState::COMMENT(_) => EscapeContext::KText,
+            ScanState::DQATTRVAL | ScanState::SQATTRVAL => EscapeContext::KAttribute,
+            // everything else is inside a tag: use the strictest one.
+            _ => EscapeContext::KUnquotedAttribute,
+        }
+    }
+}
+
+pub(in crate::codegen) fn scan(state: ScanState, source: &str) -> ScanState {
+    let mut state = state;
+    for c in source.chars() {
+        state = next_state(state, c);
+    }
+    state
+}
+
+fn next_state(state: ScanState, c: char) -> ScanState {
+    match state {
+        ScanState::TEXT => match c {
+            '<' => ScanState::TAGOPEN,
+            _ => ScanState::TEXT,
+        },
+        ScanState::TAGOPEN => match c {
+            '!' => ScanState::DECL,
+            '/' => ScanState::TAGNAME,
+            c if c.is_ascii_alphabetic() => ScanState::TAGNAME,
+            // "a < b" is text.
+            _ => ScanState::TEXT,
+        },
+        ScanState::TAGNAME => match c {
+            '>' => ScanState::TEXT,
+            '/' => ScanState::TAG,
+            c if c.is_whitespace() => ScanState::TAG,
+            _ => ScanState::TAGNAME,
+        },
+        ScanState::TAG => match c {
+            '>' => ScanState::TEXT,
+            '=' => ScanState::BEFOREATTRVAL,
+            '/' => ScanState::TAG,
+            c if c.is_whitespace() => ScanState::TAG,
+            _ => ScanState::ATTRNAME,
+        },
+        ScanState::ATTRNAME => match c {
+            '>' => ScanState::TEXT,
+            '=' => ScanState::BEFOREATTRVAL,
+            '/' => ScanState::TAG,
+            c if c.is_whitespace() => ScanState::AFTERATTRNAME,
+            _ => ScanState::ATTRNAME,
+        },
+        ScanState::AFTERATTRNAME => match c {
+            '>' => ScanState::TEXT,
+            '=' => ScanState::BEFOREATTRVAL,
+            '/' => ScanState::TAG,
+            c if c.is_whitespace() => ScanState::AFTERATTRNAME,
+            _ => ScanState::ATTRNAME,
+        },
+        ScanState::BEFOREATTRVAL => match c {
+            '>' => ScanState::TEXT,
+            '"' => ScanState::DQATTRVAL,
+            '\'' => ScanState::SQATTRVAL,
+            c if c.is_whitespace() => ScanState::BEFOREATTRVAL,
+            _ => ScanState::ATTRVAL,
+        },
+        ScanState::DQATTRVAL => match c {
+            '"' => ScanState::TAG,
+            _ => ScanState::DQATTRVAL,
+        },
+        ScanState::SQATTRVAL => match c {
+            '\'' => ScanState::TAG,
+            _ => ScanState::SQATTRVAL,
+        },
+        ScanState::ATTRVAL => match c {
+            '>' => ScanState::TEXT,
+            c if c.is_whitespace() => ScanState::TAG,
+            _ => ScanState::ATTRVAL,
+        },
+        ScanState::DECL => match c {
+            '-' => ScanState::DECLDASH,
+            '>' => ScanState::TEXT,
+            _ => ScanState::TAG,
+        },
+        ScanState::DECLDASH => match c {
+            '-' => ScanState::COMMENT(0),
+            '>' => ScanState::TEXT,
+            _ => ScanState::TAG,
+        },
+        ScanState::COMMENT(dashes) => match c {
+            '-' => ScanState::COMMENT((dashes + 1).min(2)),
+            '>' if dashes == 2 => ScanState::TEXT,
+            _ => ScanState::COMMENT(0),
+        },
+    }
+}
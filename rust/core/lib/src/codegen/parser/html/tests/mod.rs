@@ -1,2 +1,3 @@
 #![cfg(test)]
 mod parser;
+mod scanner;
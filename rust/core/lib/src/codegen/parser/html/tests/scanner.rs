@@ -0,0 +1,52 @@
+#![cfg(test)]
+use crate::codegen::parser::html::{ScanState, scan_html};
+use crate::types::EscapeContext;
+
+#[test]
+fn scan_text() {
+    let state = scan_html(ScanState::default(), "<div class=\"a\">hello ");
+    assert_eq!(state, ScanState::TEXT);
+    assert_eq!(state.escape_context(), EscapeContext::KText);
+
+    let state = scan_html(ScanState::default(), "1 < 2 and ");
+    assert_eq!(state, ScanState::TEXT);
+}
+
+#[test]
+fn scan_quoted_attribute() {
+    let state = scan_html(ScanState::default(), "<a href=\"/users/");
+    assert_eq!(state, ScanState::DQATTRVAL);
+    assert_eq!(state.escape_context(), EscapeContext::KAttribute);
+
+    let state = scan_html(ScanState::default(), "<a title='");
+    assert_eq!(state, ScanState::SQATTRVAL);
+    assert_eq!(state.escape_context(), EscapeContext::KAttribute);
+}
+
+#[test]
+fn scan_unquoted_attribute() {
+    let state = scan_html(ScanState::default(), "<input value=");
+    assert_eq!(state, ScanState::BEFOREATTRVAL);
+    assert_eq!(state.escape_context(), EscapeContext::KUnquotedAttribute);
+
+    let state = scan_html(ScanState::default(), "<input ");
+    assert_eq!(state.escape_context(), EscapeContext::KUnquotedAttribute);
+}
+
+#[test]
+fn scan_resumed_from_previous_state() {
+    // <a href="@url">@name</a>
+    let state = scan_html(ScanState::default(), "<a href=\"");
+    assert_eq!(state, ScanState::DQATTRVAL);
+    let state = scan_html(state, "\">");
+    assert_eq!(state, ScanState::TEXT);
+}
+
+#[test]
+fn scan_comment() {
+    let state = scan_html(ScanState::default(), "<!-- <a href=\"");
+    assert_eq!(state, ScanState::COMMENT(0));
+    assert_eq!(state.escape_context(), EscapeContext::KText);
+    let state = scan_html(state, " -->");
+    assert_eq!(state, ScanState::TEXT);
+}
@@ -0,0 +1,17 @@
+mod scanner;
+mod validator;
+
+#[cfg(test)]
+mod tests;
+
+pub(in crate::codegen) use scanner::ScanState;
+
+// Resume scanning from the state where previous content ended and return the state where `json` ends.
+pub(in crate::codegen) fn scan_json(state: ScanState, json: &str) -> ScanState {
+    scanner::scan(state, json)
+}
+
+// Check the json is a single well-formed value, error is (byte offset, message).
+pub(in crate::codegen) fn validate_json(json: &str) -> Result<(), (usize, String)> {
+    validator::validate(json)
+}
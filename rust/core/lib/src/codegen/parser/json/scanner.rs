@@ -0,0 +1,41 @@
+use crate::types::EscapeContext;
+
+// Tracks whether the static json content ends inside a string literal or not, so that expressions
+// following the content could be escaped as string chars or written as values:
+// "{\"name\": \"" => STRING, "{\"age\": " => VALUE
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(in crate::codegen) enum ScanState {
+    #[default]
+    VALUE,
+    STRING,
+    // saw '\' in string
+    ESCAPE,
+}
+
+impl ScanState {
+    pub(in crate::codegen) fn escape_context(&self) -> EscapeContext {
+        match self {
+            ScanState::VALUE => EscapeContext::KJsonValue,
+            ScanState::STRING | ScanState::ESCAPE => EscapeContext::KJsonString,
+        }
+    }
+}
+
+pub(in crate::codegen) fn scan(state: ScanState, source: &str) -> ScanState {
+    let mut state = state;
+    for c in source.chars() {
+        state = match state {
+            ScanState::VALUE => match c {
+                '"' => ScanState::STRING,
+                _ => ScanState::VALUE,
+            },
+            ScanState::STRING => match c {
+                '"' => ScanState::VALUE,
+                '\\' => ScanState::ESCAPE,
+                _ => ScanState::STRING,
+            },
+            ScanState::ESCAPE => ScanState::STRING,
+        };
+    }
+    state
+}
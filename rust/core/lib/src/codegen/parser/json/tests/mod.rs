@@ -0,0 +1,3 @@
+#![cfg(test)]
+mod scanner;
+mod validator;
@@ -0,0 +1,31 @@
+#![cfg(test)]
+use crate::codegen::parser::json::{ScanState, scan_json};
+use crate::types::EscapeContext;
+
+#[test]
+fn scan_value() {
+    let state = scan_json(ScanState::default(), "{\"age\": ");
+    assert_eq!(state, ScanState::VALUE);
+    assert_eq!(state.escape_context(), EscapeContext::KJsonValue);
+}
+
+#[test]
+fn scan_string() {
+    let state = scan_json(ScanState::default(), "{\"name\": \"");
+    assert_eq!(state, ScanState::STRING);
+    assert_eq!(state.escape_context(), EscapeContext::KJsonString);
+
+    let state = scan_json(ScanState::default(), "{\"name\": \"say \\\"");
+    assert_eq!(state, ScanState::STRING);
+}
+
+#[test]
+fn scan_resume() {
+    let state = scan_json(ScanState::default(), "{\"name\": \"");
+    let state = scan_json(state, "\", \"age\": ");
+    assert_eq!(state, ScanState::VALUE);
+
+    let state = scan_json(ScanState::default(), "[\"\\");
+    assert_eq!(state, ScanState::ESCAPE);
+    assert_eq!(state.escape_context(), EscapeContext::KJsonString);
+}
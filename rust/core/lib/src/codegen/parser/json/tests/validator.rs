@@ -0,0 +1,35 @@
+#![cfg(test)]
+use crate::codegen::parser::json::validate_json;
+
+#[test]
+fn validate_valid_json() {
+    assert!(validate_json("{}").is_ok());
+    assert!(validate_json(" [1, -2.5e3, true, false, null, \"a\\n\\u00e9\"] ").is_ok());
+    assert!(validate_json("{\"a\": {\"b\": [0, {}]}, \"c\": \"\"}").is_ok());
+}
+
+#[test]
+fn validate_trailing_comma() {
+    let result = validate_json("{\"a\": 1,}");
+    assert_eq!(
+        result,
+        Err((8, "Expected a string key in object".to_string()))
+    );
+}
+
+#[test]
+fn validate_missing_value() {
+    let result = validate_json("{\"a\": }");
+    assert_eq!(
+        result,
+        Err((6, "Unexpected '}', expected a json value".to_string()))
+    );
+}
+
+#[test]
+fn validate_unterminated() {
+    assert!(validate_json("{\"a\": \"b").is_err());
+    assert!(validate_json("[1, 2").is_err());
+    assert!(validate_json("{} {}").is_err());
+    assert!(validate_json("01").is_err());
+}
@@ -0,0 +1,196 @@
+// Minimal recursive descent checker for RFC 8259 json, it doesn't build values but only reports
+// the first error with its byte offset.
+struct Validator<'s> {
+    source: &'s [u8],
+    pos: usize,
+}
+
+type ValidateResult = Result<(), (usize, String)>;
+
+pub(in crate::codegen) fn validate(json: &str) -> ValidateResult {
+    let mut validator = Validator {
+        source: json.as_bytes(),
+        pos: 0,
+    };
+    validator.skip_whitespace();
+    validator.value()?;
+    validator.skip_whitespace();
+    match validator.peek() {
+        None => Ok(()),
+        Some(c) => validator.error(&format!("Unexpected '{}' after json value", c as char)),
+    }
+}
+
+impl<'s> Validator<'s> {
+    fn peek(&self) -> Option<u8> {
+        self.source.get(self.pos).copied()
+    }
+
+    fn error(&self, message: &str) -> ValidateResult {
+        Err((self.pos, message.to_string()))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, c: u8) -> ValidateResult {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(current) if current == c => {
+                self.pos += 1;
+                Ok(())
+            }
+            Some(current) => self.error(&format!(
+                "Expected '{}' but found '{}'",
+                c as char, current as char
+            )),
+            None => self.error(&format!("Expected '{}' but reached the end", c as char)),
+        }
+    }
+
+    fn value(&mut self) -> ValidateResult {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'{') => self.object(),
+            Some(b'[') => self.array(),
+            Some(b'"') => self.string(),
+            Some(b't') => self.literal("true"),
+            Some(b'f') => self.literal("false"),
+            Some(b'n') => self.literal("null"),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            Some(c) => self.error(&format!(
+                "Unexpected '{}', expected a json value",
+                c as char
+            )),
+            None => self.error("Expected a json value but reached the end"),
+        }
+    }
+
+    fn object(&mut self) -> ValidateResult {
+        self.expect(b'{')?;
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(());
+        }
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some(b'"') {
+                return self.error("Expected a string key in object");
+            }
+            self.string()?;
+            self.expect(b':')?;
+            self.value()?;
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                _ => return self.error("Expected ',' or '}' in object"),
+            }
+        }
+    }
+
+    fn array(&mut self) -> ValidateResult {
+        self.expect(b'[')?;
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(());
+        }
+        loop {
+            self.value()?;
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                _ => return self.error("Expected ',' or ']' in array"),
+            }
+        }
+    }
+
+    fn string(&mut self) -> ValidateResult {
+        self.expect(b'"')?;
+        loop {
+            match self.peek() {
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                Some(b'\\') => {
+                    self.pos += 1;
+                    match self.peek() {
+                        Some(b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't') => {
+                            self.pos += 1
+                        }
+                        Some(b'u') => {
+                            self.pos += 1;
+                            for _ in 0..4 {
+                                match self.peek() {
+                                    Some(c) if c.is_ascii_hexdigit() => self.pos += 1,
+                                    _ => return self.error("Invalid unicode escape in string"),
+                                }
+                            }
+                        }
+                        _ => return self.error("Invalid escape in string"),
+                    }
+                }
+                Some(c) if c < 0x20 => return self.error("Control char must be escaped in string"),
+                Some(_) => self.pos += 1,
+                None => return self.error("Unterminated string"),
+            }
+        }
+    }
+
+    fn number(&mut self) -> ValidateResult {
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        match self.peek() {
+            Some(b'0') => self.pos += 1,
+            Some(b'1'..=b'9') => self.digits(),
+            _ => return self.error("Invalid number"),
+        }
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            if !matches!(self.peek(), Some(b'0'..=b'9')) {
+                return self.error("Expected digit after '.'");
+            }
+            self.digits();
+        }
+        if let Some(b'e' | b'E') = self.peek() {
+            self.pos += 1;
+            if let Some(b'+' | b'-') = self.peek() {
+                self.pos += 1;
+            }
+            if !matches!(self.peek(), Some(b'0'..=b'9')) {
+                return self.error("Expected digit in exponent");
+            }
+            self.digits();
+        }
+        Ok(())
+    }
+
+    fn digits(&mut self) {
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn literal(&mut self, literal: &str) -> ValidateResult {
+        if self.source[self.pos..].starts_with(literal.as_bytes()) {
+            self.pos += literal.len();
+            Ok(())
+        } else {
+            self.error(&format!("Invalid literal, expected '{}'", literal))
+        }
+    }
+}
@@ -37,7 +37,7 @@ tokenizer_test_case!(tokenizer_eof, vec![""], [token::Kind::EOF]);
 tokenizer_test_case!(
     tokenizer_symbols,
     vec![
-        "@", "=", "!", "-", "<", ">", "{", "}", "(", ")", "/", "*", ";", ",", ":", "\"", "'",
+        "@", "=", "!", "-", "<", ">", "{", "}", "(", ")", "/", "*", ";", ",", ":", "\"", "'", "~",
         "\r\n", "\n"
     ],
     vec![
@@ -58,6 +58,7 @@ tokenizer_test_case!(
         token::Kind::COLON,
         token::Kind::DQMARK,
         token::Kind::SQMAERK,
+        token::Kind::TILDE,
         token::Kind::NEWLINE,
         token::Kind::NEWLINE,
         token::Kind::EOF
@@ -81,6 +82,20 @@ tokenizer_test_case!(
     ]
 );
 
+tokenizer_test_case!(
+    tokenizer_exp_with_member_access,
+    vec!["user.name", ".", " ", "items[0].title", " ", "1.5"],
+    [
+        token::Kind::EXPRESSION,
+        token::Kind::EXPRESSION,
+        token::Kind::WHITESPACE,
+        token::Kind::EXPRESSION,
+        token::Kind::WHITESPACE,
+        token::Kind::EXPRESSION,
+        token::Kind::EOF
+    ]
+);
+
 tokenizer_test_case!(
     tokenizer_unicode_stream,
     vec!["你好", ";", "hello", ")", "世界"],
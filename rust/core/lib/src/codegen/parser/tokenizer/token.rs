@@ -31,6 +31,7 @@ pub enum Kind {
     COMMA = b',',
     DQMARK = b'"',
     SQMAERK = b'\'',
+    TILDE = b'~',
 }
 
 impl std::fmt::Display for Token {
@@ -71,6 +72,7 @@ pub(crate) fn tokenize(stream: &mut StrStream<'_>, location: &mut Location) -> T
         b',' => tokenize_symbol(stream, Kind::COMMA, location),
         b'"' => tokenize_symbol(stream, Kind::DQMARK, location),
         b'\'' => tokenize_symbol(stream, Kind::SQMAERK, location),
+        b'~' => tokenize_symbol(stream, Kind::TILDE, location),
         b' ' => tokenize_whitespace(stream, location),
         b'\r' => tokenize_newline(stream, location),
         b'\n' => tokenize_newline(stream, location),
@@ -121,11 +123,19 @@ fn tokenize_newline(stream: &mut StrStream<'_>, location: &mut Location) -> Toke
 
 fn tokenize_expression(stream: &mut StrStream<'_>, location: &Location) -> Token {
     let start = stream.current_token_start();
-    const TOKEN_START: &[u8] = b"@=!-<>(){}/*,; :\"'\r\n";
-    let offset = stream
-        .as_bstr()
-        .offset_for(|b| TOKEN_START.contains_token(b))
-        .unwrap_or_else(|| stream.eof_offset());
+    const TOKEN_START: &[u8] = b"@=!-<>(){}/*,; :\"'~\r\n";
+    let bytes = stream.as_bstr();
+    let mut offset = 0;
+    while offset < bytes.len() && !TOKEN_START.contains_token(bytes[offset]) {
+        // a '.' which doesn't start a member access ends the expression, like `@name.`
+        if offset > 0 && bytes[offset] == b'.' {
+            match bytes.get(offset + 1) {
+                Some(b) if b.is_ascii_alphanumeric() || *b == b'_' => {}
+                _ => break,
+            }
+        }
+        offset += 1;
+    }
     stream.next_slice(offset);
     let end = stream.previous_token_end();
     let loc = Location::new(location.line, start - location.column);
@@ -0,0 +1,47 @@
+use crate::codegen::consts;
+use crate::codegen::parser::Token;
+use crate::codegen::parser::tokenizer::{self, TokenStream};
+use crate::codegen::parser::types::context::{Kind, ParseContext};
+use crate::codegen::types::Block;
+use crate::types::{error, result};
+use winnow::stream::Stream as _;
+
+impl<'a> Block<'a> {
+    // @await exp or @await (exp)
+    pub(in crate::codegen::parser::types) fn parse_await<'s>(
+        token: &Token,
+        token_stream: &mut TokenStream,
+        context: &mut ParseContext<'_, 's>,
+    ) -> result::Result<Block<'s>> {
+        let source = context.source();
+        // consume the await token
+        token_stream.next_token();
+        tokenizer::skip_whitespace(token_stream);
+        // the awaited expression is parsed like `@exp` or `@(exp)`.
+        let context = &mut context.clone_for(Kind::KINLINEDCODE);
+        let block = match token_stream.peek_token() {
+            Some(next) if next.kind() == tokenizer::Kind::OPARENTHESIS => {
+                Some(Self::parse_block_within_kinds(
+                    tokenizer::Kind::OPARENTHESIS,
+                    tokenizer::Kind::CPARENTHESIS,
+                    token_stream,
+                    context,
+                )?)
+            }
+            Some(next) if next.kind() == tokenizer::Kind::EXPRESSION => Some(
+                Self::create_inlined_code_block(next, token_stream, context)?,
+            ),
+            _ => None,
+        };
+        match block {
+            Some(Block::KINLINEDCODE(span)) if !span.content().trim().is_empty() => {
+                Ok(Block::new_await(span))
+            }
+            _ => Err(error::CompileError::from_parser(
+                source,
+                Some(*token),
+                &format!("Expected expression after '@{}'", consts::KEYWORD_AWAIT),
+            )),
+        }
+    }
+}
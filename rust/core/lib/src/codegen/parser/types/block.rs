@@ -39,6 +39,9 @@ impl<'a> Block<'a> {
                             match context.switch_if_possible(token_stream) {
                                 Ok((true, mut new_context)) => {
                                     // 1. consume the current pending tokens belong to current context.
+                                    if util::is_trim_marked(token_stream) {
+                                        context.trim_end();
+                                    }
                                     if let Some(block) = context.consume()? {
                                         span.push_block(block);
                                     }
@@ -48,6 +51,7 @@ impl<'a> Block<'a> {
                                         token_stream,
                                         &mut new_context,
                                     )?;
+                                    context.mark_content(&block);
                                     span.push_block(block);
                                 }
                                 Ok((false, _)) => {
@@ -163,6 +167,9 @@ impl<'a> Block<'a> {
                     match context.switch_if_possible(token_stream) {
                         Ok((true, mut new_context)) => {
                             // 1. consume the current pending tokens belong to current context.
+                            if util::is_trim_marked(token_stream) {
+                                context.trim_end();
+                            }
                             if let Some(block) = context.consume()? {
                                 span.push_block(block);
                             }
@@ -0,0 +1,112 @@
+use crate::codegen::consts;
+use crate::codegen::parser::Token;
+use crate::codegen::parser::tokenizer::{self, TokenStream, get_nth_token};
+use crate::codegen::parser::types::context::{Kind, ParseContext};
+use crate::codegen::parser::types::util;
+use crate::codegen::types::{Block, Span};
+use crate::types::{error, result};
+use winnow::stream::Stream as _;
+
+impl<'a> Block<'a> {
+    // @if cond { content } else if cond { content } else { content }
+    pub(in crate::codegen::parser::types) fn parse_if<'s>(
+        token: &Token,
+        token_stream: &mut TokenStream,
+        context: &mut ParseContext<'_, 's>,
+    ) -> result::Result<Block<'s>> {
+        let source = context.source();
+        // consume the if token
+        token_stream.next_token();
+
+        let mut span = Span::new(source);
+        let mut condition = Some(Self::parse_condition(
+            consts::KEYWORD_IF,
+            token,
+            token_stream,
+            context,
+        )?);
+        loop {
+            let branch = Self::parse_branch(condition.unwrap_or_default(), token_stream, context)?;
+            span.push_block(branch);
+            if condition.is_none() {
+                break;
+            }
+
+            // else is only taken when followed by '{' or 'if', otherwise it's content.
+            let Some(offset) = util::peek_keyword(token_stream, source, consts::KEYWORD_ELSE)
+            else {
+                break;
+            };
+            let Some(next_offset) = util::skip_whitespace_offset(token_stream, offset) else {
+                break;
+            };
+            let next_token = get_nth_token(token_stream, next_offset).unwrap();
+            match next_token.kind() {
+                tokenizer::Kind::OCURLYBRACKET => {
+                    util::advance(token_stream, next_offset);
+                    condition = None;
+                }
+                tokenizer::Kind::EXPRESSION
+                    if &source[next_token.range()] == consts::KEYWORD_IF =>
+                {
+                    util::advance(token_stream, next_offset + 1);
+                    condition = Some(Self::parse_condition(
+                        consts::KEYWORD_IF,
+                        next_token,
+                        token_stream,
+                        context,
+                    )?);
+                }
+                _ => break,
+            }
+        }
+
+        ParseContext::create_block(context, None, span)
+    }
+
+    // header of control blocks: `@if cond {`, `@while cond {`.
+    pub(in crate::codegen::parser::types) fn parse_condition<'s>(
+        keyword: &str,
+        token: &Token,
+        token_stream: &mut TokenStream,
+        context: &ParseContext<'_, 's>,
+    ) -> result::Result<&'s str> {
+        let source = context.source();
+        if !tokenizer::skip_whitespace(token_stream) {
+            return Err(error::CompileError::from_parser(
+                source,
+                Some(*token),
+                &format!("Expected whitespace after '{}'", keyword),
+            ));
+        }
+        let condition = util::parse_block_header(token_stream).and_then(|tokens| {
+            let range = tokens.first()?.range().start..tokens.last()?.range().end;
+            Some(source[range].trim())
+        });
+        match condition {
+            Some(condition) if !condition.is_empty() => Ok(condition),
+            _ => Err(error::CompileError::from_parser(
+                source,
+                Some(*token),
+                &format!("Expected condition and '{{' after '{}'", keyword),
+            )),
+        }
+    }
+
+    // branch body is content: `{ <p>content</p> }`.
+    pub(in crate::codegen::parser::types) fn parse_branch<'s>(
+        condition: &str,
+        token_stream: &mut TokenStream,
+        context: &ParseContext<'_, 's>,
+    ) -> result::Result<Block<'s>> {
+        let body = Self::parse_block_within_kinds(
+            tokenizer::Kind::OCURLYBRACKET,
+            tokenizer::Kind::CCURLYBRACKET,
+            token_stream,
+            &mut context.clone_for(Kind::KCONTENT),
+        )?;
+        let mut span = Span::new(context.source());
+        span.push_block(body);
+        Ok(Block::new_branch(condition, span))
+    }
+}
@@ -1,7 +1,7 @@
 use crate::codegen::parser::tokenizer::{TokenStream, get_nth_token};
 use crate::codegen::parser::{Token, tokenizer};
-use crate::codegen::types::Block;
 use crate::codegen::types::Span;
+use crate::codegen::types::{Block, SectionMode};
 use crate::codegen::{CompilerOptions, consts};
 use crate::types::template;
 use crate::types::{error, result};
@@ -13,14 +13,22 @@ pub(in crate::codegen) enum Kind {
     KCODE,
     KCOMMENT,
     KCONTENT,
+    KFOR,
     KFUNCTIONS,
+    KIF,
+    KINJECT,
     KINLINEDCODE,
     KINLINEDCONTENT,
     KLAYOUT,
+    KMATCH,
+    KMODEL,
+    KPARTIAL,
+    KRAW,
     KRENDER,
     KROOT,
     KSECTION,
     KUSE,
+    KWHILE,
 }
 
 #[derive(Clone)]
@@ -30,6 +38,8 @@ pub(in crate::codegen) struct ParseContext<'a, 's> {
     tokens: Vec<Token>,
     compiler_option: &'a CompilerOptions,
     source: &'s str,
+    // content or a block other than directives and comments was parsed.
+    content_started: bool,
 }
 
 impl<'a, 's> ParseContext<'a, 's> {
@@ -45,6 +55,7 @@ impl<'a, 's> ParseContext<'a, 's> {
             template_kind: template_kind,
             compiler_option: option,
             source: source,
+            content_started: false,
         }
     }
 
@@ -55,6 +66,7 @@ impl<'a, 's> ParseContext<'a, 's> {
             template_kind: self.template_kind,
             compiler_option: self.compiler_option,
             source: self.source,
+            content_started: false,
         }
     }
 
@@ -66,6 +78,43 @@ impl<'a, 's> ParseContext<'a, 's> {
         self.tokens.push(token);
     }
 
+    // `@~` strips the whitespace and newlines before the transition.
+    pub(in crate::codegen) fn trim_end(&mut self) {
+        while self.tokens.last().is_some_and(|t| {
+            matches!(
+                t.kind(),
+                tokenizer::Kind::WHITESPACE | tokenizer::Kind::NEWLINE
+            )
+        }) {
+            self.tokens.pop();
+        }
+    }
+
+    // `@model Type` is a directive before any content, `@model` after it is the value.
+    pub(in crate::codegen) fn mark_content(&mut self, block: &Block) {
+        if !matches!(
+            block,
+            Block::KCOMMENT(_)
+                | Block::KFUNCTIONS(_)
+                | Block::KINJECT(_)
+                | Block::KLAYOUT(_)
+                | Block::KMODEL(_)
+                | Block::KUSE(_)
+        ) {
+            self.content_started = true;
+        }
+    }
+
+    fn has_content(&self) -> bool {
+        self.content_started
+            || self.tokens.iter().any(|t| {
+                !matches!(
+                    t.kind(),
+                    tokenizer::Kind::WHITESPACE | tokenizer::Kind::NEWLINE
+                )
+            })
+    }
+
     pub(in crate::codegen) fn is_block(&self) -> bool {
         matches!(self.block_kind, Kind::KCONTENT | Kind::KROOT | Kind::KCODE)
     }
@@ -80,12 +129,21 @@ impl<'a, 's> ParseContext<'a, 's> {
     pub(in crate::codegen) fn is_code(&self) -> bool {
         matches!(
             self.block_kind,
-            Kind::KCODE | Kind::KFUNCTIONS | Kind::KINLINEDCODE | Kind::KLAYOUT | Kind::KUSE
+            Kind::KCODE
+                | Kind::KFUNCTIONS
+                | Kind::KINJECT
+                | Kind::KINLINEDCODE
+                | Kind::KLAYOUT
+                | Kind::KMODEL
+                | Kind::KUSE
         )
     }
 
     pub(in crate::codegen) fn is_inline(&self) -> bool {
-        matches!(self.block_kind, Kind::KINLINEDCODE | Kind::KINLINEDCONTENT)
+        matches!(
+            self.block_kind,
+            Kind::KINLINEDCODE | Kind::KINLINEDCONTENT | Kind::KRAW
+        )
     }
 
     pub(in crate::codegen) fn consume(&mut self) -> result::Result<Option<Block<'s>>> {
@@ -97,6 +155,7 @@ impl<'a, 's> ParseContext<'a, 's> {
             span.push_token(*token);
         }
 
+        self.content_started = self.has_content();
         self.tokens.clear();
         // workaround fix later.
         let context = if matches!(self.block_kind, Kind::KROOT) {
@@ -115,6 +174,10 @@ impl<'a, 's> ParseContext<'a, 's> {
     pub(in crate::codegen) fn source(&self) -> &'s str {
         self.source
     }
+
+    pub(in crate::codegen) fn need_trim_blocks(&self) -> bool {
+        self.compiler_option.need_trim_blocks(self.template_kind)
+    }
 }
 
 impl<'a, 's> ParseContext<'a, 's> {
@@ -143,8 +206,12 @@ impl<'a, 's> ParseContext<'a, 's> {
             }
         };
 
-        // check the next token after '@'
-        let next_token = get_nth_token(token_stream, 1);
+        // check the next token after '@' and the trim marker `@~`.
+        let offset = match get_nth_token(token_stream, 1) {
+            Some(token) if token.kind() == tokenizer::Kind::TILDE => 2,
+            _ => 1,
+        };
+        let next_token = get_nth_token(token_stream, offset);
         if None == next_token {
             // no token after '@', don't switch context
             return Ok((false, self.clone_for(self.block_kind())));
@@ -172,6 +239,17 @@ impl<'a, 's> ParseContext<'a, 's> {
                             ))
                         }
                     }
+                    consts::DIRECTIVE_KEYWORD_INJECT => {
+                        if self.block_kind() == Kind::KROOT {
+                            Ok((true, self.clone_for(Kind::KINJECT)))
+                        } else {
+                            Err(error::CompileError::from_parser(
+                                source,
+                                Some(*next_token),
+                                "The 'inject' directive is only allowed in the root context.",
+                            ))
+                        }
+                    }
                     consts::DIRECTIVE_KEYWORD_LAYOUT => {
                         if self.block_kind() == Kind::KROOT {
                             // only allowed in root context.
@@ -184,6 +262,50 @@ impl<'a, 's> ParseContext<'a, 's> {
                             ))
                         }
                     }
+                    // `@model` followed by a type in the root before any content,
+                    // otherwise it's the model value like `@model.name` or `<p>@model is ready</p>`.
+                    consts::DIRECTIVE_KEYWORD_MODEL
+                        if self.block_kind() == Kind::KROOT
+                            && !self.has_content()
+                            && get_nth_token(token_stream, offset + 1)
+                                .is_some_and(|t| t.kind() == tokenizer::Kind::WHITESPACE) =>
+                    {
+                        Ok((true, self.clone_for(Kind::KMODEL)))
+                    }
+                    consts::KEYWORD_FUNCTIONS => {
+                        if self.block_kind() == Kind::KROOT {
+                            // emitted at module level.
+                            Ok((true, self.clone_for(Kind::KFUNCTIONS)))
+                        } else {
+                            Err(error::CompileError::from_parser(
+                                source,
+                                Some(*next_token),
+                                "The 'functions' is only allowed in the root context.",
+                            ))
+                        }
+                    }
+                    consts::KEYWORD_IF
+                    | consts::KEYWORD_FOR
+                    | consts::KEYWORD_MATCH
+                    | consts::KEYWORD_WHILE => {
+                        if !self.is_code() && !self.is_inline() {
+                            let kind = match exp {
+                                consts::KEYWORD_IF => Kind::KIF,
+                                consts::KEYWORD_FOR => Kind::KFOR,
+                                consts::KEYWORD_MATCH => Kind::KMATCH,
+                                _ => Kind::KWHILE,
+                            };
+                            Ok((true, self.clone_for(kind)))
+                        } else {
+                            Err(error::CompileError::from_parser(
+                                source,
+                                Some(*next_token),
+                                &format!(
+                                    "The '{exp}' is only allowed in the content context, use rust '{exp}' in code."
+                                ),
+                            ))
+                        }
+                    }
                     consts::KEYWORD_SECTION => {
                         if self.is_block() && self.block_kind() != Kind::KSECTION {
                             // todo: how to detect nested section in side section?
@@ -248,21 +370,29 @@ impl<'a, 's> ParseContext<'a, 's> {
         span: Span<'s>,
     ) -> result::Result<Block<'s>> {
         match name {
-            Some(name) => Ok(Block::new_section(&name, span)),
+            Some(name) => Ok(Block::new_section(&name, SectionMode::KDEFAULT, span)),
             None => {
                 // convert to block.
                 match context.block_kind() {
                     Kind::KCODE => Ok(Block::new_code(span)),
                     Kind::KCOMMENT => Ok(Block::new_comment(span)),
                     Kind::KCONTENT => Ok(Block::new_content(span)),
+                    Kind::KFOR => Ok(Block::new_for(span)),
                     Kind::KFUNCTIONS => Ok(Block::new_functions(span)),
+                    Kind::KIF => Ok(Block::new_if(span)),
+                    Kind::KINJECT => Ok(Block::new_inject(span)),
                     Kind::KINLINEDCODE => Ok(Block::new_inline_code(span)),
                     Kind::KINLINEDCONTENT => Ok(Block::new_inline_content(span)),
                     Kind::KLAYOUT => Ok(Block::new_layout(span)),
+                    Kind::KMATCH => Ok(Block::new_match(span)),
+                    Kind::KMODEL => Ok(Block::new_model(span)),
+                    Kind::KPARTIAL => Ok(Block::new_partial(span)),
+                    Kind::KRAW => Ok(Block::new_raw(span)),
                     Kind::KRENDER => Ok(Block::new_render(span)),
                     Kind::KROOT => Ok(Block::new_root(span)),
-                    Kind::KSECTION => Ok(Block::new_section("", span)),
+                    Kind::KSECTION => Ok(Block::new_section("", SectionMode::KDEFAULT, span)),
                     Kind::KUSE => Ok(Block::new_use(span)),
+                    Kind::KWHILE => Ok(Block::new_while(span)),
                 }
             }
         }
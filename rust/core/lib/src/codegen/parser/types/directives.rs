@@ -35,6 +35,28 @@ impl<'a> Block<'a> {
         // consume the directive token
         token_stream.next_token();
 
+        // @layout(exp) is evaluated when the view is rendered.
+        if directive == consts::DIRECTIVE_KEYWORD_LAYOUT
+            && get_nth_token(token_stream, 0).map(|t| t.kind())
+                == Some(tokenizer::Kind::OPARENTHESIS)
+        {
+            let count = Self::parse_call_arguments(token_stream, source).ok_or_else(|| {
+                error::CompileError::from_parser(
+                    source,
+                    Some(*start_token),
+                    &format!("Expected ')' to close '@{directive}('"),
+                )
+            })?;
+            let mut span = Span::new(source);
+            for _ in 0..count {
+                if let Some(token) = token_stream.next_token() {
+                    span.push_token(*token);
+                }
+            }
+            tokenizer::skip_next_token_if(token_stream, |k| k == tokenizer::Kind::SEMICOLON);
+            return Ok(Block::new_layout(span));
+        }
+
         // whitespace after directive token
         if !tokenizer::skip_whitespace(token_stream) {
             return Err(error::CompileError::from_parser(
@@ -79,7 +101,9 @@ impl<'a> Block<'a> {
         }
 
         match directive {
+            consts::DIRECTIVE_KEYWORD_INJECT => Ok(Block::new_inject(span)),
             consts::DIRECTIVE_KEYWORD_LAYOUT => Ok(Block::new_layout(span)),
+            consts::DIRECTIVE_KEYWORD_MODEL => Ok(Block::new_model(span)),
             consts::DIRECTIVE_KEYWORD_USE => Ok(Block::new_use(span)),
             _ => Err(error::CompileError::from_parser(
                 source,
@@ -0,0 +1,22 @@
+use crate::codegen::parser::Token;
+use crate::codegen::parser::tokenizer::{self, TokenStream};
+use crate::codegen::parser::types::context::ParseContext;
+use crate::codegen::types::{Block, Span};
+use crate::types::result;
+use winnow::stream::Stream as _;
+
+impl<'a> Block<'a> {
+    // @flush or @flush;
+    pub(in crate::codegen::parser::types) fn parse_flush<'s>(
+        token: &Token,
+        token_stream: &mut TokenStream,
+        context: &mut ParseContext<'_, 's>,
+    ) -> result::Result<Block<'s>> {
+        // consume the flush token
+        token_stream.next_token();
+        let mut span = Span::new(context.source());
+        span.push_token(*token);
+        tokenizer::skip_next_token_if(token_stream, |k| k == tokenizer::Kind::SEMICOLON);
+        Ok(Block::new_flush(span))
+    }
+}
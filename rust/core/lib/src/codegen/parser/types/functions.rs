@@ -0,0 +1,48 @@
+use crate::codegen::consts;
+use crate::codegen::parser::Token;
+use crate::codegen::parser::tokenizer::{self, TokenStream};
+use crate::codegen::parser::types::context::ParseContext;
+use crate::codegen::types::Block;
+use crate::types::{error, result};
+use winnow::stream::Stream as _;
+
+impl<'a> Block<'a> {
+    // @functions { fn helper() {} struct Row {} }
+    pub(in crate::codegen::parser::types) fn parse_functions<'s>(
+        token: &Token,
+        token_stream: &mut TokenStream,
+        context: &mut ParseContext<'_, 's>,
+    ) -> result::Result<Block<'s>> {
+        let source = context.source();
+        // consume the functions token
+        token_stream.next_token();
+        tokenizer::skip_whitespace_and_newline(token_stream);
+        match token_stream.peek_token() {
+            Some(t) if t.kind() == tokenizer::Kind::OCURLYBRACKET => {}
+            _ => {
+                return Err(error::CompileError::from_parser(
+                    source,
+                    Some(*token),
+                    &format!("Expected '{{' after '@{}'", consts::KEYWORD_FUNCTIONS),
+                ));
+            }
+        }
+
+        let block = Self::parse_block_within_kinds(
+            tokenizer::Kind::OCURLYBRACKET,
+            tokenizer::Kind::CCURLYBRACKET,
+            token_stream,
+            context,
+        )?;
+        // pure rust, there is no writer at module level.
+        if !matches!(block, Block::KFUNCTIONS(_)) || block.span().has_blocks() {
+            return Err(error::CompileError::from_parser(
+                source,
+                Some(*token),
+                &format!("Content is not allowed in '@{}'", consts::KEYWORD_FUNCTIONS),
+            ));
+        }
+
+        Ok(block)
+    }
+}
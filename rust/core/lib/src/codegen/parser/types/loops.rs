@@ -0,0 +1,87 @@
+use crate::codegen::consts;
+use crate::codegen::parser::Token;
+use crate::codegen::parser::tokenizer::{self, TokenStream, get_nth_token};
+use crate::codegen::parser::types::context::ParseContext;
+use crate::codegen::parser::types::util;
+use crate::codegen::types::{Block, Span};
+use crate::types::{error, result};
+use winnow::stream::Stream as _;
+
+impl<'a> Block<'a> {
+    // @for pattern in exp { content } @empty { content }
+    pub(in crate::codegen::parser::types) fn parse_for<'s>(
+        token: &Token,
+        token_stream: &mut TokenStream,
+        context: &mut ParseContext<'_, 's>,
+    ) -> result::Result<Block<'s>> {
+        let source = context.source();
+        // consume the for token
+        token_stream.next_token();
+
+        let header = Self::parse_condition(consts::KEYWORD_FOR, token, token_stream, context)?;
+        // `in` surrounded by any whitespace, the header could span lines.
+        let has_in = header.match_indices("in").any(|(index, _)| {
+            let (pattern, expression) = (&header[..index], &header[index + 2..]);
+            pattern.ends_with(char::is_whitespace)
+                && expression.starts_with(char::is_whitespace)
+                && !pattern.trim().is_empty()
+                && !expression.trim().is_empty()
+        });
+        if !has_in {
+            return Err(error::CompileError::from_parser(
+                source,
+                Some(*token),
+                &format!(
+                    "Expected 'pattern in expression' after '{}'",
+                    consts::KEYWORD_FOR
+                ),
+            ));
+        }
+
+        let mut span = Span::new(source);
+        span.push_block(Self::parse_branch(header, token_stream, context)?);
+
+        // optional @empty { content } for no item.
+        if let Some(offset) = Self::peek_empty(token_stream, source) {
+            util::advance(token_stream, offset);
+            span.push_block(Self::parse_branch("", token_stream, context)?);
+        }
+
+        ParseContext::create_block(context, None, span)
+    }
+
+    // @while cond { content }
+    pub(in crate::codegen::parser::types) fn parse_while<'s>(
+        token: &Token,
+        token_stream: &mut TokenStream,
+        context: &mut ParseContext<'_, 's>,
+    ) -> result::Result<Block<'s>> {
+        // consume the while token
+        token_stream.next_token();
+
+        let condition = Self::parse_condition(consts::KEYWORD_WHILE, token, token_stream, context)?;
+        let mut span = Span::new(context.source());
+        span.push_block(Self::parse_branch(condition, token_stream, context)?);
+
+        ParseContext::create_block(context, None, span)
+    }
+
+    // offset of '{' if next tokens are `@empty {`.
+    fn peek_empty(token_stream: &TokenStream, source: &str) -> Option<usize> {
+        let offset = util::skip_whitespace_offset(token_stream, 0)?;
+        if get_nth_token(token_stream, offset)?.kind() != tokenizer::Kind::AT {
+            return None;
+        }
+        let keyword = get_nth_token(token_stream, offset + 1)?;
+        if keyword.kind() != tokenizer::Kind::EXPRESSION
+            || &source[keyword.range()] != consts::KEYWORD_EMPTY
+        {
+            return None;
+        }
+        let offset = util::skip_whitespace_offset(token_stream, offset + 2)?;
+        match get_nth_token(token_stream, offset)?.kind() {
+            tokenizer::Kind::OCURLYBRACKET => Some(offset),
+            _ => None,
+        }
+    }
+}
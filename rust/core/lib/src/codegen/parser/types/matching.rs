@@ -0,0 +1,131 @@
+use crate::codegen::consts;
+use crate::codegen::parser::Token;
+use crate::codegen::parser::tokenizer::{self, TokenStream, get_nth_token};
+use crate::codegen::parser::types::context::ParseContext;
+use crate::codegen::parser::types::util;
+use crate::codegen::types::{Block, Span};
+use crate::types::{error, result};
+use winnow::stream::Stream as _;
+
+impl<'a> Block<'a> {
+    // @match exp { pattern => { content } pattern if guard => { content }, }
+    pub(in crate::codegen::parser::types) fn parse_match<'s>(
+        token: &Token,
+        token_stream: &mut TokenStream,
+        context: &mut ParseContext<'_, 's>,
+    ) -> result::Result<Block<'s>> {
+        let source = context.source();
+        // consume the match token
+        token_stream.next_token();
+
+        // expression is kept as tokens of the block.
+        let mut span = Span::new(source);
+        tokenizer::skip_whitespace(token_stream);
+        for header_token in util::parse_block_header(token_stream).unwrap_or_default() {
+            span.push_token(header_token);
+        }
+        if span.content().trim().is_empty() {
+            return Err(error::CompileError::from_parser(
+                source,
+                Some(*token),
+                &format!(
+                    "Expected expression and '{{' after '{}'",
+                    consts::KEYWORD_MATCH
+                ),
+            ));
+        }
+        // consume '{'
+        token_stream.next_token();
+
+        loop {
+            tokenizer::skip_next_token_if(token_stream, |k| {
+                matches!(
+                    k,
+                    tokenizer::Kind::WHITESPACE | tokenizer::Kind::NEWLINE | tokenizer::Kind::COMMA
+                )
+            });
+            match token_stream.peek_token() {
+                Some(t) if t.kind() == tokenizer::Kind::CCURLYBRACKET => {
+                    token_stream.next_token();
+                    break;
+                }
+                Some(t) if t.kind() != tokenizer::Kind::EOF => {
+                    let pattern = Self::parse_arm_pattern(t, token_stream, context)?;
+                    tokenizer::skip_whitespace_and_newline(token_stream);
+                    match token_stream.peek_token() {
+                        Some(brace) if brace.kind() == tokenizer::Kind::OCURLYBRACKET => {
+                            span.push_block(Self::parse_branch(pattern, token_stream, context)?);
+                        }
+                        _ => {
+                            return Err(error::CompileError::from_parser(
+                                source,
+                                Some(*t),
+                                &format!(
+                                    "Expected '{{' after '{} =>' in '@{}'",
+                                    pattern,
+                                    consts::KEYWORD_MATCH
+                                ),
+                            ));
+                        }
+                    }
+                }
+                _ => {
+                    return Err(error::CompileError::from_parser(
+                        source,
+                        Some(*token),
+                        &format!("Expected '}}' to close '@{}'", consts::KEYWORD_MATCH),
+                    ));
+                }
+            }
+        }
+
+        if !span.has_blocks() {
+            return Err(error::CompileError::from_parser(
+                source,
+                Some(*token),
+                &format!("Expected at least one arm in '@{}'", consts::KEYWORD_MATCH),
+            ));
+        }
+        ParseContext::create_block(context, None, span)
+    }
+
+    // pattern until '=>': `Status::Paid`, `Point { x, .. } if x > 0`
+    fn parse_arm_pattern<'s>(
+        token: &Token,
+        token_stream: &mut TokenStream,
+        context: &ParseContext<'_, 's>,
+    ) -> result::Result<&'s str> {
+        let source = context.source();
+        let start = token.range().start;
+        let mut depth = 0;
+        while let Some(current) = token_stream.peek_token() {
+            match current.kind() {
+                tokenizer::Kind::EOF => break,
+                tokenizer::Kind::OPARENTHESIS | tokenizer::Kind::OCURLYBRACKET => depth += 1,
+                tokenizer::Kind::CPARENTHESIS | tokenizer::Kind::CCURLYBRACKET => depth -= 1,
+                tokenizer::Kind::EQUALS if depth == 0 => {
+                    let is_arrow = get_nth_token(token_stream, 1)
+                        .is_some_and(|t| t.kind() == tokenizer::Kind::GREATTHAN);
+                    if is_arrow {
+                        let pattern = source[start..current.range().start].trim();
+                        // consume '=>'
+                        token_stream.next_token();
+                        token_stream.next_token();
+                        if pattern.is_empty() {
+                            break;
+                        }
+                        return Ok(pattern);
+                    }
+                }
+                _ => {}
+            }
+            token_stream.next_token();
+        }
+
+        Err(error::CompileError::from_parser(
+            source,
+            Some(*token),
+            &format!("Expected 'pattern =>' in '@{}'", consts::KEYWORD_MATCH),
+        ))
+    }
+}
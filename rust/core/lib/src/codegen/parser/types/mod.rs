@@ -1,11 +1,20 @@
+mod awaits;
 mod block;
 mod comment;
+mod condition;
 pub(in crate::codegen) mod context;
 mod directives;
+mod flush;
+mod functions;
+mod loops;
+mod matching;
+mod partial;
+mod raw;
 mod render;
 mod section;
 mod template;
 mod transition;
+mod trim;
 mod util;
 
 #[cfg(test)]
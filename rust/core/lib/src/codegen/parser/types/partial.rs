@@ -0,0 +1,90 @@
+use crate::codegen::consts;
+use crate::codegen::parser::Token;
+use crate::codegen::parser::tokenizer::{self, TokenStream};
+use crate::codegen::parser::types::context::{Kind, ParseContext};
+use crate::codegen::types::{Block, Span};
+use crate::types::{error, result};
+use winnow::stream::Stream as _;
+
+impl<'a> Block<'a> {
+    // @partial(path), @partial(path, data)
+    pub(in crate::codegen::parser::types) fn parse_partial<'s>(
+        token: &Token,
+        token_stream: &mut TokenStream,
+        context: &mut ParseContext<'_, 's>,
+    ) -> result::Result<Block<'s>> {
+        let source = context.source();
+        // consume the partial token
+        token_stream.next_token();
+        match token_stream.peek_token() {
+            Some(t) if t.kind() == tokenizer::Kind::OPARENTHESIS => {
+                token_stream.next_token();
+            }
+            _ => {
+                return Err(error::CompileError::from_parser(
+                    source,
+                    Some(*token),
+                    &format!("Expected '(' after '@{}'", consts::KEYWORD_PARTIAL),
+                ));
+            }
+        }
+
+        // split the arguments by ',' out of nested brackets and string literals.
+        let mut args = vec![Span::new(source)];
+        let mut depth = 0;
+        let mut in_string = false;
+        let mut escaped = false;
+        loop {
+            let arg_token = match token_stream.next_token() {
+                Some(t) if t.kind() != tokenizer::Kind::EOF => *t,
+                _ => {
+                    return Err(error::CompileError::from_parser(
+                        source,
+                        Some(*token),
+                        &format!("Expected ')' to close '@{}('", consts::KEYWORD_PARTIAL),
+                    ));
+                }
+            };
+            match arg_token.kind() {
+                tokenizer::Kind::DQMARK if !escaped => in_string = !in_string,
+                _ if in_string => {}
+                tokenizer::Kind::OPARENTHESIS | tokenizer::Kind::OCURLYBRACKET => depth += 1,
+                tokenizer::Kind::CPARENTHESIS if depth == 0 => break,
+                tokenizer::Kind::CPARENTHESIS | tokenizer::Kind::CCURLYBRACKET => depth -= 1,
+                tokenizer::Kind::COMMA if depth == 0 => {
+                    args.push(Span::new(source));
+                    continue;
+                }
+                _ => {}
+            }
+            escaped = in_string
+                && arg_token.kind() == tokenizer::Kind::EXPRESSION
+                && source[arg_token.range()].ends_with('\\');
+            if let Some(arg) = args.last_mut() {
+                arg.push_token(arg_token);
+            }
+        }
+
+        if args.len() > 2 || args.iter().any(|arg| arg.content().trim().is_empty()) {
+            return Err(error::CompileError::from_parser(
+                source,
+                Some(*token),
+                &format!(
+                    "Expected '@{}(path)' or '@{}(path, data)'",
+                    consts::KEYWORD_PARTIAL,
+                    consts::KEYWORD_PARTIAL
+                ),
+            ));
+        }
+
+        let mut span = Span::new(source);
+        for arg in args {
+            span.push_block(ParseContext::create_block(
+                &context.clone_for(Kind::KINLINEDCODE),
+                None,
+                arg,
+            )?);
+        }
+        ParseContext::create_block(context, None, span)
+    }
+}
@@ -0,0 +1,46 @@
+use crate::codegen::consts;
+use crate::codegen::parser::Token;
+use crate::codegen::parser::tokenizer::{self, TokenStream};
+use crate::codegen::parser::types::context::ParseContext;
+use crate::codegen::types::Block;
+use crate::types::{error, result};
+use winnow::stream::Stream as _;
+
+impl<'a> Block<'a> {
+    // @raw(exp)
+    pub(in crate::codegen::parser::types) fn parse_raw<'s>(
+        token: &Token,
+        token_stream: &mut TokenStream,
+        context: &mut ParseContext<'_, 's>,
+    ) -> result::Result<Block<'s>> {
+        let source = context.source();
+        // consume the raw token
+        token_stream.next_token();
+        match token_stream.peek_token() {
+            Some(t) if t.kind() == tokenizer::Kind::OPARENTHESIS => {}
+            _ => {
+                return Err(error::CompileError::from_parser(
+                    source,
+                    Some(*token),
+                    &format!("Expected '(' after '@{}'", consts::KEYWORD_RAW),
+                ));
+            }
+        }
+
+        let block = Self::parse_block_within_kinds(
+            tokenizer::Kind::OPARENTHESIS,
+            tokenizer::Kind::CPARENTHESIS,
+            token_stream,
+            context,
+        )?;
+        if !matches!(block, Block::KRAW(_)) || block.content().trim().is_empty() {
+            return Err(error::CompileError::from_parser(
+                source,
+                Some(*token),
+                &format!("Expected expression in '@{}()'", consts::KEYWORD_RAW),
+            ));
+        }
+
+        Ok(block)
+    }
+}
@@ -1,9 +1,11 @@
 use crate::codegen::consts;
-use crate::codegen::parser::tokenizer::{self, TokenStream};
+use crate::codegen::parser::Token;
+use crate::codegen::parser::tokenizer::{self, TokenStream, get_nth_token};
 use crate::codegen::parser::types::context::Kind;
 use crate::codegen::parser::types::context::ParseContext;
-use crate::codegen::parser::types::util::get_token_if;
+use crate::codegen::parser::types::util;
 use crate::codegen::types::Block;
+use crate::codegen::types::SectionMode;
 use crate::codegen::types::Span;
 use crate::types::{error, result};
 use winnow::stream::Stream as _;
@@ -37,104 +39,123 @@ impl<'a> Block<'a> {
             }
         };
 
-        // render, render(exp), render(exp, true|false)
+        // render, render(name), render(name, required), with an optional fallback `{ ... }` after named ones.
         // consume the directive token
         token_stream.next_token();
         tokenizer::skip_whitespace(token_stream);
 
-        // TODO(future): p1 is block, p2 is also block, see previous commit.
         // without ()
-        if let None = get_token_if(token_stream, |k| k == tokenizer::Kind::OPARENTHESIS) {
+        if get_nth_token(token_stream, 0).map(|t| t.kind()) != Some(tokenizer::Kind::OPARENTHESIS) {
             // empty render
             let block = ParseContext::create_block(&context, None, Span::new(source))?;
             return Ok(block);
         }
-        tokenizer::skip_whitespace(token_stream);
 
-        let token = get_token_if(token_stream, |k| {
-            k == tokenizer::Kind::EXPRESSION || k == tokenizer::Kind::CPARENTHESIS
-        })
-        .ok_or_else(|| {
+        // arguments are rust expressions, validated at codegen.
+        let arguments = Self::parse_render_arguments(token_stream, source).ok_or_else(|| {
             error::CompileError::from_parser(
                 source,
                 Some(*start_token),
-                &format!("Expected '(' after '{}'", consts::KEYWORD_RENDER,),
+                &format!("Expected ')' to close '@{}('", consts::KEYWORD_RENDER),
             )
         })?;
-
-        if token.kind() == tokenizer::Kind::CPARENTHESIS {
-            // no params @render()
-            // empty render
-            let block = ParseContext::create_block(&context, None, Span::new(source))?;
+        let mut root_span = Span::new(source);
+        // no params @render()
+        if arguments.len() == 1 && arguments[0].is_empty() {
+            let block = ParseContext::create_block(&context, None, root_span)?;
             return Ok(block);
-        } else {
-            let mut root_span = Span::new(source);
-
-            // has params
-            let mut left_span = Span::new(source);
-            left_span.push_token(*token);
-            let left_block = ParseContext::create_block(&context, None, left_span)?;
-            root_span.push_block(left_block);
-
-            tokenizer::skip_whitespace(token_stream);
-            tokenizer::skip_next_token_if(token_stream, |k| k == tokenizer::Kind::COMMA);
-            tokenizer::skip_whitespace(token_stream);
-            // second.
-            let token = get_token_if(token_stream, |k| {
-                k == tokenizer::Kind::EXPRESSION || k == tokenizer::Kind::CPARENTHESIS
-            })
-            .ok_or_else(|| {
-                error::CompileError::from_parser(
+        }
+        for (params, tokens) in arguments.into_iter().enumerate() {
+            if tokens.is_empty() {
+                return Err(error::CompileError::from_parser(
                     source,
                     Some(*start_token),
-                    &format!("Expected '(' after '{}'", consts::KEYWORD_RENDER,),
+                    &format!(
+                        "Expected expression as parameter {} for '{}'",
+                        params + 1,
+                        consts::KEYWORD_RENDER
+                    ),
+                ));
+            }
+            if params == 2 {
+                return Err(error::CompileError::from_parser(
+                    source,
+                    Some(tokens[0]),
+                    &format!(
+                        "Expected at most 2 parameters for '{}'",
+                        consts::KEYWORD_RENDER
+                    ),
+                ));
+            }
+
+            let mut span = Span::new(source);
+            for token in tokens {
+                span.push_token(token);
+            }
+            // name, required.
+            let kind = match params {
+                0 => context.block_kind(),
+                _ => Kind::KCONTENT,
+            };
+            root_span.push_block(ParseContext::create_block(
+                &context.clone_for(kind),
+                None,
+                span,
+            )?);
+        }
+        Self::parse_render_fallback(&mut root_span, token_stream, context)?;
+        ParseContext::create_block(&context, None, root_span)
+    }
+
+    // tokens of the arguments split like a call, trimmed, the call is consumed.
+    fn parse_render_arguments(
+        token_stream: &mut TokenStream,
+        source: &str,
+    ) -> Option<Vec<Vec<Token>>> {
+        let separators = Self::parse_call_separators(token_stream, source)?;
+        let mut arguments = vec![];
+        // after '('.
+        let mut start = 1;
+        for separator in separators {
+            let mut tokens = (start..separator)
+                .filter_map(|offset| get_nth_token(token_stream, offset).copied())
+                .collect::<Vec<_>>();
+            let is_space = |t: &Token| {
+                matches!(
+                    t.kind(),
+                    tokenizer::Kind::WHITESPACE | tokenizer::Kind::NEWLINE
                 )
-            })?;
+            };
+            while tokens.last().is_some_and(is_space) {
+                tokens.pop();
+            }
+            let leading = tokens.iter().take_while(|t| is_space(t)).count();
+            arguments.push(tokens.split_off(leading));
+            start = separator + 1;
+        }
+        util::advance(token_stream, start);
+        Some(arguments)
+    }
 
-            if token.kind() == tokenizer::Kind::CPARENTHESIS {
-                // only one param
-                let block = ParseContext::create_block(&context, None, root_span)?;
-                return Ok(block);
-            } else {
-                // validate, must be true or false
-                let text = &source[token.range()];
-                let is_bool = text.parse::<bool>().is_ok();
-                if !is_bool {
-                    return Err(error::CompileError::from_parser(
-                        source,
-                        Some(*token),
-                        &format!(
-                            "Expected boolean literal (true or false) as second parameter for '{}'",
-                            consts::KEYWORD_RENDER,
-                        ),
-                    ));
+    // `{ ... }` rendered when the section is missing, kept as the last block of the render span.
+    fn parse_render_fallback<'s>(
+        root_span: &mut Span<'s>,
+        token_stream: &mut TokenStream,
+        context: &mut ParseContext<'_, 's>,
+    ) -> result::Result<()> {
+        let mut offset = 0;
+        while let Some(token) = get_nth_token(token_stream, offset) {
+            match token.kind() {
+                tokenizer::Kind::WHITESPACE => offset += 1,
+                tokenizer::Kind::OCURLYBRACKET => {
+                    tokenizer::skip_whitespace(token_stream);
+                    let span = Self::parse_section_body(token_stream, context)?;
+                    root_span.push_block(Block::new_section("", SectionMode::KDEFAULT, span));
+                    break;
                 }
-
-                // has two params
-                let mut right_span = Span::new(source);
-                right_span.push_token(*token);
-                let right_block = ParseContext::create_block(
-                    &context.clone_for(Kind::KCONTENT),
-                    None,
-                    right_span,
-                )?;
-                root_span.push_block(right_block);
-                tokenizer::skip_whitespace(token_stream);
-                // must be )
-                get_token_if(token_stream, |k| k == tokenizer::Kind::CPARENTHESIS).ok_or_else(
-                    || {
-                        error::CompileError::from_parser(
-                            source,
-                            Some(*start_token),
-                            &format!(
-                                "Expected ')' after second parameter for '{}'",
-                                consts::KEYWORD_RENDER,
-                            ),
-                        )
-                    },
-                )?;
-                Ok(ParseContext::create_block(&context, None, root_span)?)
+                _ => break,
             }
         }
+        Ok(())
     }
 }
@@ -2,7 +2,7 @@ use crate::codegen::consts;
 use crate::codegen::parser::Token;
 use crate::codegen::parser::tokenizer::{self, TokenStream};
 use crate::codegen::parser::types::context::{Kind, ParseContext};
-use crate::codegen::types::{Block, Span};
+use crate::codegen::types::{Block, SectionMode, Span};
 use crate::types::{error, result};
 use winnow::stream::Stream as _;
 
@@ -43,41 +43,43 @@ impl<'a> Block<'a> {
 
                     // whitespace after section name
                     tokenizer::skip_whitespace(token_stream);
+
+                    // optional merge mode like `@section scripts append {`.
+                    let mode = match token_stream.peek_token() {
+                        Some(mode_token) if mode_token.kind() == tokenizer::Kind::EXPRESSION => {
+                            let mode = match &source[mode_token.range()] {
+                                consts::SECTION_MODE_APPEND => SectionMode::KAPPEND,
+                                consts::SECTION_MODE_PREPEND => SectionMode::KPREPEND,
+                                consts::SECTION_MODE_REPLACE => SectionMode::KREPLACE,
+                                other => {
+                                    return Err(error::CompileError::from_parser(
+                                        source,
+                                        Some(*mode_token),
+                                        &format!(
+                                            "Unknown mode '{}' for '@{} {}', expected '{}', '{}' or '{}'",
+                                            other,
+                                            consts::KEYWORD_SECTION,
+                                            name,
+                                            consts::SECTION_MODE_APPEND,
+                                            consts::SECTION_MODE_PREPEND,
+                                            consts::SECTION_MODE_REPLACE
+                                        ),
+                                    ));
+                                }
+                            };
+                            // consume the mode token.
+                            token_stream.next_token();
+                            tokenizer::skip_whitespace(token_stream);
+                            mode
+                        }
+                        _ => SectionMode::KDEFAULT,
+                    };
                     match token_stream.peek_token() {
                         Some(brace_token)
                             if brace_token.kind() == tokenizer::Kind::OCURLYBRACKET =>
                         {
-                            // Note: section is content.
-                            let block = Self::parse_block_within_kinds(
-                                tokenizer::Kind::OCURLYBRACKET,
-                                tokenizer::Kind::CCURLYBRACKET,
-                                token_stream,
-                                &mut context.clone_for(Kind::KSECTION),
-                            )?;
-                            let root_span = block.span();
-                            let section_span = match root_span.is_simple() {
-                                true => root_span.clone(),
-                                false => {
-                                    // unpack.
-                                    let mut span = Span::new(source);
-                                    for block in root_span.blocks() {
-                                        // revert back
-                                        if matches!(block, Block::KSECTION(_, _)) {
-                                            span.push_block(block.to_content());
-                                        } else {
-                                            span.push_block(block.clone());
-                                        }
-                                    }
-                                    span
-                                }
-                            };
-
-                            let block = ParseContext::create_block(
-                                &context.clone_for(Kind::KSECTION),
-                                Some(name.to_string()),
-                                section_span,
-                            )?;
-                            Ok(block)
+                            let section_span = Self::parse_section_body(token_stream, context)?;
+                            Ok(Block::new_section(name, mode, section_span))
                         }
                         _ => Err(error::CompileError::from_parser(
                             source,
@@ -102,4 +104,43 @@ impl<'a> Block<'a> {
             },
         }
     }
+
+    // `{ ... }` of a section, also the fallback of `@render(name) { ... }`.
+    pub(in crate::codegen::parser::types) fn parse_section_body<'s>(
+        token_stream: &mut TokenStream,
+        context: &mut ParseContext<'_, 's>,
+    ) -> result::Result<Span<'s>> {
+        let source = context.source();
+        // Note: section is content.
+        let block = Self::parse_block_within_kinds(
+            tokenizer::Kind::OCURLYBRACKET,
+            tokenizer::Kind::CCURLYBRACKET,
+            token_stream,
+            &mut context.clone_for(Kind::KSECTION),
+        )?;
+        let root_span = block.span();
+        let section_span = match root_span.is_simple() {
+            // single block like @if is returned as it is.
+            _ if !matches!(block, Block::KSECTION(_, _, _)) => {
+                let mut span = Span::new(source);
+                span.push_block(block.clone());
+                span
+            }
+            true => root_span.clone(),
+            false => {
+                // unpack.
+                let mut span = Span::new(source);
+                for block in root_span.blocks() {
+                    // revert back
+                    if matches!(block, Block::KSECTION(_, _, _)) {
+                        span.push_block(block.to_content());
+                    } else {
+                        span.push_block(block.clone());
+                    }
+                }
+                span
+            }
+        };
+        Ok(section_span)
+    }
 }
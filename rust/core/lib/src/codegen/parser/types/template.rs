@@ -23,7 +23,10 @@ impl<'a> Template<'a> {
             context::ParseContext::new(context::Kind::KROOT, kind, compiler_options, source);
         // skip leading whitespace and newlines.
         tokenizer::skip_whitespace_and_newline(&mut token_stream);
-        let block = Block::parse(&mut token_stream, &mut context)?;
+        let mut block = Block::parse(&mut token_stream, &mut context)?;
+        if compiler_options.need_trim_blocks(kind) {
+            block.trim_blocks(source);
+        }
         let template = Template::new(namespace, block, kind);
         Ok(template)
     }
@@ -0,0 +1,38 @@
+#![cfg(test)]
+use crate::codegen::CompilerOptions;
+use crate::codegen::types::Block;
+use crate::codegen::types::Template;
+use crate::types::result;
+use crate::types::template::Kind;
+
+#[test]
+fn template_from_await() -> result::Result<()> {
+    let content = "<h1>@await repo.title()</h1><p>@await (repo.count() + 1)</p>";
+    let options = CompilerOptions::default();
+    let template = Template::from(&content, None, Kind::KHTML, &options)?;
+    let root_span = template.block().span();
+    let block = &root_span.blocks()[1];
+    assert!(matches!(block, Block::KAWAIT(_)));
+    assert_eq!(block.content(), "repo.title()");
+    let block = &root_span.blocks()[3];
+    assert!(matches!(block, Block::KAWAIT(_)));
+    assert_eq!(block.content(), "repo.count() + 1");
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn template_from_await_without_expression() {
+    let content = "<p>@await</p>";
+    let options = CompilerOptions::default();
+    Template::from(&content, None, Kind::KHTML, &options).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn template_from_await_in_code() {
+    let content = "@{ @await load() }";
+    let options = CompilerOptions::default();
+    Template::from(&content, None, Kind::KHTML, &options).unwrap();
+}
@@ -0,0 +1,100 @@
+#![cfg(test)]
+use crate::codegen::CompilerOptions;
+use crate::codegen::types::Block;
+use crate::codegen::types::Template;
+use crate::types::result;
+use crate::types::template::Kind;
+
+#[test]
+fn template_from_if() -> result::Result<()> {
+    let content = "<div>@if age > (limit + 1) {<b>@name</b>}</div>";
+    let options = CompilerOptions::default();
+    let template = Template::from(&content, None, Kind::KHTML, &options)?;
+    let root_span = template.block().span();
+    assert_eq!(root_span.blocks().len(), 3);
+    let block = &root_span.blocks()[1];
+    assert!(matches!(block, Block::KIF(_)));
+    let branches = block.span().blocks();
+    assert_eq!(branches.len(), 1);
+    match &branches[0] {
+        Block::KBRANCH(condition, span) => {
+            assert_eq!(condition, "age > (limit + 1)");
+            assert!(matches!(span.blocks()[0], Block::KCONTENT(_)));
+            assert_eq!(span.blocks()[0].span().blocks().len(), 3);
+        }
+        _ => panic!("expected branch"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn template_from_if_else_if_else() -> result::Result<()> {
+    let content = "@if a {A}\nelse if b { B } else {C}\n<p>next</p>";
+    let options = CompilerOptions::default();
+    let template = Template::from(&content, None, Kind::KHTML, &options)?;
+    let root_span = template.block().span();
+    assert_eq!(root_span.blocks().len(), 2);
+    let branches = root_span.blocks()[0].span().blocks();
+    let conditions = branches
+        .iter()
+        .map(|b| match b {
+            Block::KBRANCH(condition, _) => condition.as_str(),
+            _ => panic!("expected branch"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(conditions, vec!["a", "b", ""]);
+    assert_eq!(branches[1].span().blocks()[0].content(), " B ");
+    assert_eq!(root_span.blocks()[1].content(), "<p>next</p>");
+
+    Ok(())
+}
+
+#[test]
+fn template_from_if_followed_by_else_text() -> result::Result<()> {
+    let content = "@if a {A} else text";
+    let options = CompilerOptions::default();
+    let template = Template::from(&content, None, Kind::KHTML, &options)?;
+    let root_span = template.block().span();
+    assert_eq!(root_span.blocks().len(), 2);
+    assert_eq!(root_span.blocks()[0].span().blocks().len(), 1);
+    assert_eq!(root_span.blocks()[1].content(), " else text");
+
+    Ok(())
+}
+
+#[test]
+fn template_from_if_in_section() -> result::Result<()> {
+    let content = "@section menu {@if a {A}}";
+    let options = CompilerOptions::default();
+    let template = Template::from(&content, None, Kind::KHTML, &options)?;
+    let section = &template.block().span().blocks()[0];
+    assert!(matches!(section, Block::KSECTION(_, _, _)));
+    assert!(matches!(section.span().blocks()[0], Block::KIF(_)));
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn template_from_if_without_condition() {
+    let content = "@if {A}";
+    let options = CompilerOptions::default();
+    Template::from(&content, None, Kind::KHTML, &options).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn template_from_if_without_block() {
+    let content = "@if a";
+    let options = CompilerOptions::default();
+    Template::from(&content, None, Kind::KHTML, &options).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn template_from_if_in_code() {
+    let content = "@{ @if a {A} }";
+    let options = CompilerOptions::default();
+    Template::from(&content, None, Kind::KHTML, &options).unwrap();
+}
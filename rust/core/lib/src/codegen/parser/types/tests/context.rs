@@ -70,6 +70,46 @@ parse_context_test_case!(
     true
 );
 
+parse_context_test_case!(
+    parse_context_from_root_inject,
+    &format!("@{} urls: Urls", consts::DIRECTIVE_KEYWORD_INJECT),
+    true,
+    Kind::KROOT,
+    true
+);
+
+parse_context_test_case!(
+    parse_context_from_content_inject,
+    &format!("@{} urls: Urls", consts::DIRECTIVE_KEYWORD_INJECT),
+    Kind::KCONTENT
+);
+
+parse_context_test_case!(
+    parse_context_from_root_model,
+    &format!("@{} String", consts::DIRECTIVE_KEYWORD_MODEL),
+    true,
+    Kind::KROOT,
+    true
+);
+
+// the model value out of the root, like `<p>@model is ready</p>` in a block.
+parse_context_test_case!(
+    parse_context_from_content_model,
+    &format!("@{} is ready", consts::DIRECTIVE_KEYWORD_MODEL),
+    true,
+    Kind::KCONTENT,
+    true
+);
+
+// the model value, not the directive.
+parse_context_test_case!(
+    parse_context_from_content_model_value,
+    &format!("@{}</p>", consts::DIRECTIVE_KEYWORD_MODEL),
+    true,
+    Kind::KCONTENT,
+    true
+);
+
 parse_context_test_case!(
     parse_context_from_content_section,
     &format!("@{}", consts::KEYWORD_SECTION),
@@ -92,6 +92,32 @@ directive_test_case!(
     |b| matches!(b, &Block::KLAYOUT(_))
 );
 
+// inject.
+directive_test_case!(
+    parse_illegal_directive_inject,
+    consts::DIRECTIVE_KEYWORD_INJECT
+);
+
+directive_test_case!(
+    parse_directive_inject,
+    "urls: crate::UrlBuilder",
+    consts::DIRECTIVE_KEYWORD_INJECT,
+    |b| matches!(b, &Block::KINJECT(_))
+);
+
+// model.
+directive_test_case!(
+    parse_illegal_directive_model,
+    consts::DIRECTIVE_KEYWORD_MODEL
+);
+
+directive_test_case!(
+    parse_directive_model,
+    "crate::models::Product",
+    consts::DIRECTIVE_KEYWORD_MODEL,
+    |b| matches!(b, &Block::KMODEL(_))
+);
+
 // use.
 #[test]
 #[should_panic]
@@ -116,3 +142,41 @@ directive_test_case!(
     consts::DIRECTIVE_KEYWORD_USE,
     |b| matches!(b, &Block::KUSE(_))
 );
+
+#[test]
+fn parse_directive_layout_expression() -> result::Result<()> {
+    let statements = [
+        r#"layout(Some("print"))"#,
+        r#"layout(Some("print"));"#,
+        "layout(Some(\"print\"))\n",
+        r#"layout(if a(b) { Some(")") } else { None })"#,
+    ];
+    for statement in statements {
+        let tokenizer = Tokenizer::new(statement);
+        let tokens = tokenizer.into_vec();
+        let mut token_stream = TokenSlice::new(&tokens);
+        let block = Block::parse_directive(
+            statement,
+            consts::DIRECTIVE_KEYWORD_LAYOUT,
+            &mut token_stream,
+        )?;
+        assert!(matches!(block, Block::KLAYOUT(_)));
+        assert!(block.content().starts_with('('));
+        assert!(block.content().ends_with(')'));
+    }
+
+    let statement = "layout(Some(\"print\")";
+    let tokenizer = Tokenizer::new(statement);
+    let tokens = tokenizer.into_vec();
+    let mut token_stream = TokenSlice::new(&tokens);
+    assert!(
+        Block::parse_directive(
+            statement,
+            consts::DIRECTIVE_KEYWORD_LAYOUT,
+            &mut token_stream
+        )
+        .is_err()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+#![cfg(test)]
+use crate::codegen::CompilerOptions;
+use crate::codegen::types::Block;
+use crate::codegen::types::Template;
+use crate::types::result;
+use crate::types::template::Kind;
+
+#[test]
+fn template_from_flush() -> result::Result<()> {
+    let content = "<head></head>@flush\n<body>@for i in 0..2 {<p>@i</p>@flush;}</body>";
+    let options = CompilerOptions::default();
+    let template = Template::from(&content, None, Kind::KHTML, &options)?;
+    let root_span = template.block().span();
+    let block = &root_span.blocks()[1];
+    assert!(matches!(block, Block::KFLUSH(_)));
+    assert_eq!(block.content(), "flush");
+    assert!(matches!(root_span.blocks()[3], Block::KFOR(_)));
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn template_from_flush_in_code() {
+    let content = "@{ @flush }";
+    let options = CompilerOptions::default();
+    Template::from(&content, None, Kind::KHTML, &options).unwrap();
+}
@@ -0,0 +1,49 @@
+#![cfg(test)]
+use crate::codegen::CompilerOptions;
+use crate::codegen::types::Block;
+use crate::codegen::types::Template;
+use crate::types::result;
+use crate::types::template::Kind;
+
+#[test]
+fn template_from_functions() -> result::Result<()> {
+    let content = r#"@functions {
+    fn greet(name: &str) -> String {
+        format!("hello {}", name)
+    }
+}
+<p>@greet("sbolt")</p>"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&content, None, Kind::KHTML, &options)?;
+    let root_span = template.block().span();
+    let block = &root_span.blocks()[0];
+    assert!(matches!(block, Block::KFUNCTIONS(_)));
+    assert!(block.content().contains("fn greet(name: &str) -> String"));
+    assert!(!root_span.blocks()[1].content().contains("fn greet"));
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn template_from_functions_with_content() {
+    let content = "@functions { fn a() { @{<p>a</p>} } }";
+    let options = CompilerOptions::default();
+    Template::from(&content, None, Kind::KHTML, &options).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn template_from_functions_in_section() {
+    let content = "@section a { @functions { fn a() {} } }";
+    let options = CompilerOptions::default();
+    Template::from(&content, None, Kind::KHTML, &options).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn template_from_functions_without_brace() {
+    let content = "@functions fn a() {}";
+    let options = CompilerOptions::default();
+    Template::from(&content, None, Kind::KHTML, &options).unwrap();
+}
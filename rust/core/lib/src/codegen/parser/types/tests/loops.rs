@@ -0,0 +1,75 @@
+#![cfg(test)]
+use crate::codegen::CompilerOptions;
+use crate::codegen::types::Block;
+use crate::codegen::types::Template;
+use crate::types::result;
+use crate::types::template::Kind;
+
+#[test]
+fn template_from_for() -> result::Result<()> {
+    let content = "<ul>@for (i, item) in items.iter().enumerate() {<li>@item</li>}</ul>";
+    let options = CompilerOptions::default();
+    let template = Template::from(&content, None, Kind::KHTML, &options)?;
+    let root_span = template.block().span();
+    assert_eq!(root_span.blocks().len(), 3);
+    let block = &root_span.blocks()[1];
+    assert!(matches!(block, Block::KFOR(_)));
+    let branches = block.span().blocks();
+    assert_eq!(branches.len(), 1);
+    match &branches[0] {
+        Block::KBRANCH(header, span) => {
+            assert_eq!(header, "(i, item) in items.iter().enumerate()");
+            assert_eq!(span.blocks()[0].span().blocks().len(), 3);
+        }
+        _ => panic!("expected branch"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn template_from_for_with_empty() -> result::Result<()> {
+    let content = "@for item in items {<li>@item</li>}\n@empty {<li>none</li>}\n<p>next</p>";
+    let options = CompilerOptions::default();
+    let template = Template::from(&content, None, Kind::KHTML, &options)?;
+    let root_span = template.block().span();
+    assert_eq!(root_span.blocks().len(), 2);
+    let branches = root_span.blocks()[0].span().blocks();
+    assert_eq!(branches.len(), 2);
+    assert!(matches!(&branches[1], Block::KBRANCH(header, _) if header.is_empty()));
+    assert_eq!(branches[1].span().blocks()[0].content(), "<li>none</li>");
+    assert_eq!(root_span.blocks()[1].content(), "<p>next</p>");
+
+    Ok(())
+}
+
+#[test]
+fn template_from_while() -> result::Result<()> {
+    let content = "@while let Some(item) = stack.pop() {<li>@item</li>}";
+    let options = CompilerOptions::default();
+    let template = Template::from(&content, None, Kind::KHTML, &options)?;
+    let block = &template.block().span().blocks()[0];
+    assert!(matches!(block, Block::KWHILE(_)));
+    assert!(matches!(
+        &block.span().blocks()[0],
+        Block::KBRANCH(condition, _) if condition == "let Some(item) = stack.pop()"
+    ));
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn template_from_for_without_in() {
+    let content = "@for item {<li>@item</li>}";
+    let options = CompilerOptions::default();
+    Template::from(&content, None, Kind::KHTML, &options).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn template_from_for_in_code() {
+    let content = "@{ @for item in items {<li>@item</li>} }";
+    let options = CompilerOptions::default();
+    Template::from(&content, None, Kind::KHTML, &options).unwrap();
+}
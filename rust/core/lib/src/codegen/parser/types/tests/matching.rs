@@ -0,0 +1,78 @@
+#![cfg(test)]
+use crate::codegen::CompilerOptions;
+use crate::codegen::types::Block;
+use crate::codegen::types::Template;
+use crate::types::result;
+use crate::types::template::Kind;
+
+#[test]
+fn template_from_match() -> result::Result<()> {
+    let content = r#"<p>@match order.status() {
+    Status::Paid => {<b>paid</b>}
+    Status::Shipped { at, .. } if at.is_some() => { shipped },
+    _ => {@other}
+}</p>"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&content, None, Kind::KHTML, &options)?;
+    let root_span = template.block().span();
+    assert_eq!(root_span.blocks().len(), 3);
+    let block = &root_span.blocks()[1];
+    assert!(matches!(block, Block::KMATCH(_)));
+    assert_eq!(block.content().trim(), "order.status()");
+    let patterns = block
+        .span()
+        .blocks()
+        .iter()
+        .map(|b| match b {
+            Block::KBRANCH(pattern, _) => pattern.as_str(),
+            _ => panic!("expected arm"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        patterns,
+        vec![
+            "Status::Paid",
+            "Status::Shipped { at, .. } if at.is_some()",
+            "_"
+        ]
+    );
+    assert!(matches!(
+        block.span().blocks()[2].span().blocks()[0],
+        Block::KINLINEDCODE(_)
+    ));
+    assert_eq!(root_span.blocks()[2].content(), "</p>");
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn template_from_match_without_arm() {
+    let content = "@match status {}";
+    let options = CompilerOptions::default();
+    Template::from(&content, None, Kind::KHTML, &options).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn template_from_match_without_arrow() {
+    let content = "@match status { Status::Paid {paid} }";
+    let options = CompilerOptions::default();
+    Template::from(&content, None, Kind::KHTML, &options).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn template_from_match_with_expression_arm() {
+    let content = "@match status { Status::Paid => \"paid\" }";
+    let options = CompilerOptions::default();
+    Template::from(&content, None, Kind::KHTML, &options).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn template_from_match_unclosed() {
+    let content = "@match status { Status::Paid => {paid}";
+    let options = CompilerOptions::default();
+    Template::from(&content, None, Kind::KHTML, &options).unwrap();
+}
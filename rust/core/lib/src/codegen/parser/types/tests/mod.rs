@@ -1,10 +1,19 @@
 #![cfg(test)]
+mod awaits;
 mod block;
 mod comments;
+mod condition;
 mod context;
 mod directives;
+mod flush;
+mod functions;
+mod loops;
+mod matching;
+mod partial;
+mod raw;
 mod render;
 mod section;
 mod template;
 mod transition;
+mod trim;
 mod util;
@@ -0,0 +1,79 @@
+#![cfg(test)]
+use crate::codegen::CompilerOptions;
+use crate::codegen::types::Block;
+use crate::codegen::types::Template;
+use crate::types::result;
+use crate::types::template::Kind;
+
+#[test]
+fn template_from_partial() -> result::Result<()> {
+    let content = r#"<ul>@partial("shared/card")</ul>"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&content, None, Kind::KHTML, &options)?;
+    let root_span = template.block().span();
+    assert_eq!(root_span.blocks().len(), 3);
+    let block = &root_span.blocks()[1];
+    assert!(matches!(block, Block::KPARTIAL(_)));
+    assert_eq!(block.span().blocks().len(), 1);
+    assert_eq!(block.span().blocks()[0].content(), r#""shared/card""#);
+
+    Ok(())
+}
+
+#[test]
+fn template_from_partial_with_data() -> result::Result<()> {
+    let content = r#"@partial("card, (a)", (row.id, format!("{}, {}", a, b)))"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&content, None, Kind::KHTML, &options)?;
+    let block = &template.block().span().blocks()[0];
+    assert!(matches!(block, Block::KPARTIAL(_)));
+    let args = block.span().blocks();
+    assert_eq!(args.len(), 2);
+    assert_eq!(args[0].content(), r#""card, (a)""#);
+    assert_eq!(
+        args[1].content().trim(),
+        r#"(row.id, format!("{}, {}", a, b))"#
+    );
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn template_from_partial_without_parenthesis() {
+    let content = r#"<div>@partial "card"</div>"#;
+    let options = CompilerOptions::default();
+    Template::from(&content, None, Kind::KHTML, &options).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn template_from_partial_without_path() {
+    let content = "<div>@partial()</div>";
+    let options = CompilerOptions::default();
+    Template::from(&content, None, Kind::KHTML, &options).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn template_from_partial_with_too_many_args() {
+    let content = r#"<div>@partial("card", a, b)</div>"#;
+    let options = CompilerOptions::default();
+    Template::from(&content, None, Kind::KHTML, &options).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn template_from_partial_unclosed() {
+    let content = r#"<div>@partial("card"</div>"#;
+    let options = CompilerOptions::default();
+    Template::from(&content, None, Kind::KHTML, &options).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn template_from_partial_in_code() {
+    let content = r#"@{ @partial("card") }"#;
+    let options = CompilerOptions::default();
+    Template::from(&content, None, Kind::KHTML, &options).unwrap();
+}
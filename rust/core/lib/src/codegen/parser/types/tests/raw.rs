@@ -0,0 +1,52 @@
+#![cfg(test)]
+use crate::codegen::CompilerOptions;
+use crate::codegen::types::Block;
+use crate::codegen::types::Template;
+use crate::types::result;
+use crate::types::template::Kind;
+
+#[test]
+fn template_from_raw() -> result::Result<()> {
+    let content = "<div>@raw(widget.html())</div>";
+    let options = CompilerOptions::default();
+    let template = Template::from(&content, None, Kind::KHTML, &options)?;
+    let root_span = template.block().span();
+    assert_eq!(root_span.blocks().len(), 3);
+    let block = &root_span.blocks()[1];
+    assert!(matches!(block, Block::KRAW(_)));
+    assert_eq!(block.content(), "widget.html()");
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn template_from_raw_without_parenthesis() {
+    let content = "<div>@raw widget</div>";
+    let options = CompilerOptions::default();
+    Template::from(&content, None, Kind::KHTML, &options).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn template_from_raw_without_expression() {
+    let content = "<div>@raw()</div>";
+    let options = CompilerOptions::default();
+    Template::from(&content, None, Kind::KHTML, &options).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn template_from_raw_with_transition() {
+    let content = "<div>@raw(@widget)</div>";
+    let options = CompilerOptions::default();
+    Template::from(&content, None, Kind::KHTML, &options).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn template_from_raw_in_code() {
+    let content = "@{ @raw(widget) }";
+    let options = CompilerOptions::default();
+    Template::from(&content, None, Kind::KHTML, &options).unwrap();
+}
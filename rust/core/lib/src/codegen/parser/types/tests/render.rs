@@ -78,9 +78,8 @@ fn block_parse_render_without_closing_2() {
 }
 
 #[test]
-#[should_panic]
-fn block_parse_render_second_param_is_not_bool() {
-    let source = r#"@render(test, hello)"#;
+fn block_parse_render_expression_params() -> result::Result<()> {
+    let source = r#"@render( names[0].as_str() , is_admin(&user, "a, b)") )"#;
     let tokenizer = Tokenizer::new(source);
     let tokens = tokenizer.into_vec();
     let mut token_stream = TokenSlice::new(&tokens);
@@ -92,7 +91,55 @@ fn block_parse_render_second_param_is_not_bool() {
         &options,
         source,
     );
-    Block::parse_render(&mut token_stream, &mut context).unwrap();
+    let block = Block::parse_render(&mut token_stream, &mut context)?;
+    let root_span = block.span();
+    assert_eq!(root_span.blocks().len(), 2);
+    assert_eq!(root_span.blocks()[0].content(), "names[0].as_str()");
+    assert_eq!(
+        root_span.blocks()[1].content(),
+        r#"is_admin(&user, "a, b)")"#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn block_parse_render_literal_params() -> result::Result<()> {
+    let cases: [(&str, &[&str]); 4] = [
+        (r#"@render("\\", flag)"#, &[r#""\\""#, "flag"]),
+        (r#"@render(name, c == ',')"#, &["name", "c == ','"]),
+        (
+            r#"@render(name(')'), c == '\'')"#,
+            &["name(')')", r"c == '\''"],
+        ),
+        (
+            r#"@render(get::<'static>(a, b))"#,
+            &["get::<'static>(a, b)"],
+        ),
+    ];
+    for (source, expected) in cases {
+        let tokenizer = Tokenizer::new(source);
+        let tokens = tokenizer.into_vec();
+        let mut token_stream = TokenSlice::new(&tokens);
+        token_stream.next_token();
+        let options = CompilerOptions::default();
+        let mut context = ParseContext::new(
+            context::Kind::KRENDER,
+            template::Kind::KHTML,
+            &options,
+            source,
+        );
+        let block = Block::parse_render(&mut token_stream, &mut context)?;
+        let params = block
+            .span()
+            .blocks()
+            .iter()
+            .map(|b| b.content())
+            .collect::<Vec<_>>();
+        assert_eq!(params, expected, "{source}");
+    }
+
+    Ok(())
 }
 
 #[test]
@@ -243,3 +290,49 @@ fn block_parse_render_more_than_2_params() {
     );
     Block::parse_render(&mut token_stream, &mut context).unwrap();
 }
+
+#[test]
+fn block_parse_render_fallback() -> result::Result<()> {
+    let source = r#"@render(test, false) { <b>default</b> } after"#;
+    let tokenizer = Tokenizer::new(source);
+    let tokens = tokenizer.into_vec();
+    let mut token_stream = TokenSlice::new(&tokens);
+    token_stream.next_token().unwrap();
+    let options = CompilerOptions::default();
+    let mut context = ParseContext::new(
+        context::Kind::KRENDER,
+        template::Kind::KHTML,
+        &options,
+        source,
+    );
+    let block = Block::parse_render(&mut token_stream, &mut context)?;
+    let root_span = match block {
+        Block::KRENDER(span) => span,
+        _ => panic!("Expected KRENDER block"),
+    };
+    assert_eq!(root_span.blocks().len(), 3);
+    assert_eq!(root_span.blocks()[0].content(), "test");
+    assert!(matches!(root_span.blocks()[2], Block::KSECTION(_, _, _)));
+    assert_eq!(root_span.blocks()[2].content().trim(), "<b>default</b>");
+
+    // content after the call is not taken as fallback.
+    let source = r#"@render(test) after"#;
+    let tokenizer = Tokenizer::new(source);
+    let tokens = tokenizer.into_vec();
+    let mut token_stream = TokenSlice::new(&tokens);
+    token_stream.next_token().unwrap();
+    let mut context = ParseContext::new(
+        context::Kind::KRENDER,
+        template::Kind::KHTML,
+        &options,
+        source,
+    );
+    let block = Block::parse_render(&mut token_stream, &mut context)?;
+    assert_eq!(block.span().blocks().len(), 1);
+    assert_eq!(
+        token_stream.peek_token().map(|t| t.kind()),
+        Some(crate::codegen::parser::tokenizer::Kind::WHITESPACE)
+    );
+
+    Ok(())
+}
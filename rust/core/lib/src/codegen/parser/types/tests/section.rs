@@ -5,8 +5,8 @@ use crate::codegen::CompilerOptions;
 use crate::codegen::parser::tokenizer::Tokenizer;
 use crate::codegen::parser::types::context;
 use crate::codegen::parser::types::context::ParseContext;
-use crate::codegen::types::Block;
 use crate::codegen::types::Template;
+use crate::codegen::types::{Block, SectionMode};
 use crate::types::result;
 use crate::types::template;
 use crate::types::template::Kind;
@@ -53,7 +53,7 @@ fn to_content() -> result::Result<()> {
 
     // 0: section
     let block = &root_span.blocks()[0];
-    assert!(matches!(block, Block::KSECTION(_, _)));
+    assert!(matches!(block, Block::KSECTION(_, _, _)));
     assert_eq!(block.content().trim(), "this is test1");
     let content_block = block.to_content();
     assert!(matches!(content_block, Block::KCONTENT(_)));
@@ -112,3 +112,40 @@ fn parse_transition_block_section_not_within_curlybracket() {
     );
     Block::parse_transition_block(&mut token_stream, &mut context).unwrap();
 }
+
+#[test]
+fn parse_section_modes() -> result::Result<()> {
+    let raw_content =
+        r#"@section a {a}@section b append {b}@section c  prepend{c}@section d replace {d}"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options)?;
+    let root_span = template.block().span();
+    let modes = root_span
+        .blocks()
+        .iter()
+        .map(|block| match block {
+            Block::KSECTION(name, mode, _) => (name.as_str(), *mode),
+            _ => panic!("Expected KSECTION block"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        modes,
+        vec![
+            ("a", SectionMode::KDEFAULT),
+            ("b", SectionMode::KAPPEND),
+            ("c", SectionMode::KPREPEND),
+            ("d", SectionMode::KREPLACE),
+        ]
+    );
+    assert_eq!(root_span.blocks()[1].content(), "b");
+
+    Ok(())
+}
+
+#[test]
+fn parse_section_unknown_mode() {
+    let raw_content = r#"@section a push {a}"#;
+    let options = CompilerOptions::default();
+    let template = Template::from(&raw_content, None, Kind::KHTML, &options);
+    assert!(template.is_err());
+}
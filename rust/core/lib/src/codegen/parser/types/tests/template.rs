@@ -498,7 +498,7 @@ fn template_from_doc_with_simple_section() -> result::Result<()> {
 
     // 0: section
     let block = &root_span.blocks()[0];
-    assert!(matches!(block, Block::KSECTION(_, _)));
+    assert!(matches!(block, Block::KSECTION(_, _, _)));
     assert_eq!(block.location().line, 1);
 
     // 1: content
@@ -527,9 +527,9 @@ fn template_from_doc_content_composit_section() -> result::Result<()> {
 
     // 0: section
     let block = &root_span.blocks()[0];
-    assert!(matches!(block, Block::KSECTION(_, _)));
+    assert!(matches!(block, Block::KSECTION(_, _, _)));
     let section_span = match block {
-        Block::KSECTION(_name, span) => span,
+        Block::KSECTION(_name, _, span) => span,
         _ => panic!("Expected KSECTION block"),
     };
     assert_eq!(section_span.blocks().len(), 3);
@@ -565,11 +565,11 @@ fn template_from_doc_with_multiple_sections() -> result::Result<()> {
 
     // 0: section, newline
     let block = &root_span.blocks()[0];
-    assert!(matches!(block, Block::KSECTION(_, _)));
+    assert!(matches!(block, Block::KSECTION(_, _, _)));
 
     // 1: section, newline
     let block = &root_span.blocks()[1];
-    assert!(matches!(block, Block::KSECTION(_, _)));
+    assert!(matches!(block, Block::KSECTION(_, _, _)));
 
     // 2: content
     let block = &root_span.blocks()[2];
@@ -577,7 +577,7 @@ fn template_from_doc_with_multiple_sections() -> result::Result<()> {
 
     // 3: section, newline
     let block = &root_span.blocks()[3];
-    assert!(matches!(block, Block::KSECTION(_, _)));
+    assert!(matches!(block, Block::KSECTION(_, _, _)));
 
     // 4: content
     let block = &root_span.blocks()[4];
@@ -585,7 +585,7 @@ fn template_from_doc_with_multiple_sections() -> result::Result<()> {
 
     // 5: section
     let block = &root_span.blocks()[5];
-    assert!(matches!(block, Block::KSECTION(_, _)));
+    assert!(matches!(block, Block::KSECTION(_, _, _)));
 
     Ok(())
 }
@@ -659,3 +659,56 @@ fn template_from_doc_with_comment_in_code() {
     let block = template.block();
     assert!(matches!(block, Block::KROOT(_)));
 }
+
+fn block_contents(source: &str) -> result::Result<Vec<String>> {
+    let options = CompilerOptions::default();
+    let template = Template::from(source, None, Kind::KHTML, &options)?;
+    Ok(template
+        .block()
+        .span()
+        .blocks()
+        .iter()
+        .map(|b| b.content())
+        .collect())
+}
+
+#[test]
+fn template_from_trim_markers() -> result::Result<()> {
+    let cases: [(&str, &[&str]); 5] = [
+        ("a \n  @~{ b }\n c", &["a", " b ", " c"]),
+        ("a\n@{ b }~ \n\n c", &["a\n", " b ", "c"]),
+        ("a  @~(b)~  c", &["a", "b", "c"]),
+        ("a @@~ b", &["a @~ b"]),
+        ("a @b~ c", &["a ", "b", "~ c"]),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(block_contents(source)?, expected, "{source:?}");
+    }
+    Ok(())
+}
+
+#[test]
+fn template_from_trim_markers_after_control_block() -> result::Result<()> {
+    let options = CompilerOptions::default();
+    let template = Template::from("@if a {\n  x\n}~\ny", None, Kind::KHTML, &options)?;
+    let blocks = template.block().span().blocks();
+    assert_eq!(blocks.len(), 2);
+    assert!(matches!(blocks[0], Block::KIF(_)));
+    assert_eq!(blocks[1].content(), "y");
+    Ok(())
+}
+
+#[test]
+fn template_from_tilde_in_string_and_text() -> result::Result<()> {
+    // only the delimiter closing a transition takes `~` as a trim marker.
+    assert_eq!(
+        block_contents("@{ let s = \"f(x)~ done\"; }<p>@s</p>")?,
+        [" let s = \"f(x)~ done\"; ", "<p>", "s", "</p>"]
+    );
+    assert_eq!(block_contents("smile :-)~ yes")?, ["smile :-)~ yes"]);
+    assert_eq!(
+        block_contents("{a}~ b @f(x)~ c")?,
+        ["{a}~ b ", "f(x)", "~ c"]
+    );
+    Ok(())
+}
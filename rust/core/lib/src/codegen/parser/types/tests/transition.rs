@@ -96,3 +96,58 @@ fn parse_transition_block_render_invalid_context() {
     let mut context = ParseContext::new(Kind::KCONTENT, template::Kind::KHTML, &options, source);
     Block::parse_transition_block(&mut token_stream, &mut context).unwrap();
 }
+
+#[test]
+fn parse_implicit_expressions() -> result::Result<()> {
+    let cases = [
+        ("<b>@user.name</b>", "user.name", "</b>"),
+        ("<b>@user.display_name()</b>", "user.display_name()", "</b>"),
+        ("<b>@items[0].title</b>", "items[0].title", "</b>"),
+        ("<b>@items[i + 1].title</b>", "items[i + 1].title", "</b>"),
+        (
+            r#"<b>@t("a) b", (1, 2)).len()!</b>"#,
+            r#"t("a) b", (1, 2)).len()"#,
+            "!</b>",
+        ),
+        ("<b>@rows()[0].name.</b>", "rows()[0].name", ".</b>"),
+        ("Hello @name. Bye", "name", ". Bye"),
+        ("Hello @name.. Bye", "name", ".. Bye"),
+        ("@name (admin)", "name", " (admin)"),
+        ("@name(@age)", "name", "("),
+        ("@name(admin", "name", "(admin"),
+    ];
+    let options = CompilerOptions::default();
+    for (source, expression, rest) in cases {
+        let template =
+            crate::codegen::types::Template::from(&source, None, template::Kind::KHTML, &options)?;
+        let blocks = template.block().span().blocks();
+        let index = blocks
+            .iter()
+            .position(|b| matches!(b, Block::KINLINEDCODE(_)))
+            .unwrap();
+        assert_eq!(blocks[index].content(), expression, "{source}");
+        assert_eq!(blocks[index + 1].content(), rest, "{source}");
+    }
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn parse_implicit_expression_unclosed_bracket() {
+    let source = "<b>@items[0</b>";
+    let options = CompilerOptions::default();
+    crate::codegen::types::Template::from(&source, None, template::Kind::KHTML, &options).unwrap();
+}
+
+#[test]
+fn parse_inlined_content_in_code_keeps_word() -> result::Result<()> {
+    let source = "@{ let a = 1; @done. }";
+    let options = CompilerOptions::default();
+    let template =
+        crate::codegen::types::Template::from(&source, None, template::Kind::KHTML, &options)?;
+    let code = &template.block().span().blocks()[0];
+    let content = &code.span().blocks()[1];
+    assert!(matches!(content, Block::KINLINEDCONTENT(_)));
+    assert_eq!(content.content(), "done.");
+    Ok(())
+}
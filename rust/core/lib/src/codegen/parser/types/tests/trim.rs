@@ -0,0 +1,94 @@
+#![cfg(test)]
+use crate::codegen::CompilerOptions;
+use crate::codegen::types::Block;
+use crate::codegen::types::Template;
+use crate::types::result;
+use crate::types::template::Kind;
+
+// content as it's written, `@` for the blocks writing expressions, partials and sections.
+fn write_content(block: &Block, output: &mut String) {
+    match block {
+        Block::KBRANCH(_, span)
+        | Block::KCONTENT(span)
+        | Block::KFOR(span)
+        | Block::KIF(span)
+        | Block::KMATCH(span)
+        | Block::KROOT(span)
+        | Block::KSECTION(_, _, span)
+        | Block::KWHILE(span) => {
+            if let Block::KCONTENT(_) | Block::KSECTION(..) = block {
+                output.push_str(&span.content());
+            }
+            span.blocks().iter().for_each(|b| write_content(b, output));
+        }
+        Block::KRENDER(span) => match span.blocks().last() {
+            Some(fallback @ Block::KSECTION(..)) => write_content(fallback, output),
+            _ => output.push('@'),
+        },
+        Block::KAWAIT(_)
+        | Block::KINLINEDCODE(_)
+        | Block::KINLINEDCONTENT(_)
+        | Block::KPARTIAL(_)
+        | Block::KRAW(_) => output.push('@'),
+        _ => {}
+    }
+}
+
+fn trim_blocks(source: &str) -> result::Result<String> {
+    let options = CompilerOptions::default().with_trim_blocks(Kind::KTEXT, true);
+    let template = Template::from(source, None, Kind::KTEXT, &options)?;
+    let mut output = String::new();
+    write_content(template.block(), &mut output);
+    Ok(output)
+}
+
+#[test]
+fn trim_blocks_control_lines() -> result::Result<()> {
+    let cases = [
+        (
+            "a\n  @if b {\n    c\n  } else if d {\n    e\n  }\nf",
+            "a\n    c\n    e\nf",
+        ),
+        ("  @for i in v {\n  - @i\n  }\n", "  - @\n"),
+        ("@{\n    // a\n    let a = 1;\n}\nb", "b"),
+        ("  @{ let a = 1; }  \nb @{ c }\n", "b \n"),
+        ("@* a *@\nb", "b"),
+        ("@use a::b;\nc\n@use d\ne", "c\ne"),
+        (
+            "@match a {\n  1 => {\n  b\n  }\n  _ => { c }\n}\nd",
+            "  b\n c d",
+        ),
+        ("{\n  }\n@model\n", "{\n  }\n@\n"),
+        ("@model A;\n<p>@model is ready</p>\n", "<p>@ is ready</p>\n"),
+        ("  @render(a) {\n  b\n  }\n@render(c)\nd", "  b\n@\nd"),
+        ("a\n  @flush\nb", "a\nb"),
+        ("a\n  @partial(\"b\")\nc", "a\n  @\nc"),
+        ("a\n  @await b()\nc", "a\n  @\nc"),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(trim_blocks(source)?, expected, "{source:?}");
+    }
+    Ok(())
+}
+
+#[test]
+fn trim_blocks_keeps_blank_lines() -> result::Result<()> {
+    assert_eq!(
+        trim_blocks("@if a {\n  x\n\n  y\n}\n\nz")?,
+        "  x\n\n  y\n\nz"
+    );
+    Ok(())
+}
+
+#[test]
+fn trim_blocks_with_trim_markers() -> result::Result<()> {
+    let cases = [
+        ("  @if a {\n  x\n  }~\ny", "  x\ny"),
+        ("a: @~(b)~\nc", "a:@c"),
+        ("smile :-)~\n", "smile :-)~\n"),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(trim_blocks(source)?, expected, "{source:?}");
+    }
+    Ok(())
+}
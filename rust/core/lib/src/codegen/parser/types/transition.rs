@@ -1,5 +1,7 @@
 use crate::codegen::consts;
-use crate::codegen::parser::tokenizer::skip_newline;
+use crate::codegen::parser::tokenizer::{
+    get_nth_token, skip_newline, skip_next_token_if, skip_whitespace_and_newline,
+};
 use crate::codegen::parser::types::context::{Kind, ParseContext};
 use crate::codegen::parser::{Token, tokenizer};
 use crate::codegen::types::Span;
@@ -35,8 +37,9 @@ impl<'a> Block<'a> {
             }
         };
 
-        // consume @.
+        // consume @ and the trim marker of `@~`.
         token_stream.next_token();
+        skip_next_token_if(token_stream, |k| k == tokenizer::Kind::TILDE);
         match token_stream.peek_token() {
             None => Err(error::CompileError::from_parser(
                 source,
@@ -44,7 +47,7 @@ impl<'a> Block<'a> {
                 "Expected content after '@'",
             )),
             Some(token) => {
-                let block = match token.kind() {
+                let mut block = match token.kind() {
                     tokenizer::Kind::OPARENTHESIS => {
                         // code part.
                         Self::parse_block_within_kinds(
@@ -67,7 +70,14 @@ impl<'a> Block<'a> {
                         let exp = &source[token.range()];
                         // todo: create a map for directive keywords.
                         match exp {
-                            consts::DIRECTIVE_KEYWORD_LAYOUT | consts::DIRECTIVE_KEYWORD_USE => {
+                            consts::DIRECTIVE_KEYWORD_MODEL
+                                if context.block_kind() == Kind::KMODEL =>
+                            {
+                                Self::parse_directive(source, exp, token_stream)?
+                            }
+                            consts::DIRECTIVE_KEYWORD_INJECT
+                            | consts::DIRECTIVE_KEYWORD_LAYOUT
+                            | consts::DIRECTIVE_KEYWORD_USE => {
                                 Self::parse_directive(source, exp, token_stream)?
                             }
                             consts::KEYWORD_RENDER => {
@@ -87,6 +97,82 @@ impl<'a> Block<'a> {
                                     ));
                                 }
                             }
+                            consts::KEYWORD_PARTIAL => {
+                                if context.is_code() {
+                                    Self::parse_partial(
+                                        token,
+                                        token_stream,
+                                        &mut context.clone_for(Kind::KPARTIAL),
+                                    )?
+                                } else {
+                                    return Err(error::CompileError::from_parser(
+                                        source,
+                                        Some(*token),
+                                        &format!(
+                                            "'@{}' can only be used in content block.",
+                                            consts::KEYWORD_PARTIAL
+                                        ),
+                                    ));
+                                }
+                            }
+                            consts::KEYWORD_RAW => {
+                                if context.is_code() {
+                                    Self::parse_raw(
+                                        token,
+                                        token_stream,
+                                        &mut context.clone_for(Kind::KRAW),
+                                    )?
+                                } else {
+                                    return Err(error::CompileError::from_parser(
+                                        source,
+                                        Some(*token),
+                                        &format!(
+                                            "'@{}' can only be used in content block.",
+                                            consts::KEYWORD_RAW
+                                        ),
+                                    ));
+                                }
+                            }
+                            consts::KEYWORD_AWAIT => {
+                                if context.is_code() {
+                                    Self::parse_await(token, token_stream, context)?
+                                } else {
+                                    return Err(error::CompileError::from_parser(
+                                        source,
+                                        Some(*token),
+                                        &format!(
+                                            "'@{}' can only be used in content block, use '.{}' in code.",
+                                            consts::KEYWORD_AWAIT,
+                                            consts::KEYWORD_AWAIT
+                                        ),
+                                    ));
+                                }
+                            }
+                            consts::KEYWORD_FLUSH => {
+                                if context.is_code() {
+                                    Self::parse_flush(token, token_stream, context)?
+                                } else {
+                                    return Err(error::CompileError::from_parser(
+                                        source,
+                                        Some(*token),
+                                        &format!(
+                                            "'@{}' can only be used in content block.",
+                                            consts::KEYWORD_FLUSH
+                                        ),
+                                    ));
+                                }
+                            }
+                            consts::KEYWORD_FOR => Self::parse_for(token, token_stream, context)?,
+                            consts::KEYWORD_FUNCTIONS => {
+                                Self::parse_functions(token, token_stream, context)?
+                            }
+                            consts::KEYWORD_IF => Self::parse_if(token, token_stream, context)?,
+                            consts::KEYWORD_MATCH => {
+                                Self::parse_match(token, token_stream, context)?
+                            }
+                            consts::KEYWORD_WHILE => {
+                                Self::parse_while(token, token_stream, context)?
+                            }
                             consts::KEYWORD_SECTION => Self::parse_section(
                                 token,
                                 token_stream,
@@ -111,23 +197,210 @@ impl<'a> Block<'a> {
                         ));
                     }
                 };
-                // skip the newline after the block.
-                skip_newline(token_stream);
+                // `}~` or `)~` at the end of a block strips the whitespace and newlines after it,
+                // implicit expressions like `@f(x)~` are not closed by a delimiter of the transition.
+                let closes_block =
+                    matches!(
+                        token.kind(),
+                        tokenizer::Kind::OPARENTHESIS | tokenizer::Kind::OCURLYBRACKET
+                    ) || !matches!(block, Block::KINLINEDCODE(_) | Block::KINLINEDCONTENT(_));
+                let closing_token = token_stream.previous_tokens().next();
+                // trim_blocks finds the lines written by expressions from it.
+                let end = closing_token.map_or(start_token.range().end, |t| t.range().end);
+                block.span_mut().set_range(start_token.range().start..end);
+                let closing_token = closing_token.map(|t| t.kind());
+                if closes_block
+                    && matches!(
+                        closing_token,
+                        Some(tokenizer::Kind::CPARENTHESIS | tokenizer::Kind::CCURLYBRACKET)
+                    )
+                    && skip_next_token_if(token_stream, |k| k == tokenizer::Kind::TILDE)
+                {
+                    skip_whitespace_and_newline(token_stream);
+                } else if !context.need_trim_blocks() {
+                    // skip the newline after the block, trim_blocks handles the line ends once the blocks are parsed.
+                    skip_newline(token_stream);
+                }
                 Ok(block)
             }
         }
     }
 
-    fn create_inlined_code_block<'s>(
+    // @name, @user.display_name(), @items[i + 1].title
+    pub(in crate::codegen::parser::types) fn create_inlined_code_block<'s>(
         token: &Token,
         token_stream: &mut TokenStream,
         context: &ParseContext<'_, 's>,
     ) -> result::Result<Block<'s>> {
+        let source = context.source();
         // consume the expression token.
         token_stream.next_token();
-        let mut span = Span::new(context.source());
+        let mut span = Span::new(source);
         span.push_token(*token);
+        if context.block_kind() == Kind::KINLINEDCONTENT {
+            // content in code is a word, keep the adjacent pieces like `@done.`
+            let mut end = token.range().end;
+            while let Some(next) = token_stream.peek_token() {
+                if next.kind() != tokenizer::Kind::EXPRESSION || next.range().start != end {
+                    break;
+                }
+                end = next.range().end;
+                span.push_token(*next);
+                token_stream.next_token();
+            }
+        } else {
+            // balanced `()`/`[]` and `.field` chains, stop at whitespace or markup.
+            let mut brackets = bracket_depth(&source[token.range()]);
+            loop {
+                match token_stream.peek_token() {
+                    Some(next) if brackets > 0 && next.kind() != tokenizer::Kind::EOF => {
+                        if next.kind() == tokenizer::Kind::EXPRESSION {
+                            brackets += bracket_depth(&source[next.range()]);
+                        }
+                        span.push_token(*next);
+                        token_stream.next_token();
+                    }
+                    Some(_) if brackets > 0 => {
+                        return Err(error::CompileError::from_parser(
+                            source,
+                            Some(*token),
+                            "Expected ']' to close the expression",
+                        ));
+                    }
+                    Some(next) if next.kind() == tokenizer::Kind::OPARENTHESIS => {
+                        match Self::parse_call_arguments(token_stream, source) {
+                            Some(count) => {
+                                for _ in 0..count {
+                                    if let Some(arg) = token_stream.next_token() {
+                                        span.push_token(*arg);
+                                    }
+                                }
+                            }
+                            None => break,
+                        }
+                    }
+                    Some(next)
+                        if next.kind() == tokenizer::Kind::EXPRESSION
+                            && is_expression_chain(&source[next.range()]) =>
+                    {
+                        brackets += bracket_depth(&source[next.range()]);
+                        span.push_token(*next);
+                        token_stream.next_token();
+                    }
+                    _ => break,
+                }
+            }
+        }
         let block = ParseContext::create_block(&context, None, span)?;
         Ok(block)
     }
+
+    // `(...)` with nested parenthesis and string literals, `@name(@age)` is content after `name`.
+    pub(in crate::codegen::parser::types) fn parse_call_arguments(
+        token_stream: &TokenStream,
+        source: &str,
+    ) -> Option<usize> {
+        Self::parse_call_separators(token_stream, source)?
+            .last()
+            .map(|close| close + 1)
+    }
+
+    // offsets of the ',' between the arguments and of the closing ')', `(a, ',', "\\")` has one ','.
+    pub(in crate::codegen::parser::types) fn parse_call_separators(
+        token_stream: &TokenStream,
+        source: &str,
+    ) -> Option<Vec<usize>> {
+        let open = get_nth_token(token_stream, 0)?;
+        if open.kind() != tokenizer::Kind::OPARENTHESIS {
+            return None;
+        }
+        let start = open.range().start;
+        let mut separators = vec![];
+        let mut offset = 0;
+        for end in scan_call_arguments(&source[start..])? {
+            // the token starting at the byte.
+            while get_nth_token(token_stream, offset)?.range().start < start + end {
+                offset += 1;
+            }
+            separators.push(offset);
+        }
+        Some(separators)
+    }
+}
+
+// bytes of the ',' out of brackets and literals and of the ')' closing the call.
+fn scan_call_arguments(call: &str) -> Option<Vec<usize>> {
+    let bytes = call.as_bytes();
+    let mut separators = vec![];
+    let mut depth = 0;
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'"' => index = string_end(bytes, index)?,
+            b'\'' => index = char_end(call, index),
+            b'@' => return None,
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' if depth == 1 => {
+                separators.push(index);
+                return Some(separators);
+            }
+            b')' | b']' | b'}' => depth -= 1,
+            b',' if depth == 1 => separators.push(index),
+            _ => {}
+        }
+        index += 1;
+    }
+    None
+}
+
+// the closing '"' of the string literal starting at `start`.
+fn string_end(bytes: &[u8], start: usize) -> Option<usize> {
+    let mut index = start + 1;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' => index += 1,
+            b'"' => return Some(index),
+            _ => {}
+        }
+        index += 1;
+    }
+    None
+}
+
+// the closing '\'' of the char literal starting at `start`, a lifetime like `'a` is left as it is.
+fn char_end(call: &str, start: usize) -> usize {
+    let rest = &call[start + 1..];
+    let end = match rest.strip_prefix('\\') {
+        // `'\''` and `'\\'`, the escaped char is skipped.
+        Some(escaped) => escaped
+            .char_indices()
+            .skip(1)
+            .find(|(_, c)| *c == '\'')
+            .map(|(index, _)| index + 1),
+        None => rest
+            .chars()
+            .next()
+            .filter(|c| rest[c.len_utf8()..].starts_with('\''))
+            .map(|c| c.len_utf8()),
+    };
+    end.map_or(start, |end| start + 1 + end)
+}
+
+// `[` not closed yet in the expression.
+fn bracket_depth(exp: &str) -> i32 {
+    exp.chars().fold(0, |depth, c| match c {
+        '[' => depth + 1,
+        ']' => depth - 1,
+        _ => depth,
+    })
+}
+
+// `.field` or `[index]` after a call.
+fn is_expression_chain(exp: &str) -> bool {
+    let mut chars = exp.chars();
+    match chars.next() {
+        Some('[') => true,
+        Some('.') => matches!(chars.next(), Some(c) if c.is_alphanumeric() || c == '_'),
+        _ => false,
+    }
 }
@@ -0,0 +1,143 @@
+use crate::codegen::parser::{Token, tokenizer};
+use crate::codegen::types::Block;
+use crate::types::Location;
+use std::ops::Range;
+
+// lines of the source with the bytes of content and the lines written by content or expressions.
+struct Lines<'s> {
+    source: &'s str,
+    starts: Vec<usize>,
+    content: Vec<bool>,
+    written: Vec<bool>,
+}
+
+impl<'s> Lines<'s> {
+    fn new(source: &'s str) -> Self {
+        let starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(index, _)| index + 1))
+            .collect::<Vec<_>>();
+        Self {
+            source,
+            content: vec![false; source.len()],
+            written: vec![false; starts.len()],
+            starts,
+        }
+    }
+
+    fn line_of(&self, offset: usize) -> usize {
+        self.starts.partition_point(|start| *start <= offset) - 1
+    }
+
+    fn mark_content(&mut self, token: &Token) {
+        let range = token.range();
+        self.content[range.clone()].fill(true);
+        if !self.source[range.clone()].trim().is_empty() {
+            let line = self.line_of(range.start);
+            self.written[line] = true;
+        }
+    }
+
+    fn mark_written(&mut self, range: Range<usize>) {
+        let first = self.line_of(range.start);
+        let last = self.line_of(range.end.max(range.start + 1) - 1);
+        self.written[first..=last].fill(true);
+    }
+
+    // syntax out of the content, like `@if a {`, `}` or `@flush`, and nothing written.
+    fn is_control(&self, line: usize) -> bool {
+        let end = self
+            .starts
+            .get(line + 1)
+            .copied()
+            .unwrap_or(self.source.len());
+        !self.written[line]
+            && (self.starts[line]..end)
+                .any(|i| !self.content[i] && !self.source.as_bytes()[i].is_ascii_whitespace())
+    }
+}
+
+impl<'a> Block<'a> {
+    /// removes the indentation and the line end of lines which only contain control syntax,
+    /// like `trim_blocks` and `lstrip_blocks` of jinja.
+    pub(in crate::codegen::parser::types) fn trim_blocks(&mut self, source: &str) {
+        let mut lines = Lines::new(source);
+        self.scan_lines(&mut lines);
+        let control = (0..lines.starts.len())
+            .map(|line| lines.is_control(line))
+            .collect::<Vec<_>>();
+        self.trim_lines(&|token| match control[lines.line_of(token.range().start)] {
+            true => trim_line_end(source, token),
+            false => Some(*token),
+        });
+    }
+
+    // blocks holding content and control blocks, code isn't trimmed.
+    fn content_blocks(&mut self) -> &mut [Block<'a>] {
+        match self {
+            Block::KBRANCH(_, span)
+            | Block::KCONTENT(span)
+            | Block::KFOR(span)
+            | Block::KIF(span)
+            | Block::KMATCH(span)
+            | Block::KROOT(span)
+            | Block::KSECTION(_, _, span)
+            | Block::KWHILE(span) => span.blocks_mut(),
+            // the fallback of `@render(name) { ... }`.
+            Block::KRENDER(span) if matches!(span.blocks().last(), Some(Block::KSECTION(..))) => {
+                let blocks = span.blocks_mut();
+                let last = blocks.len() - 1;
+                &mut blocks[last..]
+            }
+            _ => &mut [],
+        }
+    }
+
+    fn scan_lines(&mut self, lines: &mut Lines) {
+        match self {
+            Block::KCONTENT(span) | Block::KSECTION(_, _, span) => {
+                span.tokens().iter().for_each(|t| lines.mark_content(t))
+            }
+            Block::KRENDER(span) if !matches!(span.blocks().last(), Some(Block::KSECTION(..))) => {
+                lines.mark_written(span.range());
+            }
+            Block::KAWAIT(span)
+            | Block::KINLINEDCODE(span)
+            | Block::KINLINEDCONTENT(span)
+            | Block::KPARTIAL(span)
+            | Block::KRAW(span) => lines.mark_written(span.range()),
+            _ => {}
+        }
+        for block in self.content_blocks() {
+            block.scan_lines(lines);
+        }
+    }
+
+    // control lines only hold whitespace and the line end of the content.
+    fn trim_lines(&mut self, trim: &impl Fn(&Token) -> Option<Token>) {
+        if let Block::KCONTENT(span) | Block::KSECTION(_, _, span) = self {
+            let tokens = span.tokens().iter().filter_map(trim).collect();
+            *span.tokens_mut() = tokens;
+        }
+        for block in self.content_blocks() {
+            block.trim_lines(trim);
+        }
+        self.span_mut()
+            .blocks_mut()
+            .retain(|b| !matches!(b, Block::KCONTENT(span) if span.is_empty()));
+    }
+}
+
+// the tokenizer takes `\n\n` as one newline, the blank line after the control line is kept.
+fn trim_line_end(source: &str, token: &Token) -> Option<Token> {
+    let range = token.range();
+    let first_end = source[range.clone()].find('\n')? + range.start + 1;
+    (token.kind() == tokenizer::Kind::NEWLINE && first_end < range.end).then(|| {
+        let line = token.location().line + 1;
+        Token::new(
+            tokenizer::Kind::NEWLINE,
+            first_end,
+            range.end,
+            Location::new(line, 0),
+        )
+    })
+}
@@ -4,20 +4,6 @@ use crate::codegen::parser::tokenizer::get_nth_token;
 use winnow::stream::Stream as _;
 use winnow::stream::TokenSlice;
 
-pub(in crate::codegen::parser::types) fn get_token_if<'a, F: Fn(Kind) -> bool>(
-    stream: &'a mut TokenSlice<Token>,
-    kind_fn: F,
-) -> Option<&'a Token> {
-    let token = stream.peek_token();
-    match token {
-        Some(t) if kind_fn(t.kind()) => {
-            stream.next_token();
-            Some(t)
-        }
-        _ => None,
-    }
-}
-
 /// check if the token is @@escaped in the source string
 pub(in crate::codegen::parser::types) fn is_token_escaped<'a>(
     stream: &'a TokenSlice<Token>,
@@ -29,3 +15,71 @@ pub(in crate::codegen::parser::types) fn is_token_escaped<'a>(
         _ => false,
     }
 }
+
+/// check if the transition is `@~`, which strips the whitespace and newlines before it.
+pub(in crate::codegen::parser::types) fn is_trim_marked(stream: &TokenSlice<Token>) -> bool {
+    let first = get_nth_token(stream, 0);
+    let second = get_nth_token(stream, 1);
+    matches!(
+        (first, second),
+        (Some(first), Some(second)) if first.kind() == Kind::AT && second.kind() == Kind::TILDE
+    )
+}
+
+/// consume the header tokens of a control block, `if a > (b + 1) {` => `a > (b + 1)`, and stop at the opening '{'.
+pub(in crate::codegen::parser::types) fn parse_block_header(
+    stream: &mut TokenSlice<Token>,
+) -> Option<Vec<Token>> {
+    let mut depth = 0;
+    let mut tokens = vec![];
+    while let Some(token) = stream.peek_token() {
+        match token.kind() {
+            Kind::EOF => return None,
+            Kind::OCURLYBRACKET if depth == 0 => return Some(tokens),
+            Kind::OPARENTHESIS => depth += 1,
+            Kind::CPARENTHESIS => depth -= 1,
+            _ => {}
+        }
+        tokens.push(*token);
+        stream.next_token();
+    }
+    None
+}
+
+/// check the next token after whitespace/newline is `keyword` without consuming, return the offset after it.
+pub(in crate::codegen::parser::types) fn peek_keyword(
+    stream: &TokenSlice<Token>,
+    source: &str,
+    keyword: &str,
+) -> Option<usize> {
+    let mut offset = 0;
+    while let Some(token) = get_nth_token(stream, offset) {
+        match token.kind() {
+            Kind::WHITESPACE | Kind::NEWLINE => offset += 1,
+            Kind::EXPRESSION if &source[token.range()] == keyword => return Some(offset + 1),
+            _ => return None,
+        }
+    }
+    None
+}
+
+/// offset of the first token which is not whitespace/newline from `offset`.
+pub(in crate::codegen::parser::types) fn skip_whitespace_offset(
+    stream: &TokenSlice<Token>,
+    offset: usize,
+) -> Option<usize> {
+    let mut offset = offset;
+    while let Some(token) = get_nth_token(stream, offset) {
+        match token.kind() {
+            Kind::WHITESPACE | Kind::NEWLINE => offset += 1,
+            _ => return Some(offset),
+        }
+    }
+    None
+}
+
+pub(in crate::codegen::parser::types) fn advance(stream: &mut TokenSlice<Token>, count: usize) {
+    for _ in 0..count {
+        stream.next_token();
+    }
+}
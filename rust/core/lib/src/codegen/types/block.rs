@@ -1,38 +1,80 @@
 use crate::codegen::types::Span;
 use crate::types::Location;
 
+// how `@section name mode {}` merges into the contents added before.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(in crate::codegen) enum SectionMode {
+    // the first one wins.
+    KDEFAULT,
+    KAPPEND,
+    KPREPEND,
+    KREPLACE,
+}
+
 #[derive(Clone, Debug)]
 pub(in crate::codegen) enum Block<'a> {
+    // @await exp: the expression awaited and written like inlined code.
+    KAWAIT(Span<'a>),
+    // if/else if/else branch or match arm: condition or pattern, empty for else.
+    KBRANCH(String, Span<'a>),
     KCODE(Span<'a>),
     KCOMMENT(Span<'a>),
     KCONTENT(Span<'a>),
+    KFLUSH(Span<'a>),
+    KFOR(Span<'a>),
     KFUNCTIONS(Span<'a>),
+    KIF(Span<'a>),
+    KINJECT(Span<'a>),
     KINLINEDCODE(Span<'a>),
     KINLINEDCONTENT(Span<'a>),
     KLAYOUT(Span<'a>),
+    KMATCH(Span<'a>),
+    KMODEL(Span<'a>),
+    KPARTIAL(Span<'a>),
+    KRAW(Span<'a>),
     KROOT(Span<'a>),
     KRENDER(Span<'a>),
-    KSECTION(String, Span<'a>),
+    KSECTION(String, SectionMode, Span<'a>),
     KUSE(Span<'a>),
+    KWHILE(Span<'a>),
 }
 
 impl<'a> Block<'a> {
     pub(in crate::codegen) fn location(&self) -> Location {
         match self {
+            Block::KAWAIT(span) => span.location(),
+            Block::KBRANCH(_, span) => span.location(),
             Block::KCODE(span) => span.location(),
             Block::KCOMMENT(span) => span.location(),
             Block::KCONTENT(span) => span.location(),
+            Block::KFLUSH(span) => span.location(),
+            Block::KFOR(span) => span.location(),
             Block::KFUNCTIONS(span) => span.location(),
+            Block::KIF(span) => span.location(),
+            Block::KINJECT(span) => span.location(),
             Block::KINLINEDCODE(span) => span.location(),
             Block::KINLINEDCONTENT(span) => span.location(),
             Block::KLAYOUT(span) => span.location(),
+            Block::KMATCH(span) => span.location(),
+            Block::KMODEL(span) => span.location(),
+            Block::KPARTIAL(span) => span.location(),
+            Block::KRAW(span) => span.location(),
             Block::KRENDER(span) => span.location(),
             Block::KROOT(span) => span.location(),
-            Block::KSECTION(_, span) => span.location(),
+            Block::KSECTION(_, _, span) => span.location(),
             Block::KUSE(span) => span.location(),
+            Block::KWHILE(span) => span.location(),
         }
     }
 
+    pub(in crate::codegen) fn new_await(span: Span<'a>) -> Self {
+        Block::KAWAIT(span)
+    }
+
+    pub(in crate::codegen) fn new_branch(condition: &str, span: Span<'a>) -> Self {
+        Block::KBRANCH(condition.to_string(), span)
+    }
+
     pub(in crate::codegen) fn new_code(span: Span<'a>) -> Self {
         Block::KCODE(span)
     }
@@ -45,10 +87,26 @@ impl<'a> Block<'a> {
         Block::KCONTENT(span)
     }
 
+    pub(in crate::codegen) fn new_flush(span: Span<'a>) -> Self {
+        Block::KFLUSH(span)
+    }
+
+    pub(in crate::codegen) fn new_for(span: Span<'a>) -> Self {
+        Block::KFOR(span)
+    }
+
     pub(in crate::codegen) fn new_functions(span: Span<'a>) -> Self {
         Block::KFUNCTIONS(span)
     }
 
+    pub(in crate::codegen) fn new_if(span: Span<'a>) -> Self {
+        Block::KIF(span)
+    }
+
+    pub(in crate::codegen) fn new_inject(span: Span<'a>) -> Self {
+        Block::KINJECT(span)
+    }
+
     pub(in crate::codegen) fn new_inline_code(span: Span<'a>) -> Self {
         Block::KINLINEDCODE(span)
     }
@@ -61,6 +119,22 @@ impl<'a> Block<'a> {
         Block::KLAYOUT(span)
     }
 
+    pub(in crate::codegen) fn new_match(span: Span<'a>) -> Self {
+        Block::KMATCH(span)
+    }
+
+    pub(in crate::codegen) fn new_model(span: Span<'a>) -> Self {
+        Block::KMODEL(span)
+    }
+
+    pub(in crate::codegen) fn new_partial(span: Span<'a>) -> Self {
+        Block::KPARTIAL(span)
+    }
+
+    pub(in crate::codegen) fn new_raw(span: Span<'a>) -> Self {
+        Block::KRAW(span)
+    }
+
     pub(in crate::codegen) fn new_render(span: Span<'a>) -> Self {
         Block::KRENDER(span)
     }
@@ -69,17 +143,21 @@ impl<'a> Block<'a> {
         Block::KROOT(span)
     }
 
-    pub(in crate::codegen) fn new_section(name: &str, span: Span<'a>) -> Self {
-        Block::KSECTION(name.to_string(), span)
+    pub(in crate::codegen) fn new_section(name: &str, mode: SectionMode, span: Span<'a>) -> Self {
+        Block::KSECTION(name.to_string(), mode, span)
     }
 
     pub(in crate::codegen) fn new_use(span: Span<'a>) -> Self {
         Block::KUSE(span)
     }
 
+    pub(in crate::codegen) fn new_while(span: Span<'a>) -> Self {
+        Block::KWHILE(span)
+    }
+
     pub(in crate::codegen) fn to_content(&self) -> Self {
         match self {
-            Block::KSECTION(_, span) => Block::KCONTENT(span.clone()),
+            Block::KSECTION(_, _, span) => Block::KCONTENT(span.clone()),
             _ => {
                 panic!("Only section block can have name");
             }
@@ -88,33 +166,82 @@ impl<'a> Block<'a> {
 
     pub(in crate::codegen) fn span(&self) -> &Span<'a> {
         match self {
+            Block::KAWAIT(span) => span,
+            Block::KBRANCH(_, span) => span,
+            Block::KCODE(span) => span,
+            Block::KCOMMENT(span) => span,
+            Block::KCONTENT(span) => span,
+            Block::KFLUSH(span) => span,
+            Block::KFOR(span) => span,
+            Block::KFUNCTIONS(span) => span,
+            Block::KIF(span) => span,
+            Block::KINJECT(span) => span,
+            Block::KINLINEDCODE(span) => span,
+            Block::KINLINEDCONTENT(span) => span,
+            Block::KLAYOUT(span) => span,
+            Block::KMATCH(span) => span,
+            Block::KMODEL(span) => span,
+            Block::KPARTIAL(span) => span,
+            Block::KRAW(span) => span,
+            Block::KRENDER(span) => span,
+            Block::KROOT(span) => span,
+            Block::KSECTION(_, _, span) => span,
+            Block::KUSE(span) => span,
+            Block::KWHILE(span) => span,
+        }
+    }
+
+    pub(in crate::codegen) fn span_mut(&mut self) -> &mut Span<'a> {
+        match self {
+            Block::KAWAIT(span) => span,
+            Block::KBRANCH(_, span) => span,
             Block::KCODE(span) => span,
             Block::KCOMMENT(span) => span,
             Block::KCONTENT(span) => span,
+            Block::KFLUSH(span) => span,
+            Block::KFOR(span) => span,
             Block::KFUNCTIONS(span) => span,
+            Block::KIF(span) => span,
+            Block::KINJECT(span) => span,
             Block::KINLINEDCODE(span) => span,
             Block::KINLINEDCONTENT(span) => span,
             Block::KLAYOUT(span) => span,
+            Block::KMATCH(span) => span,
+            Block::KMODEL(span) => span,
+            Block::KPARTIAL(span) => span,
+            Block::KRAW(span) => span,
             Block::KRENDER(span) => span,
             Block::KROOT(span) => span,
-            Block::KSECTION(_, span) => span,
+            Block::KSECTION(_, _, span) => span,
             Block::KUSE(span) => span,
+            Block::KWHILE(span) => span,
         }
     }
 
     pub(in crate::codegen) fn content(&self) -> String {
         match self {
+            Block::KAWAIT(span) => span.content(),
+            Block::KBRANCH(_, span) => span.content(),
             Block::KCODE(span) => span.content(),
             Block::KCOMMENT(span) => span.content(),
             Block::KCONTENT(span) => span.content(),
+            Block::KFLUSH(span) => span.content(),
+            Block::KFOR(span) => span.content(),
             Block::KFUNCTIONS(span) => span.content(),
+            Block::KIF(span) => span.content(),
+            Block::KINJECT(span) => span.content(),
             Block::KINLINEDCODE(span) => span.content(),
             Block::KINLINEDCONTENT(span) => span.content(),
             Block::KLAYOUT(span) => span.content(),
+            Block::KMATCH(span) => span.content(),
+            Block::KMODEL(span) => span.content(),
+            Block::KPARTIAL(span) => span.content(),
+            Block::KRAW(span) => span.content(),
             Block::KRENDER(span) => span.content(),
             Block::KROOT(span) => span.content(),
-            Block::KSECTION(_, span) => span.content(),
+            Block::KSECTION(_, _, span) => span.content(),
             Block::KUSE(span) => span.content(),
+            Block::KWHILE(span) => span.content(),
         }
     }
 }
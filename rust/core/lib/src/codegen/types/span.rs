@@ -28,6 +28,28 @@ impl<'a> Span<'a> {
         &self.blocks
     }
 
+    pub(in crate::codegen) fn blocks_mut(&mut self) -> &mut Vec<Block<'a>> {
+        &mut self.blocks
+    }
+
+    pub(in crate::codegen) fn tokens(&self) -> &Vec<Token> {
+        &self.tokens
+    }
+
+    pub(in crate::codegen) fn tokens_mut(&mut self) -> &mut Vec<Token> {
+        &mut self.tokens
+    }
+
+    // source range of the transition the span is parsed from, empty for others.
+    pub(in crate::codegen) fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    pub(in crate::codegen) fn set_range(&mut self, range: Range<usize>) -> &mut Self {
+        self.range = range;
+        self
+    }
+
     pub(in crate::codegen) fn is_simple(&self) -> bool {
         self.blocks.is_empty()
     }
@@ -57,6 +79,11 @@ impl<'a> Span<'a> {
         self
     }
 
+    pub(in crate::codegen) fn prepend_blocks(&mut self, blocks: Vec<Block<'a>>) -> &mut Self {
+        self.blocks.splice(0..0, blocks);
+        self
+    }
+
     pub(in crate::codegen) fn push_token(&mut self, token: Token) -> &mut Self {
         self.tokens.push(token);
         self
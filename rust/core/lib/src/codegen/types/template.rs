@@ -5,6 +5,8 @@ pub(in crate::codegen) struct Template<'a> {
     namespace: Option<String>,
     block: Block<'a>,
     kind: Kind,
+    // the layout is set by a `_viewstart`.
+    view_start_layout: bool,
 }
 
 impl<'a> Template<'a> {
@@ -13,9 +15,19 @@ impl<'a> Template<'a> {
             namespace,
             block,
             kind,
+            view_start_layout: false,
         }
     }
 
+    pub(in crate::codegen) fn with_view_start_layout(mut self, view_start_layout: bool) -> Self {
+        self.view_start_layout = view_start_layout;
+        self
+    }
+
+    pub(in crate::codegen) fn has_view_start_layout(&self) -> bool {
+        self.view_start_layout
+    }
+
     pub(in crate::codegen) fn namespace(&self) -> Option<&String> {
         self.namespace.as_ref()
     }
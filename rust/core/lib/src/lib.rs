@@ -6,3 +6,7 @@ pub mod codegen;
 
 // mod for build and runtime.
 pub mod types;
+
+// chunks of RenderStream.
+#[cfg(feature = "stream")]
+pub use bytes;
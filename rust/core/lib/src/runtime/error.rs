@@ -13,6 +13,55 @@ impl RuntimeError {
             format!("Layout '{layout}' not found for View `{view_name}`"),
         )
     }
+
+    pub fn partial_not_found(partial: &str, view_name: &str) -> Self {
+        RuntimeError::NotFound(
+            view_name.to_string(),
+            format!("Partial '{partial}' not found for View `{view_name}`"),
+        )
+    }
+
+    pub fn service_not_found(service: &str, view_name: &str) -> Self {
+        RuntimeError::NotFound(
+            view_name.to_string(),
+            format!("Service '{service}' not found for View `{view_name}`"),
+        )
+    }
+
+    pub fn model_not_found(model: &str, view_name: &str) -> Self {
+        RuntimeError::NotFound(
+            view_name.to_string(),
+            format!("Model '{model}' not found for View `{view_name}`"),
+        )
+    }
+
+    pub fn non_finite_number(value: &str) -> Self {
+        RuntimeError::InvalidValue(
+            "Json".to_string(),
+            format!("Non-finite number '{value}' is not valid json"),
+        )
+    }
+
+    pub fn write_failed(detail: &str) -> Self {
+        RuntimeError::WriteFailed(
+            "Writer".to_string(),
+            format!("Failed to write the output: {detail}"),
+        )
+    }
+
+    pub fn async_view(view_name: &str) -> Self {
+        RuntimeError::Unsupported(
+            view_name.to_string(),
+            format!("View `{view_name}` awaits while rendering, use `render_async` instead"),
+        )
+    }
+
+    pub fn format_failed() -> Self {
+        RuntimeError::InvalidValue(
+            "Format".to_string(),
+            "A formatting trait implementation returned an error".to_string(),
+        )
+    }
 }
 
 impl fmt::Display for RuntimeError {
@@ -21,6 +70,18 @@ impl fmt::Display for RuntimeError {
             RuntimeError::NotFound(name, message) => {
                 write!(f, "View:{}, NotFound: {}", name, message)
             }
+            RuntimeError::InvalidValue(name, message) => {
+                write!(f, "{}, InvalidValue: {}", name, message)
+            }
+            RuntimeError::WriteFailed(name, message) => {
+                write!(f, "{}, WriteFailed: {}", name, message)
+            }
+            RuntimeError::Unsupported(name, message) => {
+                write!(f, "View:{}, Unsupported: {}", name, message)
+            }
         }
     }
 }
+
+// web frameworks take errors of body streams as boxed std errors.
+impl std::error::Error for RuntimeError {}
@@ -0,0 +1,89 @@
+// Escaping helpers used by writers for expression output.
+use crate::types::EscapeContext;
+use crate::types::template::Kind;
+
+// expression output of a template of the kind.
+pub(crate) fn escape(kind: Kind, content: &str, context: EscapeContext, output: &mut String) {
+    match (kind, context) {
+        (
+            Kind::KHTML,
+            EscapeContext::KText | EscapeContext::KJsonString | EscapeContext::KJsonValue,
+        ) => escape_html_text(content, output),
+        (Kind::KHTML, EscapeContext::KAttribute) => escape_html_attribute(content, output),
+        (Kind::KHTML, EscapeContext::KUnquotedAttribute) => {
+            escape_html_unquoted_attribute(content, output)
+        }
+        (Kind::KJSON, EscapeContext::KJsonString) => escape_json_string(content, output),
+        // json value is written as is, e.g. numbers, booleans or serialized json.
+        _ => output.push_str(content),
+    }
+}
+
+// text node: only chars which could open markup or an entity.
+pub(crate) fn escape_html_text(content: &str, output: &mut String) {
+    escape_with(content, output, |c| match c {
+        '&' => Some("&amp;"),
+        '<' => Some("&lt;"),
+        '>' => Some("&gt;"),
+        _ => None,
+    });
+}
+
+// quoted attribute value: quotes in addition to text chars.
+pub(crate) fn escape_html_attribute(content: &str, output: &mut String) {
+    escape_with(content, output, |c| match c {
+        '&' => Some("&amp;"),
+        '<' => Some("&lt;"),
+        '>' => Some("&gt;"),
+        '"' => Some("&quot;"),
+        '\'' => Some("&#x27;"),
+        '`' => Some("&#x60;"),
+        _ => None,
+    });
+}
+
+// unquoted attribute value: any ascii char other than alphanumerics could end the value.
+pub(crate) fn escape_html_unquoted_attribute(content: &str, output: &mut String) {
+    output.reserve(content.len());
+    for c in content.chars() {
+        if c.is_ascii() && !c.is_ascii_alphanumeric() && !matches!(c, ',' | '.' | '-' | '_') {
+            output.push_str(&format!("&#x{:02X};", c as u32));
+        } else {
+            output.push(c);
+        }
+    }
+}
+
+// json string literal: quotes, backslash and control chars.
+pub(crate) fn escape_json_string(content: &str, output: &mut String) {
+    output.reserve(content.len());
+    for c in content.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            '\u{08}' => output.push_str("\\b"),
+            '\u{0C}' => output.push_str("\\f"),
+            // line/paragraph separators are valid json but break javascript strings.
+            c if c.is_control() || c == '\u{2028}' || c == '\u{2029}' => {
+                output.push_str(&format!("\\u{:04x}", c as u32))
+            }
+            c => output.push(c),
+        }
+    }
+}
+
+fn escape_with<F: Fn(char) -> Option<&'static str>>(content: &str, output: &mut String, f: F) {
+    output.reserve(content.len());
+    let mut last = 0;
+    for (index, c) in content.char_indices() {
+        if let Some(entity) = f(c) {
+            output.push_str(&content[last..index]);
+            output.push_str(entity);
+            last = index + c.len_utf8();
+        }
+    }
+    output.push_str(&content[last..]);
+}
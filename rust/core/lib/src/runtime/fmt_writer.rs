@@ -0,0 +1,40 @@
+use crate::types::error::RuntimeError;
+use crate::types::{EscapeContext, FmtWriter, Writer, result};
+use std::fmt;
+
+impl<'a, W: Writer> FmtWriter<'a, W> {
+    pub fn new(writer: &'a mut W, context: Option<EscapeContext>) -> Self {
+        Self {
+            writer,
+            context,
+            error: None,
+        }
+    }
+
+    // called by `write!(FmtWriter::new(&mut writer, context), "{:.2}", value)?`.
+    pub fn write_fmt(mut self, args: fmt::Arguments<'_>) -> result::RenderResult<()> {
+        match fmt::Write::write_fmt(&mut self, args) {
+            Ok(()) => Ok(()),
+            // the error of the writer which stopped formatting, like non-finite number in json.
+            Err(_) => Err(self.error.unwrap_or_else(RuntimeError::format_failed)),
+        }
+    }
+}
+
+impl<W: Writer> fmt::Write for FmtWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        match self.context {
+            Some(context) => match self.writer.try_write_escaped(s, context) {
+                Ok(()) => Ok(()),
+                Err(err) => {
+                    self.error.get_or_insert(err);
+                    Err(fmt::Error)
+                }
+            },
+            None => {
+                self.writer.write(s);
+                Ok(())
+            }
+        }
+    }
+}
@@ -1,15 +1,22 @@
+use crate::runtime::escape;
+use crate::types::EscapeContext;
 use crate::types::HtmlWriter;
 use crate::types::Writer;
+use crate::types::template::Kind;
 
 impl Writer for HtmlWriter {
-    fn into_string(self) -> String {
-        self.content
-    }
-
     // TODO: do optimization here for html stream.
     fn write(&mut self, content: &str) {
         self.content.push_str(content);
     }
+
+    fn write_escaped(&mut self, content: &str, context: EscapeContext) {
+        escape::escape(Kind::KHTML, content, context, &mut self.content);
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.content.reserve(additional);
+    }
 }
 
 impl HtmlWriter {
@@ -18,4 +25,9 @@ impl HtmlWriter {
             content: String::new(),
         }
     }
+
+    // convert to String by consuming self.
+    pub fn into_string(self) -> String {
+        self.content
+    }
 }
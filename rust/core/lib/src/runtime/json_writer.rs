@@ -0,0 +1,61 @@
+use crate::runtime::escape;
+use crate::types::EscapeContext;
+use crate::types::JsonWriter;
+use crate::types::Writer;
+use crate::types::error::RuntimeError;
+use crate::types::result;
+use crate::types::template::Kind;
+
+impl Writer for JsonWriter {
+    fn write(&mut self, content: &str) {
+        self.content.push_str(content);
+    }
+
+    fn write_escaped(&mut self, content: &str, context: EscapeContext) {
+        escape::escape(Kind::KJSON, content, context, &mut self.content);
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.content.reserve(additional);
+    }
+
+    fn try_write_escaped(
+        &mut self,
+        content: &str,
+        context: EscapeContext,
+    ) -> result::RenderResult<()> {
+        validate_json_value(content, context)?;
+        self.write_escaped(content, context);
+        Ok(())
+    }
+}
+
+impl Default for JsonWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JsonWriter {
+    pub fn new() -> Self {
+        JsonWriter {
+            content: String::new(),
+        }
+    }
+
+    // convert to String by consuming self.
+    pub fn into_string(self) -> String {
+        self.content
+    }
+}
+
+// f32/f64 display NaN and infinity as below, which json could not represent.
+pub(crate) fn validate_json_value(
+    content: &str,
+    context: EscapeContext,
+) -> result::RenderResult<()> {
+    if context == EscapeContext::KJsonValue && matches!(content, "NaN" | "inf" | "-inf") {
+        return Err(RuntimeError::non_finite_number(content));
+    }
+    Ok(())
+}
@@ -1,19 +1,76 @@
+use crate::types::EscapeContext;
 use crate::types::KWriter;
 use crate::types::Writer;
+use crate::types::result;
+use crate::types::template::Kind;
+use crate::types::{HtmlWriter, JsonWriter};
 
 impl Writer for KWriter {
     fn write(&mut self, content: &str) {
         match self {
             KWriter::KHtml(writer) => writer.write(content),
-            KWriter::KJson(existing_content) => existing_content.write(content),
+            KWriter::KJson(writer) => writer.write(content),
             KWriter::KText(existing_content) => existing_content.write(content),
         }
     }
 
-    fn into_string(self) -> String {
+    fn write_escaped(&mut self, content: &str, context: EscapeContext) {
+        match self {
+            KWriter::KHtml(writer) => writer.write_escaped(content, context),
+            KWriter::KJson(writer) => writer.write_escaped(content, context),
+            KWriter::KText(existing_content) => existing_content.write_escaped(content, context),
+        }
+    }
+
+    fn try_write_escaped(
+        &mut self,
+        content: &str,
+        context: EscapeContext,
+    ) -> result::RenderResult<()> {
+        match self {
+            KWriter::KHtml(writer) => writer.try_write_escaped(content, context),
+            KWriter::KJson(writer) => writer.try_write_escaped(content, context),
+            KWriter::KText(existing_content) => {
+                existing_content.try_write_escaped(content, context)
+            }
+        }
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        match self {
+            KWriter::KHtml(writer) => writer.reserve(additional),
+            KWriter::KJson(writer) => writer.reserve(additional),
+            KWriter::KText(existing_content) => Writer::reserve(existing_content, additional),
+        }
+    }
+}
+
+impl KWriter {
+    pub fn new(kind: Kind) -> Self {
+        match kind {
+            Kind::KHTML => KWriter::KHtml(HtmlWriter::new()),
+            Kind::KJSON => KWriter::KJson(JsonWriter::new()),
+            Kind::KTEXT => KWriter::KText(String::new()),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            KWriter::KHtml(writer) => writer.content.len(),
+            KWriter::KJson(writer) => writer.content.len(),
+            KWriter::KText(content) => content.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // convert to String by consuming self.
+    pub fn into_string(self) -> String {
         match self {
             KWriter::KHtml(writer) => writer.into_string(),
-            KWriter::KJson(content) => content,
+            KWriter::KJson(writer) => writer.into_string(),
             KWriter::KText(content) => content,
         }
     }
@@ -1,6 +1,13 @@
 mod error;
+mod escape;
+mod fmt_writer;
 mod html_writer;
+mod json_writer;
 mod kwriter;
+mod render;
+#[cfg(feature = "stream")]
+mod render_stream;
+mod stream_writer;
 mod string_writer;
 
 #[cfg(test)]
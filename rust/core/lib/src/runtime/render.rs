@@ -0,0 +1,213 @@
+use crate::types::error::RuntimeError;
+use crate::types::{
+    EscapeContext, FmtWriter, Markup, Render, RenderDisplay, RenderIter, Writer, result,
+};
+use std::borrow::Cow;
+use std::rc::Rc;
+use std::sync::Arc;
+
+impl Render for str {
+    fn render_to(
+        &self,
+        writer: &mut impl Writer,
+        context: EscapeContext,
+    ) -> result::RenderResult<()> {
+        writer.try_write_escaped(self, context)
+    }
+}
+
+impl Render for String {
+    fn render_to(
+        &self,
+        writer: &mut impl Writer,
+        context: EscapeContext,
+    ) -> result::RenderResult<()> {
+        writer.try_write_escaped(self, context)
+    }
+}
+
+impl Render for Cow<'_, str> {
+    fn render_to(
+        &self,
+        writer: &mut impl Writer,
+        context: EscapeContext,
+    ) -> result::RenderResult<()> {
+        writer.try_write_escaped(self, context)
+    }
+}
+
+// already safe in markup, json still gets it escaped as a string.
+impl Render for Markup {
+    fn render_to(
+        &self,
+        writer: &mut impl Writer,
+        context: EscapeContext,
+    ) -> result::RenderResult<()> {
+        match context {
+            EscapeContext::KJsonString | EscapeContext::KJsonValue => {
+                writer.try_write_escaped(self.as_str(), context)
+            }
+            _ => {
+                writer.write(self.as_str());
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Render for bool {
+    fn render_to(
+        &self,
+        writer: &mut impl Writer,
+        context: EscapeContext,
+    ) -> result::RenderResult<()> {
+        writer.try_write_escaped(if *self { "true" } else { "false" }, context)
+    }
+}
+
+impl Render for char {
+    fn render_to(
+        &self,
+        writer: &mut impl Writer,
+        context: EscapeContext,
+    ) -> result::RenderResult<()> {
+        writer.try_write_escaped(self.encode_utf8(&mut [0; 4]), context)
+    }
+}
+
+// integers are formatted into the writer without a String.
+macro_rules! render_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl Render for $ty {
+                fn render_to(
+                    &self,
+                    writer: &mut impl Writer,
+                    context: EscapeContext,
+                ) -> result::RenderResult<()> {
+                    write!(FmtWriter::new(writer, Some(context)), "{}", self)
+                }
+            }
+        )*
+    };
+}
+
+render_integer!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+// json could not represent non-finite numbers.
+macro_rules! render_float {
+    ($($ty:ty),*) => {
+        $(
+            impl Render for $ty {
+                fn render_to(
+                    &self,
+                    writer: &mut impl Writer,
+                    context: EscapeContext,
+                ) -> result::RenderResult<()> {
+                    if context == EscapeContext::KJsonValue && !self.is_finite() {
+                        return Err(RuntimeError::non_finite_number(&self.to_string()));
+                    }
+                    write!(FmtWriter::new(writer, Some(context)), "{}", self)
+                }
+            }
+        )*
+    };
+}
+
+render_float!(f32, f64);
+
+// None is rendered as nothing like DisplayOption.
+impl<T: Render> Render for Option<T> {
+    fn render_to(
+        &self,
+        writer: &mut impl Writer,
+        context: EscapeContext,
+    ) -> result::RenderResult<()> {
+        match self {
+            Some(value) => value.render_to(writer, context),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Render> Render for [T] {
+    fn render_to(
+        &self,
+        writer: &mut impl Writer,
+        context: EscapeContext,
+    ) -> result::RenderResult<()> {
+        for item in self {
+            item.render_to(writer, context)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Render, const N: usize> Render for [T; N] {
+    fn render_to(
+        &self,
+        writer: &mut impl Writer,
+        context: EscapeContext,
+    ) -> result::RenderResult<()> {
+        self.as_slice().render_to(writer, context)
+    }
+}
+
+impl<T: Render> Render for Vec<T> {
+    fn render_to(
+        &self,
+        writer: &mut impl Writer,
+        context: EscapeContext,
+    ) -> result::RenderResult<()> {
+        self.as_slice().render_to(writer, context)
+    }
+}
+
+impl<I> Render for RenderIter<I>
+where
+    I: Iterator + Clone,
+    I::Item: Render,
+{
+    fn render_to(
+        &self,
+        writer: &mut impl Writer,
+        context: EscapeContext,
+    ) -> result::RenderResult<()> {
+        for item in self.0.clone() {
+            item.render_to(writer, context)?;
+        }
+        Ok(())
+    }
+}
+
+// formatted into the writer without a String.
+impl<T: std::fmt::Display> Render for RenderDisplay<T> {
+    fn render_to(
+        &self,
+        writer: &mut impl Writer,
+        context: EscapeContext,
+    ) -> result::RenderResult<()> {
+        write!(FmtWriter::new(writer, Some(context)), "{}", self.0)
+    }
+}
+
+// smart pointers and references render what they point to.
+macro_rules! render_deref {
+    ($($ty:ty),*) => {
+        $(
+            impl<T: Render + ?Sized> Render for $ty {
+                fn render_to(
+                    &self,
+                    writer: &mut impl Writer,
+                    context: EscapeContext,
+                ) -> result::RenderResult<()> {
+                    (**self).render_to(writer, context)
+                }
+            }
+        )*
+    };
+}
+
+render_deref!(&T, &mut T, Box<T>, Rc<T>, Arc<T>);
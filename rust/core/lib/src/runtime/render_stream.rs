@@ -0,0 +1,92 @@
+use crate::types::template::Kind;
+use crate::types::{ChannelSink, RenderStream, RenderTask, Sink, StreamWriter, result};
+use futures_channel::mpsc;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+// content is sent without `@flush` once the buffer grows over it.
+const CHUNK_SIZE: usize = 8 * 1024;
+
+// chunks sent but not received yet, the render waits for the receiver beyond it.
+const CHUNK_BOUND: usize = 4;
+
+impl RenderStream {
+    // runs the render by the spawner, the output written into the writer is received as chunks.
+    pub fn spawn<F>(kind: Kind, spawner: impl FnOnce(RenderTask), render: F) -> Self
+    where
+        F: FnOnce(&mut StreamWriter<ChannelSink>) -> result::RenderResult<()> + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel(CHUNK_BOUND);
+        spawner(Box::new(move || {
+            let mut error_sender = sender.clone();
+            let mut writer = StreamWriter::new(ChannelSink::new(sender), kind);
+            if let Err(err) = render(&mut writer).and_then(|_| writer.finish().map(|_| ())) {
+                // the receiver might be dropped already, like a closed connection.
+                let _ = send_blocking(&mut error_sender, Err(err));
+            }
+        }));
+        Self { receiver }
+    }
+}
+
+// wakes the render parked by a full channel.
+struct ThreadWaker(std::thread::Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+// parks the thread of the render until the receiver takes a chunk.
+fn send_blocking(
+    sender: &mut mpsc::Sender<result::RenderResult<bytes::Bytes>>,
+    item: result::RenderResult<bytes::Bytes>,
+) -> Result<(), String> {
+    let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    let dropped = |_| "the receiver of the stream is dropped".to_string();
+    loop {
+        match sender.poll_ready(&mut cx) {
+            Poll::Ready(Ok(())) => return sender.start_send(item).map_err(dropped),
+            Poll::Ready(Err(err)) => return Err(dropped(err)),
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+impl futures_core::Stream for RenderStream {
+    type Item = result::RenderResult<bytes::Bytes>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.receiver).poll_next(cx)
+    }
+}
+
+impl ChannelSink {
+    pub(crate) fn new(sender: mpsc::Sender<result::RenderResult<bytes::Bytes>>) -> Self {
+        Self {
+            sender,
+            buffer: Vec::with_capacity(CHUNK_SIZE),
+        }
+    }
+}
+
+impl Sink for ChannelSink {
+    fn write_str(&mut self, content: &str) -> Result<(), String> {
+        self.buffer.extend_from_slice(content.as_bytes());
+        if self.buffer.len() >= CHUNK_SIZE {
+            return self.flush();
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), String> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let chunk = std::mem::replace(&mut self.buffer, Vec::with_capacity(CHUNK_SIZE));
+        send_blocking(&mut self.sender, Ok(bytes::Bytes::from(chunk)))
+    }
+}
@@ -0,0 +1,95 @@
+use crate::runtime::{escape, json_writer};
+use crate::types::error::RuntimeError;
+use crate::types::template::Kind;
+use crate::types::{EscapeContext, FmtSink, IoSink, Sink, StreamWriter, Writer, result};
+
+impl<W: std::io::Write> Sink for IoSink<W> {
+    fn write_str(&mut self, content: &str) -> Result<(), String> {
+        self.0
+            .write_all(content.as_bytes())
+            .map_err(|e| e.to_string())
+    }
+
+    fn flush(&mut self) -> Result<(), String> {
+        self.0.flush().map_err(|e| e.to_string())
+    }
+}
+
+impl<W: std::fmt::Write> Sink for FmtSink<W> {
+    fn write_str(&mut self, content: &str) -> Result<(), String> {
+        self.0.write_str(content).map_err(|e| e.to_string())
+    }
+}
+
+impl<S: Sink> StreamWriter<S> {
+    pub fn new(sink: S, kind: Kind) -> Self {
+        Self {
+            sink,
+            kind,
+            buffer: String::new(),
+            error: None,
+        }
+    }
+
+    // flushes the sink and returns it, or the first error of it.
+    pub fn finish(mut self) -> result::RenderResult<S> {
+        Writer::flush(&mut self);
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(self.sink),
+        }
+    }
+}
+
+impl<W: std::io::Write> StreamWriter<IoSink<W>> {
+    pub fn from_io(writer: W, kind: Kind) -> Self {
+        Self::new(IoSink(writer), kind)
+    }
+}
+
+impl<W: std::fmt::Write> StreamWriter<FmtSink<W>> {
+    pub fn from_fmt(writer: W, kind: Kind) -> Self {
+        Self::new(FmtSink(writer), kind)
+    }
+}
+
+impl<S: Sink> Writer for StreamWriter<S> {
+    fn write(&mut self, content: &str) {
+        // nothing is written once the sink failed.
+        if self.error.is_some() {
+            return;
+        }
+        if let Err(err) = self.sink.write_str(content) {
+            self.error = Some(RuntimeError::write_failed(&err));
+        }
+    }
+
+    fn write_escaped(&mut self, content: &str, context: EscapeContext) {
+        let mut buffer = std::mem::take(&mut self.buffer);
+        buffer.clear();
+        escape::escape(self.kind, content, context, &mut buffer);
+        self.write(&buffer);
+        self.buffer = buffer;
+    }
+
+    fn flush(&mut self) {
+        if self.error.is_some() {
+            return;
+        }
+        if let Err(err) = self.sink.flush() {
+            self.error = Some(RuntimeError::write_failed(&err));
+        }
+    }
+
+    fn try_write_escaped(
+        &mut self,
+        content: &str,
+        context: EscapeContext,
+    ) -> result::RenderResult<()> {
+        if self.kind == Kind::KJSON {
+            json_writer::validate_json_value(content, context)?;
+        }
+        self.write_escaped(content, context);
+        Ok(())
+    }
+}
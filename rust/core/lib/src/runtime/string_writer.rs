@@ -1,11 +1,11 @@
 use crate::types::Writer;
 
 impl Writer for String {
-    fn into_string(self) -> String {
-        self
-    }
-
     fn write(&mut self, content: &str) {
         self.push_str(content);
     }
+
+    fn reserve(&mut self, additional: usize) {
+        String::reserve(self, additional);
+    }
 }
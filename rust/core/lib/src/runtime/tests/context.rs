@@ -1,5 +1,5 @@
 #![cfg(test)]
-use crate::types::{Context, DefaultViewContext};
+use crate::types::{ChildViewContext, Context, DefaultViewContext};
 
 #[test]
 fn default_context_state() {
@@ -49,3 +49,95 @@ fn default_context_default_section() {
     let default_section_content = default_section_content.unwrap();
     assert_eq!(default_section_content, "S2");
 }
+
+#[test]
+fn child_context_data_fallback() {
+    let mut context = DefaultViewContext::new();
+    context.set_data("name", || "parent".to_string());
+    context.set_data("age", || 1);
+
+    let mut child = ChildViewContext::new(&mut context);
+    child.set_data("name", || "child".to_string());
+    assert_eq!(child.get_data::<String>("name").unwrap(), "child");
+    assert_eq!(child.get_data::<i32>("age"), Some(&1));
+    assert!(child.get_data::<String>("age").is_none());
+    assert!(child.get_data::<i32>("missing").is_none());
+
+    // nested child contexts keep falling back.
+    let grandchild = ChildViewContext::new(&mut child);
+    assert_eq!(grandchild.get_data::<String>("name").unwrap(), "child");
+    assert_eq!(grandchild.get_data::<i32>("age"), Some(&1));
+
+    assert_eq!(context.get_data::<String>("name").unwrap(), "parent");
+}
+
+#[test]
+fn child_context_shares_sections() {
+    let mut context = DefaultViewContext::new();
+    context.set_default_section(String::from("body"));
+
+    let mut child = ChildViewContext::new(&mut context);
+    assert_eq!(child.get_default_section().unwrap(), "body");
+    child.add_section("scripts", String::from("S1"));
+
+    let sections = context.get_section("scripts");
+    assert!(sections.is_some());
+    assert!(sections.unwrap().contains(&String::from("S1")));
+}
+
+#[test]
+fn default_context_section_modes() {
+    let mut context = DefaultViewContext::new();
+    context.add_section("scripts", String::from("S1"));
+    context.add_section("scripts", String::from("S2"));
+    assert_eq!(context.get_section("scripts").unwrap(), &vec!["S1"]);
+
+    context.append_section("scripts", String::from("S3"));
+    context.prepend_section("scripts", String::from("S0"));
+    assert_eq!(
+        context.get_section("scripts").unwrap(),
+        &vec!["S0", "S1", "S3"]
+    );
+
+    context.replace_section("scripts", String::from("R"));
+    assert_eq!(context.get_section("scripts").unwrap(), &vec!["R"]);
+
+    context.prepend_section("styles", String::from("P"));
+    context.append_section("footer", String::from("A"));
+    context.replace_section("header", String::from("H"));
+    assert_eq!(context.get_section("styles").unwrap(), &vec!["P"]);
+    assert_eq!(context.get_section("footer").unwrap(), &vec!["A"]);
+    assert_eq!(context.get_section("header").unwrap(), &vec!["H"]);
+}
+
+#[test]
+fn child_context_section_modes() {
+    let mut context = DefaultViewContext::new();
+    context.add_section("scripts", String::from("S1"));
+
+    let mut child = ChildViewContext::new(&mut context);
+    child.append_section("scripts", String::from("S2"));
+    child.prepend_section("scripts", String::from("S0"));
+
+    assert_eq!(
+        context.get_section("scripts").unwrap(),
+        &vec!["S0", "S1", "S2"]
+    );
+}
+
+#[test]
+fn take_default_section() {
+    let mut context = DefaultViewContext::new();
+    assert!(context.take_default_section().is_none());
+
+    context.set_default_section(String::from("page"));
+    let page = context.take_default_section();
+    context.set_default_section(String::from("layout"));
+    assert_eq!(context.get_default_section().unwrap(), "layout");
+    context.set_default_section(page.unwrap());
+    assert_eq!(context.get_default_section().unwrap(), "page");
+
+    let mut child = ChildViewContext::new(&mut context);
+    assert_eq!(child.take_default_section().unwrap(), "page");
+    assert!(context.get_default_section().is_none());
+}
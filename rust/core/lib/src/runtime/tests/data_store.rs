@@ -38,3 +38,14 @@ fn custom_type() {
         })
     );
 }
+
+#[test]
+fn take() {
+    let mut store = DataStore::<String>::new();
+    store.set("k1", "v1".to_string());
+
+    let value = store.take("k1").and_then(|v| v.downcast::<String>().ok());
+    assert_eq!(value.as_deref(), Some(&"v1".to_string()));
+    assert!(store.take("k1").is_none());
+    assert!(store.set("k1", 1));
+}
@@ -17,3 +17,63 @@ fn layout_not_found() {
         "View:test, NotFound: Layout 'layout' not found for View `test`"
     );
 }
+
+#[test]
+fn partial_not_found() {
+    let error = RuntimeError::partial_not_found("row", "test");
+    let err_msg = error.to_string();
+    assert_eq!(
+        err_msg,
+        "View:test, NotFound: Partial 'row' not found for View `test`"
+    );
+}
+
+#[test]
+fn service_not_found() {
+    let error = RuntimeError::service_not_found("urls", "test");
+    let err_msg = error.to_string();
+    assert_eq!(
+        err_msg,
+        "View:test, NotFound: Service 'urls' not found for View `test`"
+    );
+}
+
+#[test]
+fn model_not_found() {
+    let error = RuntimeError::model_not_found("String", "test");
+    let err_msg = error.to_string();
+    assert_eq!(
+        err_msg,
+        "View:test, NotFound: Model 'String' not found for View `test`"
+    );
+}
+
+#[test]
+fn non_finite_number() {
+    let error = RuntimeError::non_finite_number("NaN");
+    let err_msg = error.to_string();
+    assert_eq!(
+        err_msg,
+        "Json, InvalidValue: Non-finite number 'NaN' is not valid json"
+    );
+}
+
+#[test]
+fn write_failed() {
+    let error = RuntimeError::write_failed("broken pipe");
+    let err_msg = error.to_string();
+    assert_eq!(
+        err_msg,
+        "Writer, WriteFailed: Failed to write the output: broken pipe"
+    );
+}
+
+#[test]
+fn async_view() {
+    let error = RuntimeError::async_view("test");
+    let err_msg = error.to_string();
+    assert_eq!(
+        err_msg,
+        "View:test, Unsupported: View `test` awaits while rendering, use `render_async` instead"
+    );
+}
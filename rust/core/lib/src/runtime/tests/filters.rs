@@ -0,0 +1,56 @@
+#![cfg(test)]
+use crate::types::filters;
+use std::time::{Duration, UNIX_EPOCH};
+
+#[test]
+fn case_and_trim() {
+    assert_eq!(filters::upper("abc"), "ABC");
+    assert_eq!(filters::lower(&"AbC".to_string()), "abc");
+    assert_eq!(filters::trim(" a b \n"), "a b");
+    assert_eq!(filters::upper(&1), "1");
+}
+
+#[test]
+fn truncate() {
+    assert_eq!(filters::truncate("hello world", 5), "hello...");
+    assert_eq!(filters::truncate("hello", 5), "hello");
+    assert_eq!(filters::truncate("你好世界", 2), "你好...");
+}
+
+#[test]
+fn default() {
+    assert_eq!(filters::default(&None::<i32>, "none"), "none");
+    assert_eq!(filters::default(&Some(1), "none"), "1");
+    assert_eq!(filters::default("", "empty"), "empty");
+    assert_eq!(filters::default(&"a", "empty"), "a");
+    assert_eq!(filters::default(&String::new(), "empty"), "empty");
+}
+
+#[test]
+fn join() {
+    assert_eq!(filters::join(&vec!["a", "b"], ", "), "a, b");
+    assert_eq!(filters::join(&[1, 2, 3][..], "-"), "1-2-3");
+    assert_eq!(filters::join(&Vec::<String>::new(), "-"), "");
+}
+
+#[test]
+fn pluralize() {
+    assert_eq!(filters::pluralize(&1, "item", "items"), "item");
+    assert_eq!(filters::pluralize(&0usize, "item", "items"), "items");
+    assert_eq!(filters::pluralize(&2u8, "item", "items"), "items");
+}
+
+#[test]
+fn date() {
+    assert_eq!(
+        filters::date(&0i64, "%Y-%m-%d %H:%M:%S"),
+        "1970-01-01 00:00:00"
+    );
+    assert_eq!(
+        filters::date(&1709210096u64, "%d/%m/%Y %H:%M:%S %%"),
+        "29/02/2024 12:34:56 %"
+    );
+    assert_eq!(filters::date(&-86400i64, "%Y-%m-%d %q"), "1969-12-31 %q");
+    let time = UNIX_EPOCH + Duration::from_secs(951782400);
+    assert_eq!(filters::date(&time, "%Y-%m-%d"), "2000-02-29");
+}
@@ -0,0 +1,20 @@
+#![cfg(test)]
+use crate::types::ForLoop;
+
+#[test]
+fn for_loop_first_and_last() {
+    let forloop = ForLoop::new(0, false);
+    assert!(forloop.first);
+    assert!(!forloop.last);
+
+    let forloop = ForLoop::new(2, true);
+    assert_eq!(forloop.index, 2);
+    assert!(!forloop.first);
+    assert!(forloop.last);
+}
+
+#[test]
+fn for_loop_separator() {
+    assert_eq!(ForLoop::new(0, false).separator(", "), ", ");
+    assert_eq!(ForLoop::new(1, true).separator(", "), "");
+}
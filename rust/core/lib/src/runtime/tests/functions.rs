@@ -1,5 +1,6 @@
 #![cfg(test)]
 use crate::types;
+use crate::types::Context as _;
 
 #[test]
 fn normalize_path_to_key() {
@@ -46,3 +47,116 @@ fn resolve_layout_to_view_keys() {
         vec![String::from("test1/p/layout"), String::from("p/layout")]
     );
 }
+
+#[test]
+fn take_and_set_model() {
+    let mut context = types::DefaultViewContext::new();
+    assert!(types::take_model::<String>(&mut context, "View").is_err());
+
+    types::set_model(&mut context, 1);
+    // the data of other types is kept.
+    assert!(types::take_model::<String>(&mut context, "View").is_err());
+    assert_eq!(context.get_data::<i32>(types::MODEL_DATA_KEY), Some(&1));
+
+    types::set_model(&mut context, "model".to_string());
+    assert!(context.get_data::<i32>(types::MODEL_DATA_KEY).is_none());
+    let model = types::take_model::<String>(&mut context, "View").unwrap();
+    assert_eq!(model, "model");
+    assert!(context.get_data_any(types::MODEL_DATA_KEY).is_none());
+
+    // taken from the parent of a child context.
+    types::set_model(&mut context, 2);
+    let mut child = types::ChildViewContext::new(&mut context);
+    assert_eq!(types::take_model::<i32>(&mut child, "View").unwrap(), 2);
+    assert!(context.get_data_any(types::MODEL_DATA_KEY).is_none());
+}
+
+#[test]
+fn lend_and_restore_model() {
+    let mut context = types::DefaultViewContext::new();
+    types::set_model(&mut context, "model".to_string());
+    let (model, taken) = types::lend_model::<String>(&mut context, "View").unwrap();
+    assert!(taken);
+    {
+        // seen by the views rendered in the same context and shared with the ones of the same type.
+        let mut lent = types::ModelViewContext::new(&mut context, model.clone());
+        assert_eq!(
+            lent.get_data::<String>(types::MODEL_DATA_KEY),
+            Some(&"model".to_string())
+        );
+        assert!(lent.take_data_any(types::MODEL_DATA_KEY).is_none());
+        let (shared, taken) = types::lend_model::<String>(&mut lent, "Partial").unwrap();
+        assert!(!taken);
+        assert_eq!(*shared, "model");
+        assert!(types::lend_model::<i32>(&mut lent, "Partial").is_err());
+
+        // the data of a partial is its own model.
+        let mut child = types::ChildViewContext::new(&mut lent);
+        child.set_data(types::PARTIAL_DATA_KEY, || 1);
+        assert_eq!(
+            *types::lend_model::<i32>(&mut child, "Partial").unwrap().0,
+            1
+        );
+
+        // data is set in the parent.
+        lent.set_data("title", || "title".to_string());
+    }
+    assert!(context.get_data_any(types::MODEL_DATA_KEY).is_none());
+    types::restore_model(&mut context, model, taken);
+    assert_eq!(
+        context.get_data::<String>(types::MODEL_DATA_KEY),
+        Some(&"model".to_string())
+    );
+    assert_eq!(
+        context.get_data::<String>("title"),
+        Some(&"title".to_string())
+    );
+}
+
+#[test]
+fn take_and_set_layout() {
+    let mut context = types::DefaultViewContext::new();
+    assert_eq!(types::take_layout(&mut context), None);
+
+    types::set_layout(&mut context, Some("print"));
+    assert_eq!(
+        types::take_layout(&mut context),
+        Some(Some("print".to_string()))
+    );
+    // taken once.
+    assert_eq!(types::take_layout(&mut context), None);
+
+    types::set_layout(&mut context, Some("print"));
+    types::set_layout(&mut context, None);
+    assert_eq!(types::take_layout(&mut context), Some(None));
+
+    // the data of the app named `layout` isn't taken.
+    context.set_data("layout", || "embed".to_string());
+    assert_eq!(types::take_layout(&mut context), None);
+    assert_eq!(
+        context.get_data::<String>("layout"),
+        Some(&"embed".to_string())
+    );
+
+    // the data of other types is kept.
+    context.set_data(types::LAYOUT_DATA_KEY, || 1);
+    assert_eq!(types::take_layout(&mut context), None);
+    assert_eq!(context.get_data::<i32>(types::LAYOUT_DATA_KEY), Some(&1));
+}
+
+#[test]
+fn inject() {
+    let mut context = types::DefaultViewContext::new();
+    context.set_data("urls", || "https://sbolt.dev".to_string());
+    let urls = types::inject::<String>(&context, "urls", "View").unwrap();
+    assert_eq!(urls, "https://sbolt.dev");
+    // still in the context.
+    assert!(context.get_data::<String>("urls").is_some());
+
+    let err = types::inject::<i32>(&context, "urls", "View").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "View:View, NotFound: Service 'urls' not found for View `View`"
+    );
+    assert!(types::inject::<String>(&context, "flags", "View").is_err());
+}
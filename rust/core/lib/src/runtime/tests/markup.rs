@@ -0,0 +1,49 @@
+#![cfg(test)]
+use crate::types::{
+    EscapeContext, HtmlSafe, HtmlWriter, Markup, Output, WriteMarkup as _, WriteRender as _, result,
+};
+
+struct Badge(&'static str);
+impl std::fmt::Display for Badge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<span class=\"badge\">{}</span>", self.0)
+    }
+}
+impl HtmlSafe for Badge {}
+
+#[test]
+fn markup_from_str() {
+    let markup = Markup::from("<b>bold</b>");
+    assert_eq!(markup.as_str(), "<b>bold</b>");
+    assert_eq!(markup.to_string(), "<b>bold</b>");
+    assert_eq!(markup.into_string(), "<b>bold</b>");
+}
+
+#[test]
+fn output_markup_not_escaped() -> result::RenderResult<()> {
+    let markup = Markup::new("<b>bold</b>");
+    let mut writer = HtmlWriter::new();
+    (&&&Output(&markup)).write_to(&mut writer, EscapeContext::KText)?;
+    let markup_ref = &markup;
+    (&&&Output(&markup_ref)).write_to(&mut writer, EscapeContext::KText)?;
+    assert_eq!(writer.into_string(), "<b>bold</b><b>bold</b>");
+    Ok(())
+}
+
+#[test]
+fn output_html_safe_not_escaped() -> result::RenderResult<()> {
+    let mut writer = HtmlWriter::new();
+    (&&&Output(&Badge("new"))).write_to(&mut writer, EscapeContext::KText)?;
+    assert_eq!(writer.into_string(), "<span class=\"badge\">new</span>");
+    Ok(())
+}
+
+#[test]
+fn output_display_escaped() -> result::RenderResult<()> {
+    let content = "<b>bold</b>";
+    let mut writer = HtmlWriter::new();
+    (&&&Output(&content)).write_to(&mut writer, EscapeContext::KText)?;
+    (&&&Output(&1)).write_to(&mut writer, EscapeContext::KText)?;
+    assert_eq!(writer.into_string(), "&lt;b&gt;bold&lt;/b&gt;1");
+    Ok(())
+}
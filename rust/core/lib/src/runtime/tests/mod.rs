@@ -2,7 +2,11 @@
 mod context;
 mod data_store;
 mod error;
+mod filters;
+mod for_loop;
 mod functions;
+mod markup;
 mod option;
+mod render;
 mod template;
 mod writer;
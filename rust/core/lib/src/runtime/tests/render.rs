@@ -0,0 +1,118 @@
+#![cfg(test)]
+use crate::types::error::RuntimeError;
+use crate::types::{
+    EscapeContext, HtmlWriter, JsonWriter, Markup, Output, Render, RenderDisplay, RenderIter,
+    WriteDisplay as _, WriteRender as _, Writer, result,
+};
+
+struct Stars(u8);
+impl Render for Stars {
+    fn render_to(
+        &self,
+        writer: &mut impl Writer,
+        _context: EscapeContext,
+    ) -> result::RenderResult<()> {
+        for _ in 0..self.0 {
+            writer.write("*");
+        }
+        Ok(())
+    }
+}
+
+struct Point(i32, i32);
+impl std::fmt::Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<{}, {}>", self.0, self.1)
+    }
+}
+
+#[test]
+fn render_primitives() -> result::RenderResult<()> {
+    let mut writer = HtmlWriter::new();
+    42u8.render_to(&mut writer, EscapeContext::KText)?;
+    (-7i64).render_to(&mut writer, EscapeContext::KText)?;
+    1.5f64.render_to(&mut writer, EscapeContext::KText)?;
+    true.render_to(&mut writer, EscapeContext::KText)?;
+    '<'.render_to(&mut writer, EscapeContext::KText)?;
+    assert_eq!(writer.into_string(), "42-71.5true&lt;");
+    Ok(())
+}
+
+#[test]
+fn render_strings_escaped() -> result::RenderResult<()> {
+    let mut writer = HtmlWriter::new();
+    "<b>".render_to(&mut writer, EscapeContext::KText)?;
+    "\"a\""
+        .to_string()
+        .render_to(&mut writer, EscapeContext::KAttribute)?;
+    std::borrow::Cow::Borrowed("&").render_to(&mut writer, EscapeContext::KText)?;
+    assert_eq!(writer.into_string(), "&lt;b&gt;&quot;a&quot;&amp;");
+    Ok(())
+}
+
+#[test]
+fn render_markup() -> result::RenderResult<()> {
+    let mut writer = HtmlWriter::new();
+    Markup::new("<b>bold</b>").render_to(&mut writer, EscapeContext::KText)?;
+    assert_eq!(writer.into_string(), "<b>bold</b>");
+
+    // json still gets it escaped.
+    let mut writer = JsonWriter::new();
+    Markup::new("\"a\"").render_to(&mut writer, EscapeContext::KJsonString)?;
+    assert_eq!(writer.into_string(), "\\\"a\\\"");
+    Ok(())
+}
+
+#[test]
+fn render_option_and_collections() -> result::RenderResult<()> {
+    let mut writer = HtmlWriter::new();
+    Some(1).render_to(&mut writer, EscapeContext::KText)?;
+    None::<&str>.render_to(&mut writer, EscapeContext::KText)?;
+    vec!["a", "<b>"].render_to(&mut writer, EscapeContext::KText)?;
+    [1, 2].render_to(&mut writer, EscapeContext::KText)?;
+    let tags = ["x", "y"];
+    RenderIter(tags.iter().map(|tag| tag.len())).render_to(&mut writer, EscapeContext::KText)?;
+    std::sync::Arc::new(Stars(2)).render_to(&mut writer, EscapeContext::KText)?;
+    assert_eq!(writer.into_string(), "1a&lt;b&gt;1211**");
+    Ok(())
+}
+
+#[test]
+fn render_display_in_option_and_collections() -> result::RenderResult<()> {
+    let mut writer = HtmlWriter::new();
+    Some(RenderDisplay(Point(1, 2))).render_to(&mut writer, EscapeContext::KText)?;
+    None::<RenderDisplay<Point>>.render_to(&mut writer, EscapeContext::KText)?;
+    let points = vec![Point(3, 4), Point(5, 6)];
+    points
+        .iter()
+        .map(RenderDisplay)
+        .collect::<Vec<_>>()
+        .render_to(&mut writer, EscapeContext::KText)?;
+    RenderIter(points.iter().map(RenderDisplay))
+        .render_to(&mut writer, EscapeContext::KAttribute)?;
+    assert_eq!(
+        writer.into_string(),
+        "&lt;1, 2&gt;&lt;3, 4&gt;&lt;5, 6&gt;&lt;3, 4&gt;&lt;5, 6&gt;"
+    );
+    Ok(())
+}
+
+#[test]
+fn render_non_finite_number_in_json() {
+    let mut writer = JsonWriter::new();
+    let result = f64::NAN.render_to(&mut writer, EscapeContext::KJsonValue);
+    assert!(matches!(result, Err(RuntimeError::InvalidValue(_, _))));
+    let result = f32::INFINITY.render_to(&mut writer, EscapeContext::KJsonString);
+    assert!(result.is_ok());
+    assert_eq!(writer.into_string(), "inf");
+}
+
+#[test]
+fn output_render_before_display() -> result::RenderResult<()> {
+    // Render types don't need Display, other Display types are formatted into the writer.
+    let mut writer = HtmlWriter::new();
+    (&&&Output(&Stars(3))).write_to(&mut writer, EscapeContext::KText)?;
+    (&&&Output(&Point(1, 2))).write_to(&mut writer, EscapeContext::KText)?;
+    assert_eq!(writer.into_string(), "***&lt;1, 2&gt;");
+    Ok(())
+}
@@ -13,10 +13,11 @@ impl Template for TestTemplate {
         todo!()
     }
 
-    fn render(
+    fn render_to(
         &self,
         _context: &mut impl crate::types::Context,
-    ) -> crate::types::result::RenderResult<String> {
+        _writer: &mut impl crate::types::Writer,
+    ) -> crate::types::result::RenderResult<()> {
         todo!()
     }
 }
@@ -1,5 +1,6 @@
 #![cfg(test)]
-use crate::types::{HtmlWriter, KWriter, Writer};
+use crate::types::error::RuntimeError;
+use crate::types::{EscapeContext, HtmlWriter, JsonWriter, KWriter, Writer, result};
 
 #[test]
 fn html_writer() {
@@ -30,10 +31,7 @@ fn string_writer() {
     string_writer.write(content);
     string_writer.writeln("");
     string_writer.writefn(|| "test".to_string());
-    assert_eq!(
-        string_writer.into_string(),
-        format!("{}\n{}", content, "test")
-    );
+    assert_eq!(string_writer, format!("{}\n{}", content, "test"));
 }
 
 #[test]
@@ -48,8 +46,175 @@ fn string_kwriter() {
 #[test]
 fn json_kwriter() {
     let content = "hell world!";
-    let string_writer = String::new();
-    let mut kwriter = KWriter::KJson(string_writer);
+    let json_writer = JsonWriter::new();
+    let mut kwriter = KWriter::KJson(json_writer);
     kwriter.write(content);
     assert_eq!(kwriter.into_string(), content)
 }
+
+#[test]
+fn json_writer_escaped_string() {
+    let mut json_writer = JsonWriter::new();
+    json_writer.write("\"");
+    json_writer.write_escaped("say \"hi\"\\\n\t\u{1}\u{2028}é", EscapeContext::KJsonString);
+    json_writer.write("\"");
+    assert_eq!(
+        json_writer.into_string(),
+        "\"say \\\"hi\\\"\\\\\\n\\t\\u0001\\u2028é\""
+    );
+}
+
+#[test]
+fn json_writer_value() -> result::RenderResult<()> {
+    let mut json_writer = JsonWriter::new();
+    json_writer.try_write_escaped(&1.5f64.to_string(), EscapeContext::KJsonValue)?;
+    json_writer.write(",");
+    json_writer.try_write_escaped("</script>", EscapeContext::KJsonString)?;
+    assert_eq!(json_writer.into_string(), "1.5,</script>");
+    Ok(())
+}
+
+#[test]
+fn json_writer_reject_non_finite() {
+    for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        let mut kwriter = KWriter::KJson(JsonWriter::new());
+        let result = kwriter.try_write_escaped(&value.to_string(), EscapeContext::KJsonValue);
+        assert!(matches!(result, Err(RuntimeError::InvalidValue(_, _))));
+    }
+
+    // in string it's just text.
+    let mut json_writer = JsonWriter::new();
+    let result = json_writer.try_write_escaped(&f64::NAN.to_string(), EscapeContext::KJsonString);
+    assert!(result.is_ok());
+    assert_eq!(json_writer.into_string(), "NaN");
+}
+
+#[test]
+fn html_writer_escaped_text() {
+    let mut html_writer = HtmlWriter::new();
+    html_writer.write_escaped("<b>\"Tom\" & 'Jerry'</b>", EscapeContext::KText);
+    assert_eq!(
+        html_writer.into_string(),
+        "&lt;b&gt;\"Tom\" &amp; 'Jerry'&lt;/b&gt;"
+    );
+}
+
+#[test]
+fn html_writer_escaped_attribute() {
+    let mut html_writer = HtmlWriter::new();
+    html_writer.write_escaped("\" onclick='alert(1)'", EscapeContext::KAttribute);
+    assert_eq!(
+        html_writer.into_string(),
+        "&quot; onclick=&#x27;alert(1)&#x27;"
+    );
+}
+
+#[test]
+fn html_writer_escaped_unquoted_attribute() {
+    let mut html_writer = HtmlWriter::new();
+    html_writer.write_escaped("a b=c.d-é", EscapeContext::KUnquotedAttribute);
+    assert_eq!(html_writer.into_string(), "a&#x20;b&#x3D;c.d-é");
+}
+
+#[test]
+fn text_kwriter_escaped() {
+    let content = "<b>\"hello\"</b>";
+    let mut kwriter = KWriter::KText(String::new());
+    kwriter.write_escaped(content, EscapeContext::KText);
+    assert_eq!(kwriter.into_string(), content)
+}
+
+#[test]
+fn fmt_writer_escaped() -> result::RenderResult<()> {
+    use crate::types::FmtWriter;
+    let mut html_writer = HtmlWriter::new();
+    write!(
+        FmtWriter::new(&mut html_writer, Some(EscapeContext::KText)),
+        "{:>6}|{:?}",
+        "<b>",
+        vec!["&"]
+    )?;
+    write!(FmtWriter::new(&mut html_writer, None), "{:.2}", 1.005f32)?;
+    assert_eq!(html_writer.into_string(), "   &lt;b&gt;|[\"&amp;\"]1.00");
+    Ok(())
+}
+
+#[test]
+fn fmt_writer_json_non_finite() {
+    use crate::types::FmtWriter;
+    let mut json_writer = JsonWriter::new();
+    let result = write!(
+        FmtWriter::new(&mut json_writer, Some(EscapeContext::KJsonValue)),
+        "{:.2}",
+        f64::NAN
+    );
+    assert!(matches!(result, Err(RuntimeError::InvalidValue(_, _))));
+}
+
+#[test]
+fn stream_writer_io() -> result::RenderResult<()> {
+    use crate::types::{StreamWriter, template::Kind};
+    let mut output = Vec::<u8>::new();
+    let mut writer = StreamWriter::from_io(&mut output, Kind::KHTML);
+    writer.write("<a href=\"");
+    writer.write_escaped("\"x\"", EscapeContext::KAttribute);
+    writer.write("\">");
+    writer.write_escaped("<b>", EscapeContext::KText);
+    writer.write("</a>");
+    writer.finish()?;
+    assert_eq!(
+        String::from_utf8_lossy(&output),
+        "<a href=\"&quot;x&quot;\">&lt;b&gt;</a>"
+    );
+    Ok(())
+}
+
+#[test]
+fn stream_writer_fmt() -> result::RenderResult<()> {
+    use crate::types::{StreamWriter, template::Kind};
+    // written through `&mut impl Writer` like views do.
+    fn write_json(mut writer: impl Writer) -> result::RenderResult<()> {
+        writer.write("\"");
+        writer.try_write_escaped("a\"b", EscapeContext::KJsonString)?;
+        writer.write("\"");
+        writer.flush();
+        writer.try_write_escaped("NaN", EscapeContext::KJsonValue)
+    }
+    let mut writer = StreamWriter::from_fmt(String::new(), Kind::KJSON);
+    assert!(matches!(
+        write_json(&mut writer),
+        Err(RuntimeError::InvalidValue(_, _))
+    ));
+    assert_eq!(writer.finish()?.0, "\"a\\\"b\"");
+    Ok(())
+}
+
+#[test]
+fn stream_writer_sink_failed() {
+    use crate::types::{StreamWriter, template::Kind};
+    let mut output = [0u8; 4];
+    let mut writer = StreamWriter::from_io(&mut output[..], Kind::KTEXT);
+    writer.write("abc");
+    writer.write("def");
+    writer.write("g");
+    let result = writer.finish();
+    assert!(matches!(result, Err(RuntimeError::WriteFailed(_, _))));
+    assert_eq!(&output, b"abcd");
+}
+
+#[test]
+fn kwriter_reserve() {
+    let mut kwriter = KWriter::new(crate::types::template::Kind::KHTML);
+    kwriter.reserve(1024);
+    assert!(kwriter.is_empty());
+    kwriter.write("test");
+    assert_eq!(kwriter.len(), 4);
+    let KWriter::KHtml(writer) = &kwriter else {
+        panic!("Expect html writer here");
+    };
+    assert!(writer.content.capacity() >= 1024);
+
+    let mut string_writer = String::new();
+    Writer::reserve(&mut string_writer, 1024);
+    assert!(string_writer.capacity() >= 1024);
+}
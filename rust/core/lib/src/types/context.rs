@@ -1,7 +1,28 @@
 use crate::types::DataStore;
+use std::any::Any;
 use std::collections::HashMap;
+use std::sync::Arc;
 
-pub trait Context {
+/// The key of the typed model declared by `@model`.
+pub const MODEL_DATA_KEY: &str = "model";
+
+/// The key of the `LayoutOverride` of the rendered view, namespaced so the data of the app isn't taken.
+pub const LAYOUT_DATA_KEY: &str = "sbolt::layout";
+
+/// The layout overriding the `@layout` of the rendered view, `None` renders it without the layout.
+pub struct LayoutOverride(pub Option<String>);
+
+/// The prefix of the keys of the bodies of views not rendered yet by their layouts.
+pub const PENDING_BODY_DATA_KEY: &str = "sbolt::body::";
+
+/// The key of the mark of a view rendered by `@partial`, it's rendered without the layout of its `_viewstart`.
+pub const PARTIAL_RENDER_DATA_KEY: &str = "sbolt::partial";
+
+/// The key of the data passed to `@partial(path, data)` in the child context, it's the model of the partial.
+pub const PARTIAL_DATA_KEY: &str = MODEL_DATA_KEY;
+
+// `Send` as the data is, so the future of `render_async` could be moved between threads.
+pub trait Context: Send {
     fn set_data<T, F>(&mut self, key: &str, f: F)
     where
         F: FnOnce() -> T,
@@ -16,6 +37,66 @@ pub trait Context {
     fn get_section_mut(&mut self, name: &str) -> Option<&mut Vec<String>>;
     fn get_default_section(&self) -> Option<&String>;
     fn set_default_section(&mut self, content: String) -> &mut Self;
+
+    /// removes the body rendered by `@render`, so a nested layout doesn't lose the one of its view.
+    fn take_default_section(&mut self) -> Option<String> {
+        self.get_section_mut("default")
+            .and_then(|defaults| defaults.pop())
+    }
+
+    /// adds the content after the ones of the section, `@section name append {}`.
+    fn append_section(&mut self, name: &str, content: String) -> &mut Self {
+        match self.get_section_mut(name) {
+            Some(contents) => contents.push(content),
+            None => {
+                self.add_section(name, content);
+            }
+        }
+        self
+    }
+
+    /// adds the content before the ones of the section, `@section name prepend {}`.
+    fn prepend_section(&mut self, name: &str, content: String) -> &mut Self {
+        match self.get_section_mut(name) {
+            Some(contents) => contents.insert(0, content),
+            None => {
+                self.add_section(name, content);
+            }
+        }
+        self
+    }
+
+    /// drops the contents added to the section before, `@section name replace {}`.
+    fn replace_section(&mut self, name: &str, content: String) -> &mut Self {
+        match self.get_section_mut(name) {
+            Some(contents) => {
+                contents.clear();
+                contents.push(content);
+            }
+            None => {
+                self.add_section(name, content);
+            }
+        }
+        self
+    }
+
+    /// type erased lookup, used by `ChildViewContext` to fall back to the parent's data.
+    fn get_data_any(&self, _key: &str) -> Option<&dyn Any> {
+        None
+    }
+
+    /// removes the data, used to move the `@model` out of the context while the view is rendered.
+    fn take_data_any(&mut self, _key: &str) -> Option<Box<dyn Any>> {
+        None
+    }
+
+    /// type erased `set_data`, used by `ModelViewContext` to set the data in the parent.
+    fn set_data_any(&mut self, _key: &str, _value: Box<dyn Any + Send + Sync>) {}
+
+    /// the `@model` lent by the view rendering in this context, the views it renders in the same context share it.
+    fn lent_model(&self) -> Option<Arc<dyn Any + Send + Sync>> {
+        None
+    }
 }
 
 pub struct DefaultViewContext {
@@ -23,6 +104,10 @@ pub struct DefaultViewContext {
     sections: HashMap<String, Vec<String>>,
 }
 
+// SAFETY: data is only set by `set_data` which takes `Send + Sync` values and sections are strings,
+// so the context could be moved to the thread of a RenderStream.
+unsafe impl Send for DefaultViewContext {}
+
 impl DefaultViewContext {
     pub fn new() -> Self {
         Self {
@@ -48,6 +133,18 @@ impl Context for DefaultViewContext {
         self.state.get(key)
     }
 
+    fn get_data_any(&self, key: &str) -> Option<&dyn Any> {
+        self.state.get_any(key)
+    }
+
+    fn take_data_any(&mut self, key: &str) -> Option<Box<dyn Any>> {
+        self.state.take(key)
+    }
+
+    fn set_data_any(&mut self, key: &str, value: Box<dyn Any + Send + Sync>) {
+        self.state.set_any(key, value);
+    }
+
     fn add_section(&mut self, name: &str, content: String) -> &mut Self {
         self.sections
             .entry(name.to_owned())
@@ -83,3 +180,221 @@ impl Context for DefaultViewContext {
         self
     }
 }
+
+/// Object safe view of a `Context`, so nested child contexts don't create new types.
+#[doc(hidden)]
+pub trait ParentContext: Send {
+    fn data(&self, key: &str) -> Option<&dyn Any>;
+    fn take(&mut self, key: &str) -> Option<Box<dyn Any>>;
+    fn put_data(&mut self, key: &str, value: Box<dyn Any + Send + Sync>);
+    fn model(&self) -> Option<Arc<dyn Any + Send + Sync>>;
+    fn put_section(&mut self, name: &str, content: String);
+    fn section(&self, name: &str) -> Option<&Vec<String>>;
+    fn section_mut(&mut self, name: &str) -> Option<&mut Vec<String>>;
+    fn default_section(&self) -> Option<&String>;
+    fn put_default_section(&mut self, content: String);
+}
+
+impl<C: Context> ParentContext for C {
+    fn data(&self, key: &str) -> Option<&dyn Any> {
+        Context::get_data_any(self, key)
+    }
+
+    fn take(&mut self, key: &str) -> Option<Box<dyn Any>> {
+        Context::take_data_any(self, key)
+    }
+
+    fn put_data(&mut self, key: &str, value: Box<dyn Any + Send + Sync>) {
+        Context::set_data_any(self, key, value);
+    }
+
+    fn model(&self) -> Option<Arc<dyn Any + Send + Sync>> {
+        Context::lent_model(self)
+    }
+
+    fn put_section(&mut self, name: &str, content: String) {
+        Context::add_section(self, name, content);
+    }
+
+    fn section(&self, name: &str) -> Option<&Vec<String>> {
+        Context::get_section(self, name)
+    }
+
+    fn section_mut(&mut self, name: &str) -> Option<&mut Vec<String>> {
+        Context::get_section_mut(self, name)
+    }
+
+    fn default_section(&self) -> Option<&String> {
+        Context::get_default_section(self)
+    }
+
+    fn put_default_section(&mut self, content: String) {
+        Context::set_default_section(self, content);
+    }
+}
+
+/// The context of a partial view, data is looked up in its own store first and then in the parent,
+/// sections are shared with the parent.
+pub struct ChildViewContext<'a> {
+    parent: &'a mut dyn ParentContext,
+    state: DataStore<Box<dyn Send + Sync>>,
+}
+
+// SAFETY: the parent is a `Send` context and data is only set by `set_data` which takes `Send + Sync` values,
+// so a partial could be awaited in `render_async`.
+unsafe impl Send for ChildViewContext<'_> {}
+
+impl<'a> ChildViewContext<'a> {
+    pub fn new<C: Context>(parent: &'a mut C) -> Self {
+        Self {
+            parent,
+            state: DataStore::<Box<dyn Send + Sync>>::new(),
+        }
+    }
+}
+
+impl Context for ChildViewContext<'_> {
+    fn set_data<T, F>(&mut self, key: &str, f: F)
+    where
+        F: FnOnce() -> T,
+        T: Send + Sync + 'static,
+    {
+        self.state.set(key, f());
+    }
+
+    fn get_data<T>(&self, key: &str) -> Option<&T>
+    where
+        T: Send + Sync + 'static,
+    {
+        match self.state.get(key) {
+            Some(value) => Some(value),
+            None => self
+                .parent
+                .data(key)
+                .and_then(|value| value.downcast_ref::<T>()),
+        }
+    }
+
+    fn add_section(&mut self, name: &str, content: String) -> &mut Self {
+        self.parent.put_section(name, content);
+        self
+    }
+
+    fn get_section(&self, name: &str) -> Option<&Vec<String>> {
+        self.parent.section(name)
+    }
+
+    fn get_section_mut(&mut self, name: &str) -> Option<&mut Vec<String>> {
+        self.parent.section_mut(name)
+    }
+
+    fn get_default_section(&self) -> Option<&String> {
+        self.parent.default_section()
+    }
+
+    fn set_default_section(&mut self, content: String) -> &mut Self {
+        self.parent.put_default_section(content);
+        self
+    }
+
+    fn get_data_any(&self, key: &str) -> Option<&dyn Any> {
+        match self.state.get_any(key) {
+            Some(value) => Some(value),
+            None => self.parent.data(key),
+        }
+    }
+    fn take_data_any(&mut self, key: &str) -> Option<Box<dyn Any>> {
+        match self.state.take(key) {
+            Some(value) => Some(value),
+            None => self.parent.take(key),
+        }
+    }
+
+    fn set_data_any(&mut self, key: &str, value: Box<dyn Any + Send + Sync>) {
+        self.state.set_any(key, value);
+    }
+
+    // the data of `@partial(path, data)` is the model of the partial instead.
+    fn lent_model(&self) -> Option<Arc<dyn Any + Send + Sync>> {
+        match self.state.get_any(MODEL_DATA_KEY) {
+            Some(_) => None,
+            None => self.parent.model(),
+        }
+    }
+}
+
+/// The context of the body of a view with `@model`, the model is lent to the views it renders in the same context,
+/// data and sections are the ones of the parent.
+pub struct ModelViewContext<'a> {
+    parent: &'a mut dyn ParentContext,
+    model: Arc<dyn Any + Send + Sync>,
+}
+
+impl<'a> ModelViewContext<'a> {
+    pub fn new<C: Context, T: Send + Sync + 'static>(parent: &'a mut C, model: Arc<T>) -> Self {
+        Self { parent, model }
+    }
+}
+
+impl Context for ModelViewContext<'_> {
+    fn set_data<T, F>(&mut self, key: &str, f: F)
+    where
+        F: FnOnce() -> T,
+        T: Send + Sync + 'static,
+    {
+        self.parent.put_data(key, Box::new(f()));
+    }
+
+    fn get_data<T>(&self, key: &str) -> Option<&T>
+    where
+        T: Send + Sync + 'static,
+    {
+        self.get_data_any(key)
+            .and_then(|value| value.downcast_ref::<T>())
+    }
+
+    fn add_section(&mut self, name: &str, content: String) -> &mut Self {
+        self.parent.put_section(name, content);
+        self
+    }
+
+    fn get_section(&self, name: &str) -> Option<&Vec<String>> {
+        self.parent.section(name)
+    }
+
+    fn get_section_mut(&mut self, name: &str) -> Option<&mut Vec<String>> {
+        self.parent.section_mut(name)
+    }
+
+    fn get_default_section(&self) -> Option<&String> {
+        self.parent.default_section()
+    }
+
+    fn set_default_section(&mut self, content: String) -> &mut Self {
+        self.parent.put_default_section(content);
+        self
+    }
+
+    fn get_data_any(&self, key: &str) -> Option<&dyn Any> {
+        match key == MODEL_DATA_KEY {
+            true => Some(self.model.as_ref()),
+            false => self.parent.data(key),
+        }
+    }
+
+    // the lent model isn't owned by the context.
+    fn take_data_any(&mut self, key: &str) -> Option<Box<dyn Any>> {
+        match key == MODEL_DATA_KEY {
+            true => None,
+            false => self.parent.take(key),
+        }
+    }
+
+    fn set_data_any(&mut self, key: &str, value: Box<dyn Any + Send + Sync>) {
+        self.parent.put_data(key, value);
+    }
+
+    fn lent_model(&self) -> Option<Arc<dyn Any + Send + Sync>> {
+        Some(self.model.clone())
+    }
+}
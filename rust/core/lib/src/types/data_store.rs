@@ -74,6 +74,26 @@ impl<T: Send + Sync> DataStore<T> {
         self._unlock_spin();
         value
     }
+
+    fn _get_any(&self, key: &str) -> Option<&dyn Any> {
+        self._ensure_data_initialized();
+
+        self._spin_lock();
+        let map = self._get_mutable_data();
+        let value = map.get(key).map(|b| b.as_ref());
+        self._unlock_spin();
+        value
+    }
+
+    fn _take(&self, key: &str) -> Option<Box<dyn Any>> {
+        self._spin_lock();
+
+        let map = self._get_mutable_data();
+        let value = map.remove(key);
+
+        self._unlock_spin();
+        value
+    }
 }
 
 impl<T: Send + Sync> DataStore<T> {
@@ -90,7 +110,28 @@ impl<T: Send + Sync> DataStore<T> {
         self._set(key.to_owned(), state)
     }
 
+    pub fn set_any(&self, key: &str, state: Box<dyn Any>) -> bool {
+        self._spin_lock();
+
+        let map = self._get_mutable_data();
+        let already_set = map.contains_key(key);
+        if !already_set {
+            map.insert(key.to_owned(), state);
+        }
+
+        self._unlock_spin();
+        !already_set
+    }
+
     pub fn get<D: 'static>(&self, key: &str) -> Option<&D> {
         self._get(key)
     }
+
+    pub fn get_any(&self, key: &str) -> Option<&dyn Any> {
+        self._get_any(key)
+    }
+
+    pub fn take(&mut self, key: &str) -> Option<Box<dyn Any>> {
+        self._take(key)
+    }
 }
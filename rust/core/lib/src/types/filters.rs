@@ -0,0 +1,160 @@
+//! Built-in filters for `@(exp | filter)`, each filter takes a reference to the piped value first.
+//! `@(name | truncate(20))` is generated as `sbolt::types::filters::truncate(&(name), 20)`.
+use std::fmt::Display;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub fn upper<T: Display + ?Sized>(value: &T) -> String {
+    value.to_string().to_uppercase()
+}
+
+pub fn lower<T: Display + ?Sized>(value: &T) -> String {
+    value.to_string().to_lowercase()
+}
+
+pub fn trim<T: Display + ?Sized>(value: &T) -> String {
+    value.to_string().trim().to_string()
+}
+
+// keeps `len` chars and appends "..." when the value is longer.
+pub fn truncate<T: Display + ?Sized>(value: &T, len: usize) -> String {
+    let content = value.to_string();
+    match content.char_indices().nth(len) {
+        Some((index, _)) => format!("{}...", &content[..index]),
+        None => content,
+    }
+}
+
+// the fallback for `None` and empty strings.
+pub fn default<T: FilterDefault + ?Sized>(value: &T, fallback: &str) -> String {
+    value
+        .value_or_none()
+        .unwrap_or_else(|| fallback.to_string())
+}
+
+pub fn join<'a, I, T>(value: &'a I, separator: &str) -> String
+where
+    I: ?Sized,
+    &'a I: IntoIterator<Item = T>,
+    T: Display,
+{
+    let mut result = String::new();
+    for (index, item) in value.into_iter().enumerate() {
+        if index > 0 {
+            result.push_str(separator);
+        }
+        result.push_str(&item.to_string());
+    }
+    result
+}
+
+pub fn pluralize<N: Copy + PartialEq + From<u8>>(
+    count: &N,
+    singular: &str,
+    plural: &str,
+) -> String {
+    match *count == N::from(1) {
+        true => singular.to_string(),
+        false => plural.to_string(),
+    }
+}
+
+// UTC date with `%Y`, `%m`, `%d`, `%H`, `%M`, `%S` and `%%`, other chars are kept.
+pub fn date<T: FilterDate + ?Sized>(value: &T, format: &str) -> String {
+    let seconds = value.unix_seconds();
+    let days = seconds.div_euclid(86400);
+    let time = seconds.rem_euclid(86400);
+    let (year, month, day) = civil_from_days(days);
+    let mut result = String::new();
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('Y') => result.push_str(&format!("{year:04}")),
+            Some('m') => result.push_str(&format!("{month:02}")),
+            Some('d') => result.push_str(&format!("{day:02}")),
+            Some('H') => result.push_str(&format!("{:02}", time / 3600)),
+            Some('M') => result.push_str(&format!("{:02}", time % 3600 / 60)),
+            Some('S') => result.push_str(&format!("{:02}", time % 60)),
+            Some('%') => result.push('%'),
+            Some(other) => {
+                result.push('%');
+                result.push(other);
+            }
+            None => result.push('%'),
+        }
+    }
+    result
+}
+
+// days since 1970-01-01 to (year, month, day), see http://howardhinnant.github.io/date_algorithms.html
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+pub trait FilterDefault {
+    fn value_or_none(&self) -> Option<String>;
+}
+
+impl<T: Display> FilterDefault for Option<T> {
+    fn value_or_none(&self) -> Option<String> {
+        self.as_ref().map(|value| value.to_string())
+    }
+}
+
+impl FilterDefault for str {
+    fn value_or_none(&self) -> Option<String> {
+        match self.is_empty() {
+            true => None,
+            false => Some(self.to_string()),
+        }
+    }
+}
+
+impl FilterDefault for String {
+    fn value_or_none(&self) -> Option<String> {
+        self.as_str().value_or_none()
+    }
+}
+
+impl<T: FilterDefault + ?Sized> FilterDefault for &T {
+    fn value_or_none(&self) -> Option<String> {
+        (**self).value_or_none()
+    }
+}
+
+pub trait FilterDate {
+    fn unix_seconds(&self) -> i64;
+}
+
+impl FilterDate for SystemTime {
+    fn unix_seconds(&self) -> i64 {
+        match self.duration_since(UNIX_EPOCH) {
+            Ok(duration) => duration.as_secs() as i64,
+            Err(err) => -(err.duration().as_secs() as i64),
+        }
+    }
+}
+
+impl FilterDate for i64 {
+    fn unix_seconds(&self) -> i64 {
+        *self
+    }
+}
+
+impl FilterDate for u64 {
+    fn unix_seconds(&self) -> i64 {
+        *self as i64
+    }
+}
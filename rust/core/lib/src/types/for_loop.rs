@@ -0,0 +1,24 @@
+// Metadata of current iteration in `@for`, available as `forloop` in the loop body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ForLoop {
+    // 0 based.
+    pub index: usize,
+    pub first: bool,
+    pub last: bool,
+}
+
+impl ForLoop {
+    #[doc(hidden)]
+    pub fn new(index: usize, last: bool) -> Self {
+        ForLoop {
+            index,
+            first: index == 0,
+            last,
+        }
+    }
+
+    // `separator` between items, empty for the last one: @(forloop.separator(", "))
+    pub fn separator<'a>(&self, separator: &'a str) -> &'a str {
+        if self.last { "" } else { separator }
+    }
+}
@@ -1,3 +1,11 @@
+use crate::types::error::RuntimeError;
+use crate::types::result::RenderResult;
+use crate::types::{
+    Context, LAYOUT_DATA_KEY, LayoutOverride, MODEL_DATA_KEY, PARTIAL_RENDER_DATA_KEY,
+    PENDING_BODY_DATA_KEY,
+};
+use std::sync::Arc;
+
 pub fn normalize_path_to_view_key(path: &str) -> Option<String> {
     if path.is_empty() {
         return None;
@@ -63,3 +71,125 @@ pub fn resolve_layout_to_view_keys(layout_path: &str, view_name: &str) -> Vec<St
     result.push(layout_path.to_string());
     result
 }
+
+// takes the data only if it's of the type, so the data of other types isn't removed.
+fn take_data<T: 'static>(context: &mut impl Context, key: &str) -> Option<T> {
+    match context
+        .get_data_any(key)
+        .is_some_and(|value| value.is::<T>())
+    {
+        true => context
+            .take_data_any(key)
+            .and_then(|value| value.downcast::<T>().ok())
+            .map(|value| *value),
+        false => None,
+    }
+}
+
+/// Moves the `@model` of a view out of the context, it's put back by `set_model` once the view is rendered.
+pub fn take_model<T: Send + Sync + 'static>(
+    context: &mut impl Context,
+    view_name: &str,
+) -> RenderResult<T> {
+    take_data::<T>(context, MODEL_DATA_KEY)
+        .ok_or_else(|| RuntimeError::model_not_found(std::any::type_name::<T>(), view_name))
+}
+
+/// Lends the `@model` of a view to its body by a `ModelViewContext`, it's shared with the view rendering it
+/// in the same context or moved out of the context, `true` is returned for the latter.
+pub fn lend_model<T: Send + Sync + 'static>(
+    context: &mut impl Context,
+    view_name: &str,
+) -> RenderResult<(Arc<T>, bool)> {
+    match context
+        .lent_model()
+        .and_then(|model| model.downcast::<T>().ok())
+    {
+        Some(model) => Ok((model, false)),
+        None => take_model::<T>(context, view_name).map(|model| (Arc::new(model), true)),
+    }
+}
+
+/// Puts the `@model` lent by `lend_model` back to the context once the view is rendered.
+pub fn restore_model<T: Send + Sync + 'static>(
+    context: &mut impl Context,
+    model: Arc<T>,
+    taken: bool,
+) {
+    if taken && let Ok(model) = Arc::try_unwrap(model) {
+        set_model(context, model);
+    }
+}
+
+/// Sets the `@model` of a view, the existing one is replaced.
+pub fn set_model<T: Send + Sync + 'static>(context: &mut impl Context, model: T) {
+    context.take_data_any(MODEL_DATA_KEY);
+    context.set_data(MODEL_DATA_KEY, move || model);
+}
+
+/// Takes the layout set for the request, `Some(None)` renders the view without its layout.
+/// It's taken once, so the layout doesn't pick it up again.
+pub fn take_layout(context: &mut impl Context) -> Option<Option<String>> {
+    take_data::<LayoutOverride>(context, LAYOUT_DATA_KEY).map(|layout| layout.0)
+}
+
+/// Sets the layout of the next view rendered with a layout, `None` renders it without the layout.
+pub fn set_layout(context: &mut impl Context, layout: Option<&str>) {
+    let layout = LayoutOverride(layout.map(|layout| layout.to_string()));
+    context.take_data_any(LAYOUT_DATA_KEY);
+    context.set_data(LAYOUT_DATA_KEY, move || layout);
+}
+
+// the body of a view is kept by the name of its layout, so a partial using the same layout doesn't take it.
+fn pending_body_key(layout: &str) -> String {
+    format!("{PENDING_BODY_DATA_KEY}{layout}")
+}
+
+/// Sets the body of a view rendered by its layout once it's needed, the one set before for the layout is returned.
+pub fn set_pending_body<T: Send + Sync + 'static>(
+    context: &mut impl Context,
+    layout: &str,
+    body: T,
+) -> Option<T> {
+    let pending = take_pending_body(context, layout);
+    context.set_data(&pending_body_key(layout), move || body);
+    pending
+}
+
+/// Takes the body of the view rendered by the layout, it's rendered at the first `@render` or `@section` of it.
+pub fn take_pending_body<T: Send + Sync + 'static>(
+    context: &mut impl Context,
+    layout: &str,
+) -> Option<T> {
+    take_data::<T>(context, &pending_body_key(layout))
+}
+
+/// Whether the layout has the body of a view not rendered yet.
+pub fn has_pending_body(context: &impl Context, layout: &str) -> bool {
+    context.get_data_any(&pending_body_key(layout)).is_some()
+}
+
+// the mark set by `@partial` for the view it renders.
+struct PartialRender;
+
+/// Marks the next view rendered as a partial, so the layout of its `_viewstart` isn't applied.
+pub fn set_partial_render(context: &mut impl Context) {
+    context.set_data(PARTIAL_RENDER_DATA_KEY, || PartialRender);
+}
+
+/// Takes the mark set by `set_partial_render`, a view takes it before its content renders other views.
+pub fn take_partial_render(context: &mut impl Context) -> bool {
+    take_data::<PartialRender>(context, PARTIAL_RENDER_DATA_KEY).is_some()
+}
+
+/// Clones the service injected by `@inject name: Type` out of the context, use `Arc` for shared services.
+pub fn inject<T: Clone + Send + Sync + 'static>(
+    context: &impl Context,
+    name: &str,
+    view_name: &str,
+) -> RenderResult<T> {
+    context
+        .get_data::<T>(name)
+        .cloned()
+        .ok_or_else(|| RuntimeError::service_not_found(name, view_name))
+}
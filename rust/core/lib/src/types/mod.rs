@@ -1,13 +1,22 @@
 mod context;
 mod data_store;
+pub mod filters;
+mod for_loop;
 mod functions;
 mod macros;
+mod render;
+#[cfg(feature = "stream")]
+mod stream;
 pub mod template;
 mod writer;
 
 pub use context::*;
 pub use data_store::*;
+pub use for_loop::*;
 pub use functions::*;
+pub use render::*;
+#[cfg(feature = "stream")]
+pub use stream::*;
 pub use template::Template;
 pub use writer::*;
 
@@ -39,6 +48,12 @@ pub mod error {
     pub enum RuntimeError {
         // could not find view
         NotFound(/*summary*/ String, /*detail*/ String),
+        // value could not be written into the output, like non-finite number in json
+        InvalidValue(/*summary*/ String, /*detail*/ String),
+        // output could not be passed to the sink of a StreamWriter, like a closed socket
+        WriteFailed(/*summary*/ String, /*detail*/ String),
+        // the view can't be rendered this way, like an async view rendered by `render`
+        Unsupported(/*summary*/ String, /*detail*/ String),
         // todo: add other types
     }
 }
@@ -0,0 +1,19 @@
+use crate::types::{EscapeContext, Writer, result};
+
+/// Writes a value straight into the writer, escaped for where it lands in the template,
+/// so `@exp` doesn't allocate a String for each value. Other `Display` types are formatted into the writer.
+pub trait Render {
+    fn render_to(
+        &self,
+        writer: &mut impl Writer,
+        context: EscapeContext,
+    ) -> result::RenderResult<()>;
+}
+
+/// Renders the items of an iterator one after another, like `@RenderIter(tags.iter())`,
+/// the iterator is cloned as rendering doesn't consume the value.
+pub struct RenderIter<I>(pub I);
+
+/// Renders a `Display` value escaped for where it lands, so own types without `Render`
+/// could be used in `Option`, `Vec` or `RenderIter`, like `@(points.iter().map(RenderDisplay).collect::<Vec<_>>())`.
+pub struct RenderDisplay<T>(pub T);
@@ -0,0 +1,18 @@
+use crate::types::result;
+
+// Chunks of the output of a view rendered by a blocking task, for body streams of web frameworks:
+// `axum::body::Body::from_stream(views::render_stream("views/index", context, spawn)?)`.
+// A chunk is sent on `@flush` or once it grows over the chunk size, errors of the render end the stream.
+pub struct RenderStream {
+    pub(crate) receiver: futures_channel::mpsc::Receiver<result::RenderResult<bytes::Bytes>>,
+}
+
+// The render of a RenderStream, it blocks while the chunks aren't received,
+// so it's run on a blocking pool like `tokio::task::spawn_blocking`.
+pub type RenderTask = Box<dyn FnOnce() + Send + 'static>;
+
+// Sink of the StreamWriter of a RenderStream.
+pub struct ChannelSink {
+    pub(crate) sender: futures_channel::mpsc::Sender<result::RenderResult<bytes::Bytes>>,
+    pub(crate) buffer: Vec<u8>,
+}
@@ -1,5 +1,6 @@
 use crate::types::Context;
 use crate::types::result;
+use crate::types::{KWriter, Writer};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Kind {
@@ -15,5 +16,39 @@ pub trait Template {
         // default impl.
         None
     }
-    fn render(&self, context: &mut impl Context) -> result::RenderResult<String>;
+    // bytes the output is expected to take, generated views count their static content and expressions.
+    fn size_hint() -> usize {
+        0
+    }
+    fn render(&self, context: &mut impl Context) -> result::RenderResult<String> {
+        let mut writer = KWriter::new(Self::kind());
+        writer.reserve(Self::size_hint());
+        self.render_to(context, &mut writer)?;
+        Ok(writer.into_string())
+    }
+    // writes the output into the writer as it's rendered, escaped by the writer.
+    fn render_to(
+        &self,
+        context: &mut impl Context,
+        writer: &mut impl Writer,
+    ) -> result::RenderResult<()>;
+    // views with `@await` and the ones using layouts or partials override it, others are rendered as they are.
+    fn render_async(
+        &self,
+        context: &mut impl Context,
+        writer: &mut (impl Writer + Send),
+    ) -> impl Future<Output = result::RenderResult<()>> + Send {
+        std::future::ready(self.render_to(context, writer))
+    }
+    // the content of the view without its layout, rendered by the layout once it's needed.
+    // views with a layout override it, others are rendered as they are.
+    fn render_body(&self, context: &mut impl Context) -> result::RenderResult<String> {
+        self.render(context)
+    }
+    fn render_body_async(
+        &self,
+        context: &mut impl Context,
+    ) -> impl Future<Output = result::RenderResult<String>> + Send {
+        std::future::ready(self.render_body(context))
+    }
 }
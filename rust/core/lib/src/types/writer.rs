@@ -1,20 +1,82 @@
+use crate::types::result;
+use crate::types::template::Kind;
+
+// Where an expression's output lands in the template, used to pick an escaping strategy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EscapeContext {
+    // text node: <p>@exp</p>
+    KText,
+    // quoted attribute value: <a href="@exp">
+    KAttribute,
+    // unquoted attribute value or inside a tag: <a href=@exp>
+    KUnquotedAttribute,
+    // json string literal: "name": "@exp"
+    KJsonString,
+    // json value: "age": @exp
+    KJsonValue,
+}
+
 pub trait Writer {
     fn write(&mut self, content: &str);
+    // writers without escaping rules write the content as it is.
+    fn write_escaped(&mut self, content: &str, _context: EscapeContext) {
+        self.write(content);
+    }
+    // writers which could reject the content(like non-finite number in json) return error.
+    fn try_write_escaped(
+        &mut self,
+        content: &str,
+        context: EscapeContext,
+    ) -> result::RenderResult<()> {
+        self.write_escaped(content, context);
+        Ok(())
+    }
     fn writeln(&mut self, content: &str) {
         self.write(content);
         self.write("\n");
     }
-    fn writefn(&mut self, content_fn: impl FnOnce() -> String) {
+    fn writefn(&mut self, content_fn: impl FnOnce() -> String)
+    where
+        Self: Sized,
+    {
         self.write(&content_fn());
     }
+    // `@flush` passes the content written so far to the sink, nothing to do for buffered writers.
+    fn flush(&mut self) {}
+    // buffered writers reserve the estimated output of a view, like the static content of it.
+    fn reserve(&mut self, _additional: usize) {}
+}
+
+// views write into `&mut impl Writer` passed by the caller, like a StreamWriter over a socket.
+impl<W: Writer + ?Sized> Writer for &mut W {
+    fn write(&mut self, content: &str) {
+        (**self).write(content);
+    }
 
-    // convert to String by consuming self.
-    fn into_string(self) -> String;
+    fn write_escaped(&mut self, content: &str, context: EscapeContext) {
+        (**self).write_escaped(content, context);
+    }
+
+    fn try_write_escaped(
+        &mut self,
+        content: &str,
+        context: EscapeContext,
+    ) -> result::RenderResult<()> {
+        (**self).try_write_escaped(content, context)
+    }
+
+    fn flush(&mut self) {
+        (**self).flush();
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        (**self).reserve(additional);
+    }
 }
 
 pub enum KWriter {
     KHtml(HtmlWriter),
-    KJson(String),
+    KJson(JsonWriter),
     KText(String),
 }
 
@@ -22,6 +84,41 @@ pub struct HtmlWriter {
     pub(crate) content: String,
 }
 
+pub struct JsonWriter {
+    pub(crate) content: String,
+}
+
+// Destination of a StreamWriter.
+pub trait Sink {
+    fn write_str(&mut self, content: &str) -> Result<(), String>;
+    fn flush(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+// Sink over std::io::Write, like a TcpStream or a BufWriter of it.
+pub struct IoSink<W: std::io::Write>(pub W);
+
+// Sink over std::fmt::Write, like a String or a Formatter.
+pub struct FmtSink<W: std::fmt::Write>(pub W);
+
+// Writer which passes the output to the sink as it's rendered instead of buffering it,
+// content is escaped by the kind of the template and the first error of the sink is kept until `finish`.
+pub struct StreamWriter<S: Sink> {
+    pub(crate) sink: S,
+    pub(crate) kind: Kind,
+    pub(crate) buffer: String,
+    pub(crate) error: Option<crate::types::error::RuntimeError>,
+}
+
+// fmt::Write adapter for formatted expressions like `@(price:.2)`, content is escaped for the context
+// and the first error of the writer is returned by `write!`.
+pub struct FmtWriter<'a, W: Writer> {
+    pub(crate) writer: &'a mut W,
+    pub(crate) context: Option<EscapeContext>,
+    pub(crate) error: Option<crate::types::error::RuntimeError>,
+}
+
 // Wrapper type for Option<&T> and Option<T> to implement Display trait.
 pub struct DisplayOption<T: std::fmt::Display>(pub Option<T>);
 impl<T: std::fmt::Display> From<T> for DisplayOption<T> {
@@ -53,3 +150,136 @@ impl<'a, T: std::fmt::Display> std::fmt::Display for DisplayOptionRef<'a, T> {
         }
     }
 }
+
+// Markup which is already safe, like pre-rendered widgets or sanitized html, writers write it as it is.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Markup(String);
+impl Markup {
+    pub fn new(content: impl Into<String>) -> Self {
+        Markup(content.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl From<String> for Markup {
+    fn from(value: String) -> Self {
+        Markup(value)
+    }
+}
+
+impl From<&str> for Markup {
+    fn from(value: &str) -> Self {
+        Markup(value.to_string())
+    }
+}
+
+impl std::fmt::Display for Markup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+// Types declare their Display output is safe markup which doesn't need escaping.
+pub trait HtmlSafe: std::fmt::Display {}
+impl HtmlSafe for Markup {}
+impl<T: HtmlSafe + ?Sized> HtmlSafe for &T {}
+
+// Wrapper used by generated code to pick how an expression is written:
+// (&&&Output(&exp)).write_to(&mut writer, context)? renders Render types, writes HtmlSafe as it is
+// and formats other Display into the writer escaped.
+#[doc(hidden)]
+pub struct Output<'a, T: ?Sized>(pub &'a T);
+
+#[doc(hidden)]
+pub trait WriteRender {
+    fn write_to(
+        &self,
+        writer: &mut impl Writer,
+        context: EscapeContext,
+    ) -> result::RenderResult<()>;
+}
+
+impl<T: crate::types::Render + ?Sized> WriteRender for &&Output<'_, T> {
+    fn write_to(
+        &self,
+        writer: &mut impl Writer,
+        context: EscapeContext,
+    ) -> result::RenderResult<()> {
+        self.0.render_to(writer, context)
+    }
+}
+
+#[doc(hidden)]
+pub trait WriteMarkup {
+    fn write_to(
+        &self,
+        writer: &mut impl Writer,
+        context: EscapeContext,
+    ) -> result::RenderResult<()>;
+}
+
+// json still gets markup escaped as a string.
+impl<T: HtmlSafe + ?Sized> WriteMarkup for &Output<'_, T> {
+    fn write_to(
+        &self,
+        writer: &mut impl Writer,
+        context: EscapeContext,
+    ) -> result::RenderResult<()> {
+        let context = match context {
+            EscapeContext::KJsonString | EscapeContext::KJsonValue => Some(context),
+            _ => None,
+        };
+        write!(FmtWriter::new(writer, context), "{}", self.0)
+    }
+}
+
+#[doc(hidden)]
+pub trait WriteDisplay {
+    fn write_to(
+        &self,
+        writer: &mut impl Writer,
+        context: EscapeContext,
+    ) -> result::RenderResult<()>;
+}
+
+impl<T: std::fmt::Display + ?Sized> WriteDisplay for Output<'_, T> {
+    fn write_to(
+        &self,
+        writer: &mut impl Writer,
+        context: EscapeContext,
+    ) -> result::RenderResult<()> {
+        write!(FmtWriter::new(writer, Some(context)), "{}", self.0)
+    }
+}
+
+// Same as WriteMarkup/WriteDisplay for formatted expressions, `@(exp:spec)` is written through
+// FmtWriter with (&&Output(&exp)).escape_context(context), markup is written as it is.
+// Values don't need to be Display, like `@(items:?)`.
+#[doc(hidden)]
+pub trait FormatMarkup {
+    fn escape_context(&self, context: EscapeContext) -> Option<EscapeContext>;
+}
+
+impl<T: HtmlSafe + ?Sized> FormatMarkup for &Output<'_, T> {
+    fn escape_context(&self, _context: EscapeContext) -> Option<EscapeContext> {
+        None
+    }
+}
+
+#[doc(hidden)]
+pub trait FormatValue {
+    fn escape_context(&self, context: EscapeContext) -> Option<EscapeContext>;
+}
+
+impl<T: ?Sized> FormatValue for Output<'_, T> {
+    fn escape_context(&self, context: EscapeContext) -> Option<EscapeContext> {
+        Some(context)
+    }
+}
@@ -1,5 +1,7 @@
 fn main() {
     let option = sbolt::codegen::CompilerOptions::default()
+        .with_filter("money", "crate::money")
+        .with_trim_blocks(sbolt::types::template::Kind::KTEXT, true)
         .with_source_dir("../views")
         .with_mod_name("lib_it_no_op_views");
     let compiler = sbolt::codegen::Compiler::new(option);
@@ -2,6 +2,30 @@
 use sbolt::types::result;
 sbolt::include_views!();
 
+// filter registered in build.rs.
+fn money(value: &f64) -> String {
+    format!("${value:.2}")
+}
+
+// service injected in views/sub/inject.
+#[derive(Clone)]
+struct UrlBuilder {
+    base: String,
+}
+
+impl UrlBuilder {
+    fn url(&self, path: &str) -> String {
+        format!("{}/{path}", self.base)
+    }
+}
+
+// model of views/sub/product.
+struct Product {
+    name: String,
+    price: f64,
+    tags: Vec<String>,
+}
+
 #[test]
 #[should_panic]
 fn no_existing_view() {
@@ -72,3 +96,474 @@ fn sub_jindex_view() -> result::RenderResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn sub_jscore_view() -> result::RenderResult<()> {
+    let mut context = sbolt::context! {
+        name: "say \"hi\"\n".to_string(),
+        score: 9.5
+    };
+    let result = lib_it_no_op_views::render("views/sub/jscore", &mut context)?;
+    let expected = "{\"name\": \"say \\\"hi\\\"\\n\", \"score\": 9.5}";
+    assert_eq!(result.trim(), expected);
+
+    Ok(())
+}
+
+#[test]
+fn sub_jscore_view_with_non_finite_number() {
+    let mut context = sbolt::context! {
+        score: f64::NAN
+    };
+    let result = lib_it_no_op_views::render("views/sub/jscore", &mut context);
+    assert!(matches!(
+        result,
+        Err(sbolt::types::error::RuntimeError::InvalidValue(_, _))
+    ));
+}
+
+#[test]
+fn sub_condition_view() -> result::RenderResult<()> {
+    let result =
+        lib_it_no_op_views::render("views/sub/condition", &mut sbolt::context! { age: 70 })?;
+    assert_eq!(result.trim(), "<p class=\"adult\"><b>senior</b></p>");
+
+    let result =
+        lib_it_no_op_views::render("views/sub/condition", &mut sbolt::context! { age: 30 })?;
+    assert_eq!(result.trim(), "<p class=\"adult\">adult</p>");
+
+    let result = lib_it_no_op_views::render("views/sub/condition", &mut sbolt::context!())?;
+    assert_eq!(result.trim(), "<p class=\"minor\">minor</p>");
+
+    Ok(())
+}
+
+#[test]
+fn sub_loops_view() -> result::RenderResult<()> {
+    let mut context = sbolt::context! {
+        items: vec!["a".to_string(), "<b>".to_string()]
+    };
+    let result = lib_it_no_op_views::render("views/sub/loops", &mut context)?;
+    let expected = "<ul><li class=\"first\">0:a</li><li class=\"\">1:&lt;b&gt;</li></ul>\n<p>a, &lt;b&gt;</p>\n<p>3 2 1 </p>";
+    assert_eq!(result.trim(), expected);
+
+    let result = lib_it_no_op_views::render("views/sub/loops", &mut sbolt::context!())?;
+    let expected = "<ul><li>none</li></ul>\n<p></p>\n<p>3 2 1 </p>";
+    assert_eq!(result.trim(), expected);
+
+    Ok(())
+}
+
+#[test]
+fn sub_matching_view() -> result::RenderResult<()> {
+    let result =
+        lib_it_no_op_views::render("views/sub/matching", &mut sbolt::context! { age: 30 })?;
+    assert_eq!(result.trim(), "<p><b>adult(30)</b></p>");
+
+    let result = lib_it_no_op_views::render("views/sub/matching", &mut sbolt::context! { age: 3 })?;
+    assert_eq!(result.trim(), "<p>minor(3)</p>");
+
+    let result = lib_it_no_op_views::render("views/sub/matching", &mut sbolt::context!())?;
+    assert_eq!(result.trim(), "<p>unknown</p>");
+
+    Ok(())
+}
+
+#[test]
+fn sub_functions_view() -> result::RenderResult<()> {
+    let result = lib_it_no_op_views::render("views/sub/functions", &mut sbolt::context!())?;
+    assert_eq!(
+        result.trim(),
+        "<ul><li>1-a</li><li>2-b</li></ul>\n<p>B.</p>"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn sub_escape_view() -> result::RenderResult<()> {
+    let mut context = sbolt::context! {
+        name: "<b>\"Tom\" & 'Jerry'</b>".to_string()
+    };
+    let result = lib_it_no_op_views::render("views/sub/escape", &mut context)?;
+    let expected = "<div title=\"&lt;b&gt;&quot;Tom&quot; &amp; &#x27;Jerry&#x27;&lt;/b&gt;\">&lt;b&gt;\"Tom\" &amp; 'Jerry'&lt;/b&gt;</div>\n<p><b>new</b> <i>22</i></p>";
+    assert_eq!(result.trim(), expected);
+
+    Ok(())
+}
+
+#[test]
+fn sub_partials_view() -> result::RenderResult<()> {
+    let mut context = sbolt::context! {
+        name: "sbolt".to_string()
+    };
+    let result = lib_it_no_op_views::render("views/sub/partials", &mut context)?;
+    let expected =
+        "<ul><li>a by sbolt</li><li>&lt;b&gt; by sbolt</li></ul>\n<ul><li> by sbolt</li></ul>";
+    assert_eq!(result.trim(), expected);
+
+    Ok(())
+}
+
+#[test]
+fn sub_format_view() -> result::RenderResult<()> {
+    let result = lib_it_no_op_views::render("views/sub/format", &mut sbolt::context!())?;
+    assert_eq!(
+        result.trim(),
+        "<p>3.14|  &lt;b&gt;|<i>new</i>|[1, 2]|0xff</p>"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn sub_filters_view() -> result::RenderResult<()> {
+    let result = lib_it_no_op_views::render("views/sub/filters", &mut sbolt::context!())?;
+    let expected = "<p>SBOLT|template...|a, b|guest</p>\n<p>items|1970-01-02 00:00|$3.50|7</p>";
+    assert_eq!(result.trim(), expected);
+
+    Ok(())
+}
+
+#[test]
+fn sub_product_view() -> result::RenderResult<()> {
+    let mut context = sbolt::context! {
+        model: Product {
+            name: "sbolt".to_string(),
+            price: 1.5,
+            tags: vec!["rust".to_string(), "<html>".to_string()],
+        }
+    };
+    let expected =
+        "<h1>sbolt</h1><p>$1.50</p>\n<ul><li>rust tag</li>\n<li>&lt;html&gt; tag</li>\n</ul>";
+    let result = lib_it_no_op_views::render("views/sub/product", &mut context)?;
+    assert_eq!(result.trim(), expected);
+    // the model is kept in the context after rendering.
+    let result = lib_it_no_op_views::render("views/sub/product", &mut context)?;
+    assert_eq!(result.trim(), expected);
+
+    let product = Product {
+        name: "typed".to_string(),
+        price: 2.0,
+        tags: vec![],
+    };
+    let result = lib_it_no_op_views::views::sub::product::ProductView::new()
+        .render_model(product, &mut sbolt::context!())?;
+    assert_eq!(result.trim(), "<h1>typed</h1><p>$2.00</p>\n<ul></ul>");
+
+    // the typed entry point of the root module.
+    let product = Product {
+        name: "root".to_string(),
+        price: 3.0,
+        tags: vec![],
+    };
+    let result = lib_it_no_op_views::render_views_sub_product(product, &mut sbolt::context!())?;
+    assert!(result.starts_with("<h1>root</h1><p>$3.00</p>"));
+
+    let result = lib_it_no_op_views::render("views/sub/product", &mut sbolt::context!(model: 1));
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn sub_flushed_view() -> result::RenderResult<()> {
+    // the partial is written into the writer of the view, `@flush` does nothing for buffered writers.
+    let result = lib_it_no_op_views::render("views/sub/flushed", &mut sbolt::context!())?;
+    assert_eq!(
+        result,
+        "<main><head><title>chunks</title></head><body><p>0</p><p>1</p><p>2</p></body></main>"
+    );
+    Ok(())
+}
+
+#[test]
+fn sub_lend_view() -> result::RenderResult<()> {
+    // the model is seen by the partials rendered in the same context and put back after rendering.
+    let mut context = sbolt::context!(model: "lent".to_string());
+    let result = lib_it_no_op_views::render("views/sub/lend", &mut context)?;
+    assert_eq!(result.trim(), "<p><b>lent</b><i>lent</i></p>");
+    assert_eq!(
+        sbolt::types::Context::get_data::<String>(&context, "model"),
+        Some(&"lent".to_string())
+    );
+    Ok(())
+}
+
+#[test]
+fn sub_inject_view() -> result::RenderResult<()> {
+    let mut context = sbolt::context! {
+        urls: UrlBuilder {
+            base: "https://sbolt.dev".to_string(),
+        },
+        flags: std::sync::Arc::new(vec!["beta".to_string()])
+    };
+    let result = lib_it_no_op_views::render("views/sub/inject", &mut context)?;
+    assert_eq!(
+        result.trim(),
+        r#"<a href="https://sbolt.dev/home">home</a><b>beta</b>"#
+    );
+
+    let mut context = sbolt::context! {
+        flags: std::sync::Arc::new(Vec::<String>::new())
+    };
+    let err = lib_it_no_op_views::render("views/sub/inject", &mut context).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "View:views::sub::inject::InjectView, NotFound: Service 'urls' not found for View `views::sub::inject::InjectView`"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn sub_trim_view() -> result::RenderResult<()> {
+    let result = lib_it_no_op_views::render("views/sub/trim", &mut sbolt::context!())?;
+    assert_eq!(
+        result,
+        "Items:\n    - a\n    - b\n    total: 2\n\nTags:a,bend\n    count: 2\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn sub_stack_view() -> result::RenderResult<()> {
+    let result = lib_it_no_op_views::render("views/sub/stack", &mut sbolt::context!())?;
+    let expected = "<title>Stack</title>\n<head><script>vendor()</script><script>base()</script><script>app()</script><script>widget()</script></head>\n<body><div>widget</div><p>stack</p></body>\n<aside><a href=\"/\">home</a></aside>\n<footer><i>note</i></footer>";
+    assert_eq!(result.trim(), expected);
+
+    Ok(())
+}
+
+#[test]
+fn sub_page_view() -> result::RenderResult<()> {
+    let result = lib_it_no_op_views::render("views/sub/page", &mut sbolt::context!())?;
+    assert!(result.contains("<title>Untitled</title>"));
+    assert!(result.contains("<body><p>page</p></body>"));
+
+    // selected by the expression.
+    let result = lib_it_no_op_views::render("views/sub/page", &mut sbolt::context!(embed: true))?;
+    assert_eq!(result, "<main><p>page</p></main>");
+
+    // overridden by the context, the data of the app named `layout` isn't taken.
+    let mut context = sbolt::context!(layout: "app".to_string());
+    sbolt::types::set_layout(&mut context, None);
+    let result = lib_it_no_op_views::render("views/sub/page", &mut context)?;
+    assert_eq!(result, "<p>page</p>");
+    assert_eq!(
+        sbolt::types::Context::get_data::<String>(&context, "layout"),
+        Some(&"app".to_string())
+    );
+
+    let mut context = sbolt::context!();
+    sbolt::types::set_layout(&mut context, Some("embed"));
+    let result = lib_it_no_op_views::render("views/sub/home", &mut context)?;
+    assert_eq!(result, "<main><div>Hello world!<div></main>");
+
+    Ok(())
+}
+
+#[test]
+fn sub_intro_view() -> result::RenderResult<()> {
+    // intro -> sub/docs -> site, with a partial rendered in its own layout by sub/docs.
+    let result = lib_it_no_op_views::render("views/sub/intro", &mut sbolt::context!())?;
+    let expected = concat!(
+        "<html><head><title>Docs: Intro</title><link href=\"intro.css\"><link href=\"docs.css\"></head>",
+        "<body><nav>intro</nav><div class=\"box\"><b>boxed</b></div>",
+        "<article><p>intro</p></article><aside><ol><li>a</li></ol></aside></body></html>"
+    );
+    assert_eq!(result, expected);
+
+    Ok(())
+}
+
+#[test]
+fn blog_views() -> result::RenderResult<()> {
+    // views/blog/_imports and _viewstart apply to the whole folder, views/blog/archive overrides the layout.
+    let mut context = sbolt::context! {
+        urls: UrlBuilder {
+            base: "https://sbolt.dev".to_string(),
+        }
+    };
+    let result = lib_it_no_op_views::render("views/blog/post", &mut context)?;
+    assert_eq!(
+        result.trim(),
+        r#"<main><a href="https://sbolt.dev/post">sbolt</a></main>"#
+    );
+
+    let result = lib_it_no_op_views::render("views/blog/plain", &mut context)?;
+    assert_eq!(result.trim(), "<p>sbolt</p>");
+
+    let result = lib_it_no_op_views::render("views/blog/archive/year", &mut context)?;
+    assert_eq!(
+        result.trim(),
+        r#"<div class="box"><p>https://sbolt.dev/2026 by sbolt</p></div>"#
+    );
+    assert!(lib_it_no_op_views::resolve_view_creator("views/blog/_viewstart").is_none());
+
+    Ok(())
+}
+
+#[test]
+fn shop_views() -> result::RenderResult<()> {
+    // views/shop/base is the layout of views/shop/_viewstart, it isn't the layout of itself.
+    let result = lib_it_no_op_views::render("views/shop/item", &mut sbolt::context!())?;
+    assert_eq!(result.trim(), "<section><p>item</p></section>");
+
+    // views/shop/_viewstart.rshtml only applies to html views.
+    let result = lib_it_no_op_views::render("views/shop/stock", &mut sbolt::context!())?;
+    assert_eq!(result.trim(), r#"{"a":1}"#);
+
+    // views rendered by `@partial` are not wrapped in the layout of their `_viewstart`.
+    let result = lib_it_no_op_views::render("views/storefront", &mut sbolt::context!())?;
+    assert_eq!(result.trim(), "<main><i>card</i></main>");
+    let result = lib_it_no_op_views::render("views/shop/card", &mut sbolt::context!())?;
+    assert_eq!(result.trim(), "<section><i>card</i></section>");
+    Ok(())
+}
+
+#[test]
+fn render_to_io_writer() -> result::RenderResult<()> {
+    // streamed through the layouts and partials of views/sub/intro as render does.
+    let mut output = Vec::<u8>::new();
+    lib_it_no_op_views::render_to("views/sub/intro", &mut sbolt::context!(), &mut output)?;
+    let expected = lib_it_no_op_views::render("views/sub/intro", &mut sbolt::context!())?;
+    assert_eq!(String::from_utf8_lossy(&output), expected);
+
+    let mut output = Vec::<u8>::new();
+    lib_it_no_op_views::render_to("views/sub/escape", &mut sbolt::context!(), &mut output)?;
+    let expected = lib_it_no_op_views::render("views/sub/escape", &mut sbolt::context!())?;
+    assert_eq!(String::from_utf8_lossy(&output), expected);
+
+    let err =
+        lib_it_no_op_views::render_to("views/no_existing", &mut sbolt::context!(), &mut output);
+    assert!(err.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn sub_chunks_view() -> result::RenderResult<()> {
+    // `@flush` does nothing for buffered writers.
+    let result = lib_it_no_op_views::render("views/sub/chunks", &mut sbolt::context!())?;
+    assert_eq!(
+        result,
+        "<head><title>chunks</title></head><body><p>0</p><p>1</p><p>2</p></body>"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn sub_pressure_view() -> result::RenderResult<()> {
+    let result = lib_it_no_op_views::render("views/sub/pressure", &mut sbolt::context!())?;
+    let expected = (0..16).map(|i| format!("<p>{i}</p>")).collect::<String>();
+    assert_eq!(result.trim(), expected);
+    Ok(())
+}
+
+#[test]
+fn sub_lazy_view() -> result::RenderResult<()> {
+    // views/stream renders the body of views/sub/lazy at `@render`.
+    let result = lib_it_no_op_views::render("views/sub/lazy", &mut sbolt::context!())?;
+    assert_eq!(
+        result,
+        "<head><title>stream</title></head><body><p>true</p></body>"
+    );
+    Ok(())
+}
+
+// service injected in views/sub/feed and views/sub/author, loaded asynchronously.
+#[derive(Clone)]
+struct Repository {
+    posts: Vec<String>,
+}
+
+impl Repository {
+    async fn title(&self) -> String {
+        "<Feed>".to_string()
+    }
+
+    async fn posts(&self) -> Vec<String> {
+        self.posts.clone()
+    }
+
+    async fn author(&self) -> String {
+        "sbolt".to_string()
+    }
+}
+
+fn assert_send<T: Send>(_: &T) {}
+
+// the futures of the views are ready once polled.
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    let mut future = std::pin::pin!(future);
+    let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+    loop {
+        if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn sub_feed_view() -> result::RenderResult<()> {
+    let mut context = sbolt::context! {
+        repo: Repository {
+            posts: vec!["a".to_string(), "b".to_string()],
+        }
+    };
+    // sent to the executors of web frameworks as the handlers are.
+    let future = lib_it_no_op_views::render_async("views/sub/feed", &mut context);
+    assert_send(&future);
+    let result = block_on(future)?;
+    assert_eq!(
+        result.trim(),
+        "<div class=\"box\"><h1>&lt;Feed&gt;</h1>\n<ul><li>a</li><li>b</li></ul>\n<p>2 posts by sbolt</p>\n</div>"
+    );
+
+    // awaits can't be rendered synchronously.
+    let err = lib_it_no_op_views::render("views/sub/feed", &mut context).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "View:views::sub::feed::FeedView, Unsupported: View `views::sub::feed::FeedView` awaits while rendering, use `render_async` instead"
+    );
+
+    // sync views are rendered as render does.
+    let result = block_on(lib_it_no_op_views::render_async(
+        "views/sub/intro",
+        &mut sbolt::context!(),
+    ))?;
+    let expected = lib_it_no_op_views::render("views/sub/intro", &mut sbolt::context!())?;
+    assert_eq!(result, expected);
+
+    Ok(())
+}
+
+// rendered by views/sub/stars without Display.
+struct Stars(u8);
+
+impl sbolt::types::Render for Stars {
+    fn render_to(
+        &self,
+        writer: &mut impl sbolt::types::Writer,
+        _context: sbolt::types::EscapeContext,
+    ) -> result::RenderResult<()> {
+        for _ in 0..self.0 {
+            writer.write("&#9733;");
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn sub_stars_view() -> result::RenderResult<()> {
+    let result = lib_it_no_op_views::render("views/sub/stars", &mut sbolt::context!())?;
+    assert_eq!(
+        result.trim(),
+        r#"<p>a&lt;b&gt;</p><p></p><p title="&#9733;&#9733;&#9733;">&#9733;&#9733;&#9733;</p><p>13</p>"#
+    );
+
+    Ok(())
+}
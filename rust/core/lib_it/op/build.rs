@@ -1,6 +1,10 @@
 fn main() {
     let option = sbolt::codegen::CompilerOptions::default()
         .with_optimization(true)
+        .with_json_validation(true)
+        .with_stream_render(true)
+        .with_filter("money", "crate::money")
+        .with_trim_blocks(sbolt::types::template::Kind::KTEXT, true)
         .with_source_dir("../views")
         .with_mod_name("lib_it_op_views");
     let compiler = sbolt::codegen::Compiler::new(option);
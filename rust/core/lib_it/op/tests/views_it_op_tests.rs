@@ -2,6 +2,30 @@
 use sbolt::types::result;
 sbolt::include_views!();
 
+// filter registered in build.rs.
+fn money(value: &f64) -> String {
+    format!("${value:.2}")
+}
+
+// service injected in views/sub/inject.
+#[derive(Clone)]
+struct UrlBuilder {
+    base: String,
+}
+
+impl UrlBuilder {
+    fn url(&self, path: &str) -> String {
+        format!("{}/{path}", self.base)
+    }
+}
+
+// model of views/sub/product.
+struct Product {
+    name: String,
+    price: f64,
+    tags: Vec<String>,
+}
+
 #[test]
 fn default_view() -> result::RenderResult<()> {
     let result = lib_it_op_views::render("views/default", &mut sbolt::context!())?;
@@ -35,3 +59,537 @@ fn sub_home_view() -> result::RenderResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn sub_jscore_view() -> result::RenderResult<()> {
+    let mut context = sbolt::context! {
+        name: "say \"hi\"".to_string(),
+        score: 9.5
+    };
+    let result = lib_it_op_views::render("views/sub/jscore", &mut context)?;
+    let expected = "{\"name\": \"say \\\"hi\\\"\", \"score\": 9.5}";
+    assert_eq!(result.trim(), expected);
+
+    Ok(())
+}
+
+#[test]
+fn sub_condition_view() -> result::RenderResult<()> {
+    let result = lib_it_op_views::render("views/sub/condition", &mut sbolt::context! { age: 70 })?;
+    assert_eq!(result.trim(), "<p class=\"adult\"><b>senior</b></p>");
+
+    let result = lib_it_op_views::render("views/sub/condition", &mut sbolt::context! { age: 30 })?;
+    assert_eq!(result.trim(), "<p class=\"adult\">adult</p>");
+
+    let result = lib_it_op_views::render("views/sub/condition", &mut sbolt::context!())?;
+    assert_eq!(result.trim(), "<p class=\"minor\">minor</p>");
+
+    Ok(())
+}
+
+#[test]
+fn sub_loops_view() -> result::RenderResult<()> {
+    let mut context = sbolt::context! {
+        items: vec!["a".to_string(), "<b>".to_string()]
+    };
+    let result = lib_it_op_views::render("views/sub/loops", &mut context)?;
+    let expected = "<ul><li class=\"first\">0:a</li><li class=\"\">1:&lt;b&gt;</li></ul><p>a, &lt;b&gt;</p><p>3 2 1 </p>";
+    assert_eq!(result.trim(), expected);
+
+    let result = lib_it_op_views::render("views/sub/loops", &mut sbolt::context!())?;
+    let expected = "<ul><li>none</li></ul><p></p><p>3 2 1 </p>";
+    assert_eq!(result.trim(), expected);
+
+    Ok(())
+}
+
+#[test]
+fn sub_matching_view() -> result::RenderResult<()> {
+    let result = lib_it_op_views::render("views/sub/matching", &mut sbolt::context! { age: 30 })?;
+    assert_eq!(result.trim(), "<p><b>adult(30)</b></p>");
+
+    let result = lib_it_op_views::render("views/sub/matching", &mut sbolt::context! { age: 3 })?;
+    assert_eq!(result.trim(), "<p>minor(3)</p>");
+
+    let result = lib_it_op_views::render("views/sub/matching", &mut sbolt::context!())?;
+    assert_eq!(result.trim(), "<p>unknown</p>");
+
+    Ok(())
+}
+
+#[test]
+fn sub_functions_view() -> result::RenderResult<()> {
+    let result = lib_it_op_views::render("views/sub/functions", &mut sbolt::context!())?;
+    assert_eq!(result.trim(), "<ul><li>1-a</li><li>2-b</li></ul><p>B.</p>");
+
+    Ok(())
+}
+
+#[test]
+fn sub_escape_view() -> result::RenderResult<()> {
+    let mut context = sbolt::context! {
+        name: "<b>\"Tom\" & 'Jerry'</b>".to_string()
+    };
+    let result = lib_it_op_views::render("views/sub/escape", &mut context)?;
+    let expected = "<div title=\"&lt;b&gt;&quot;Tom&quot; &amp; &#x27;Jerry&#x27;&lt;/b&gt;\">&lt;b&gt;\"Tom\" &amp; 'Jerry'&lt;/b&gt;</div><p><b>new</b> <i>22</i></p>";
+    assert_eq!(result.trim(), expected);
+
+    Ok(())
+}
+
+#[test]
+fn sub_partials_view() -> result::RenderResult<()> {
+    let mut context = sbolt::context! {
+        name: "sbolt".to_string()
+    };
+    let result = lib_it_op_views::render("views/sub/partials", &mut context)?;
+    let expected =
+        "<ul><li>a by sbolt</li><li>&lt;b&gt; by sbolt</li></ul><ul><li> by sbolt</li></ul>";
+    assert_eq!(result.trim(), expected);
+
+    Ok(())
+}
+
+#[test]
+fn sub_format_view() -> result::RenderResult<()> {
+    let result = lib_it_op_views::render("views/sub/format", &mut sbolt::context!())?;
+    assert_eq!(
+        result.trim(),
+        "<p>3.14|  &lt;b&gt;|<i>new</i>|[1, 2]|0xff</p>"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn sub_filters_view() -> result::RenderResult<()> {
+    let result = lib_it_op_views::render("views/sub/filters", &mut sbolt::context!())?;
+    let expected = "<p>SBOLT|template...|a, b|guest</p><p>items|1970-01-02 00:00|$3.50|7</p>";
+    assert_eq!(result.trim(), expected);
+
+    Ok(())
+}
+
+#[test]
+fn sub_product_view() -> result::RenderResult<()> {
+    let mut context = sbolt::context! {
+        model: Product {
+            name: "sbolt".to_string(),
+            price: 1.5,
+            tags: vec!["rust".to_string(), "<html>".to_string()],
+        }
+    };
+    let expected = "<h1>sbolt</h1><p>$1.50</p><ul><li>rust tag</li><li>&lt;html&gt; tag</li></ul>";
+    let result = lib_it_op_views::render("views/sub/product", &mut context)?;
+    assert_eq!(result.trim(), expected);
+    // the model is kept in the context after rendering.
+    let result = lib_it_op_views::render("views/sub/product", &mut context)?;
+    assert_eq!(result.trim(), expected);
+
+    let product = Product {
+        name: "typed".to_string(),
+        price: 2.0,
+        tags: vec![],
+    };
+    let result = lib_it_op_views::views::sub::product::ProductView::new()
+        .render_model(product, &mut sbolt::context!())?;
+    assert_eq!(result.trim(), "<h1>typed</h1><p>$2.00</p><ul></ul>");
+
+    // the typed entry point of the root module.
+    let product = Product {
+        name: "root".to_string(),
+        price: 3.0,
+        tags: vec![],
+    };
+    let result = lib_it_op_views::render_views_sub_product(product, &mut sbolt::context!())?;
+    assert!(result.starts_with("<h1>root</h1><p>$3.00</p>"));
+
+    let result = lib_it_op_views::render("views/sub/product", &mut sbolt::context!(model: 1));
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn sub_lend_view() -> result::RenderResult<()> {
+    // the model is seen by the partials rendered in the same context and put back after rendering.
+    let mut context = sbolt::context!(model: "lent".to_string());
+    let result = lib_it_op_views::render("views/sub/lend", &mut context)?;
+    assert_eq!(result.trim(), "<p><b>lent</b><i>lent</i></p>");
+    assert_eq!(
+        sbolt::types::Context::get_data::<String>(&context, "model"),
+        Some(&"lent".to_string())
+    );
+    Ok(())
+}
+
+#[test]
+fn sub_inject_view() -> result::RenderResult<()> {
+    let mut context = sbolt::context! {
+        urls: UrlBuilder {
+            base: "https://sbolt.dev".to_string(),
+        },
+        flags: std::sync::Arc::new(vec!["beta".to_string()])
+    };
+    let result = lib_it_op_views::render("views/sub/inject", &mut context)?;
+    assert_eq!(
+        result.trim(),
+        r#"<a href="https://sbolt.dev/home">home</a><b>beta</b>"#
+    );
+
+    let mut context = sbolt::context! {
+        flags: std::sync::Arc::new(Vec::<String>::new())
+    };
+    let err = lib_it_op_views::render("views/sub/inject", &mut context).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "View:views::sub::inject::InjectView, NotFound: Service 'urls' not found for View `views::sub::inject::InjectView`"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn sub_trim_view() -> result::RenderResult<()> {
+    let result = lib_it_op_views::render("views/sub/trim", &mut sbolt::context!())?;
+    assert_eq!(
+        result,
+        "Items:\n    - a\n    - b\n    total: 2\n\nTags:a,bend\n    count: 2\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn sub_stack_view() -> result::RenderResult<()> {
+    let result = lib_it_op_views::render("views/sub/stack", &mut sbolt::context!())?;
+    let expected = "<title>Stack</title><head><script>vendor()</script><script>base()</script><script>app()</script><script>widget()</script></head><body><div>widget</div><p>stack</p></body><aside><a href=\"/\">home</a></aside><footer><i>note</i></footer>";
+    assert_eq!(result.trim(), expected);
+
+    Ok(())
+}
+
+#[test]
+fn sub_page_view() -> result::RenderResult<()> {
+    let result = lib_it_op_views::render("views/sub/page", &mut sbolt::context!())?;
+    assert!(result.contains("<title>Untitled</title>"));
+    assert!(result.contains("<body><p>page</p></body>"));
+
+    // selected by the expression.
+    let result = lib_it_op_views::render("views/sub/page", &mut sbolt::context!(embed: true))?;
+    assert_eq!(result, "<main><p>page</p></main>");
+
+    // overridden by the context, the data of the app named `layout` isn't taken.
+    let mut context = sbolt::context!(layout: "app".to_string());
+    sbolt::types::set_layout(&mut context, None);
+    let result = lib_it_op_views::render("views/sub/page", &mut context)?;
+    assert_eq!(result, "<p>page</p>");
+    assert_eq!(
+        sbolt::types::Context::get_data::<String>(&context, "layout"),
+        Some(&"app".to_string())
+    );
+
+    let mut context = sbolt::context!();
+    sbolt::types::set_layout(&mut context, Some("embed"));
+    let result = lib_it_op_views::render("views/sub/home", &mut context)?;
+    assert_eq!(result, "<main><div>Hello world!<div></main>");
+
+    Ok(())
+}
+
+#[test]
+fn sub_intro_view() -> result::RenderResult<()> {
+    // intro -> sub/docs -> site, with a partial rendered in its own layout by sub/docs.
+    let result = lib_it_op_views::render("views/sub/intro", &mut sbolt::context!())?;
+    let expected = concat!(
+        "<html><head><title>Docs: Intro</title><link href=\"intro.css\"/><link href=\"docs.css\"/></head>",
+        "<body><nav>intro</nav><div class=\"box\"><b>boxed</b></div>",
+        "<article><p>intro</p></article><aside><ol><li>a</li></ol></aside></body></html>"
+    );
+    assert_eq!(result, expected);
+
+    Ok(())
+}
+
+#[test]
+fn blog_views() -> result::RenderResult<()> {
+    // views/blog/_imports and _viewstart apply to the whole folder, views/blog/archive overrides the layout.
+    let mut context = sbolt::context! {
+        urls: UrlBuilder {
+            base: "https://sbolt.dev".to_string(),
+        }
+    };
+    let result = lib_it_op_views::render("views/blog/post", &mut context)?;
+    assert_eq!(
+        result.trim(),
+        r#"<main><a href="https://sbolt.dev/post">sbolt</a></main>"#
+    );
+
+    let result = lib_it_op_views::render("views/blog/plain", &mut context)?;
+    assert_eq!(result.trim(), "<p>sbolt</p>");
+
+    let result = lib_it_op_views::render("views/blog/archive/year", &mut context)?;
+    assert_eq!(
+        result.trim(),
+        r#"<div class="box"><p>https://sbolt.dev/2026 by sbolt</p></div>"#
+    );
+    assert!(lib_it_op_views::resolve_view_creator("views/blog/_viewstart").is_none());
+
+    Ok(())
+}
+
+#[test]
+fn shop_views() -> result::RenderResult<()> {
+    // views/shop/base is the layout of views/shop/_viewstart, it isn't the layout of itself.
+    let result = lib_it_op_views::render("views/shop/item", &mut sbolt::context!())?;
+    assert_eq!(result.trim(), "<section><p>item</p></section>");
+
+    // views/shop/_viewstart.rshtml only applies to html views.
+    let result = lib_it_op_views::render("views/shop/stock", &mut sbolt::context!())?;
+    assert_eq!(result.trim(), r#"{"a":1}"#);
+
+    // views rendered by `@partial` are not wrapped in the layout of their `_viewstart`.
+    let result = lib_it_op_views::render("views/storefront", &mut sbolt::context!())?;
+    assert_eq!(result.trim(), "<main><i>card</i></main>");
+    let result = lib_it_op_views::render("views/shop/card", &mut sbolt::context!())?;
+    assert_eq!(result.trim(), "<section><i>card</i></section>");
+    Ok(())
+}
+
+#[test]
+fn render_to_io_writer() -> result::RenderResult<()> {
+    // streamed through the layouts and partials of views/sub/intro as render does.
+    let mut output = Vec::<u8>::new();
+    lib_it_op_views::render_to("views/sub/intro", &mut sbolt::context!(), &mut output)?;
+    let expected = lib_it_op_views::render("views/sub/intro", &mut sbolt::context!())?;
+    assert_eq!(String::from_utf8_lossy(&output), expected);
+
+    let mut output = Vec::<u8>::new();
+    lib_it_op_views::render_to("views/sub/escape", &mut sbolt::context!(), &mut output)?;
+    let expected = lib_it_op_views::render("views/sub/escape", &mut sbolt::context!())?;
+    assert_eq!(String::from_utf8_lossy(&output), expected);
+
+    let err = lib_it_op_views::render_to("views/no_existing", &mut sbolt::context!(), &mut output);
+    assert!(err.is_err());
+
+    Ok(())
+}
+
+// the render of a stream, a web app runs it on a blocking pool.
+fn spawn(task: sbolt::types::RenderTask) {
+    std::thread::spawn(task);
+}
+
+// polls the stream on the test thread until the next chunk or its end.
+fn next_chunk(
+    stream: &mut sbolt::types::RenderStream,
+) -> Option<result::RenderResult<sbolt::bytes::Bytes>> {
+    use futures_core::Stream;
+    let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+    loop {
+        match std::pin::Pin::new(&mut *stream).poll_next(&mut cx) {
+            std::task::Poll::Ready(chunk) => return chunk,
+            std::task::Poll::Pending => std::thread::yield_now(),
+        }
+    }
+}
+
+fn collect_chunks(
+    mut stream: sbolt::types::RenderStream,
+) -> Vec<result::RenderResult<sbolt::bytes::Bytes>> {
+    std::iter::from_fn(|| next_chunk(&mut stream)).collect()
+}
+
+#[test]
+fn sub_chunks_view() -> result::RenderResult<()> {
+    let stream = lib_it_op_views::render_stream("views/sub/chunks", sbolt::context!(), spawn)?;
+    let chunks = collect_chunks(stream)
+        .into_iter()
+        .collect::<result::RenderResult<Vec<_>>>()?;
+    assert_eq!(
+        chunks,
+        vec![
+            "<head><title>chunks</title></head>",
+            "<body><p>0</p>",
+            "<p>1</p>",
+            "<p>2</p>",
+            "</body>"
+        ]
+    );
+
+    // errors of the render end the stream.
+    let stream = lib_it_op_views::render_stream("views/sub/product", sbolt::context!(), spawn)?;
+    let chunks = collect_chunks(stream);
+    assert_eq!(chunks.len(), 1);
+    assert!(chunks[0].is_err());
+    assert!(lib_it_op_views::render_stream("views/no_existing", sbolt::context!(), spawn).is_err());
+
+    // views with `@await` are rendered by render_async only.
+    let stream = lib_it_op_views::render_stream("views/sub/feed", sbolt::context!(), spawn)?;
+    let chunks = collect_chunks(stream);
+    assert!(matches!(
+        chunks.as_slice(),
+        [Err(sbolt::types::error::RuntimeError::Unsupported(_, _))]
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn sub_flushed_view() -> result::RenderResult<()> {
+    // partials of the same kind write into the stream, so their `@flush` splits the chunks.
+    let stream = lib_it_op_views::render_stream("views/sub/flushed", sbolt::context!(), spawn)?;
+    let chunks = collect_chunks(stream)
+        .into_iter()
+        .collect::<result::RenderResult<Vec<_>>>()?;
+    assert_eq!(
+        chunks,
+        vec![
+            "<main><head><title>chunks</title></head>",
+            "<body><p>0</p>",
+            "<p>1</p>",
+            "<p>2</p>",
+            "</body></main>"
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn sub_pressure_view() -> result::RenderResult<()> {
+    // the render waits while the chunks of views/sub/pressure aren't received.
+    let rendered = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let stream = lib_it_op_views::render_stream(
+        "views/sub/pressure",
+        sbolt::context!(rendered: rendered.clone()),
+        spawn,
+    )?;
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    assert!(rendered.load(std::sync::atomic::Ordering::SeqCst) < 16);
+    let chunks = collect_chunks(stream)
+        .into_iter()
+        .collect::<result::RenderResult<Vec<_>>>()?;
+    assert_eq!(chunks.len(), 16);
+    assert_eq!(chunks[15], "<p>15</p>");
+    assert_eq!(rendered.load(std::sync::atomic::Ordering::SeqCst), 16);
+    Ok(())
+}
+
+#[test]
+fn sub_lazy_view() -> result::RenderResult<()> {
+    // the head of views/stream is received before the body of views/sub/lazy is rendered.
+    let (sender, receiver) = std::sync::mpsc::channel::<()>();
+    let mut stream = lib_it_op_views::render_stream(
+        "views/sub/lazy",
+        sbolt::context!(signal: std::sync::Mutex::new(receiver)),
+        spawn,
+    )?;
+    let head = next_chunk(&mut stream).expect("missing head")?;
+    assert_eq!(head, "<head><title>stream</title></head>");
+    sender.send(()).expect("the body isn't waiting");
+    let body = collect_chunks(stream)
+        .into_iter()
+        .collect::<result::RenderResult<Vec<_>>>()?;
+    assert_eq!(body.concat(), b"<body><p>true</p></body>".as_slice());
+
+    let result = lib_it_op_views::render("views/sub/lazy", &mut sbolt::context!())?;
+    assert_eq!(
+        result,
+        "<head><title>stream</title></head><body><p>true</p></body>"
+    );
+    Ok(())
+}
+
+// service injected in views/sub/feed and views/sub/author, loaded asynchronously.
+#[derive(Clone)]
+struct Repository {
+    posts: Vec<String>,
+}
+
+impl Repository {
+    async fn title(&self) -> String {
+        "<Feed>".to_string()
+    }
+
+    async fn posts(&self) -> Vec<String> {
+        self.posts.clone()
+    }
+
+    async fn author(&self) -> String {
+        "sbolt".to_string()
+    }
+}
+
+fn assert_send<T: Send>(_: &T) {}
+
+// the futures of the views are ready once polled.
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    let mut future = std::pin::pin!(future);
+    let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+    loop {
+        if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn sub_feed_view() -> result::RenderResult<()> {
+    let mut context = sbolt::context! {
+        repo: Repository {
+            posts: vec!["a".to_string(), "b".to_string()],
+        }
+    };
+    // sent to the executors of web frameworks as the handlers are.
+    let future = lib_it_op_views::render_async("views/sub/feed", &mut context);
+    assert_send(&future);
+    let result = block_on(future)?;
+    assert_eq!(
+        result.trim(),
+        "<div class=\"box\"><h1>&lt;Feed&gt;</h1><ul><li>a</li><li>b</li></ul><p>2 posts by sbolt</p></div>"
+    );
+
+    // awaits can't be rendered synchronously.
+    let err = lib_it_op_views::render("views/sub/feed", &mut context).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "View:views::sub::feed::FeedView, Unsupported: View `views::sub::feed::FeedView` awaits while rendering, use `render_async` instead"
+    );
+
+    // sync views are rendered as render does.
+    let result = block_on(lib_it_op_views::render_async(
+        "views/sub/intro",
+        &mut sbolt::context!(),
+    ))?;
+    let expected = lib_it_op_views::render("views/sub/intro", &mut sbolt::context!())?;
+    assert_eq!(result, expected);
+
+    Ok(())
+}
+
+// rendered by views/sub/stars without Display.
+struct Stars(u8);
+
+impl sbolt::types::Render for Stars {
+    fn render_to(
+        &self,
+        writer: &mut impl sbolt::types::Writer,
+        _context: sbolt::types::EscapeContext,
+    ) -> result::RenderResult<()> {
+        for _ in 0..self.0 {
+            writer.write("&#9733;");
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn sub_stars_view() -> result::RenderResult<()> {
+    let result = lib_it_op_views::render("views/sub/stars", &mut sbolt::context!())?;
+    assert_eq!(
+        result.trim(),
+        r#"<p>a&lt;b&gt;</p><p></p><p title="&#9733;&#9733;&#9733;">&#9733;&#9733;&#9733;</p><p>13</p>"#
+    );
+
+    Ok(())
+}